@@ -15,6 +15,43 @@ export declare class GpuixRenderer {
   removeChild(parentId: number, childId: number): void
   insertBefore(parentId: number, childId: number, beforeId: number): void
   setStyle(id: number, styleJson: string): void
+  /**
+   * Set the element's style classes from a whitespace-separated className.
+   * Classes are merged in order, then the inline style from setStyle.
+   */
+  setClassName(id: number, className: string): void
+  /**
+   * Register (or replace) a named style class, referenced by elements via
+   * setClassName. Elements already using the class restyle on the next frame.
+   */
+  registerStyleClass(name: string, styleJson: string): void
+  /**
+   * Register (or replace) named keyframes, e.g.
+   * `{"from": {"opacity": 1}, "50%": {"opacity": 0.4}, "to": {"opacity": 1}}`.
+   * Elements reference them with an `animation` style like `"pulse 1s infinite"`.
+   */
+  registerAnimation(name: string, keyframesJson: string): void
+  /**
+   * Set the theme: a JSON object mapping token names to colors, lengths
+   * or font names, e.g. `{"accent": "#89b4fa", "gap": 8}`. Styles refer
+   * to tokens as `"var(--accent)"` in any field. Replaces the previous
+   * theme and re-resolves themed styles on the next frame.
+   */
+  setTheme(themeJson: string): void
+  /**
+   * Choose the names used for `key` in keyboard payloads: "gpui"
+   * (default: "enter", "down") or "dom" (KeyboardEvent.key: "Enter",
+   * "ArrowDown"). Listeners pick it up on the next frame.
+   */
+  setKeyNames(names: string): void
+  /**
+   * Add key bindings from a JSON array of
+   * `{"keys": "ctrl-k ctrl-s", "action": "save", "context": "Editor"}`.
+   * Matches are sent as `action` events; see keymap.rs.
+   */
+  registerKeyBindings(bindingsJson: string): void
+  /** Remove every key binding registered with registerKeyBindings(). */
+  clearKeyBindings(): void
   setText(id: number, content: string): void
   setEventListener(id: number, eventType: string, hasHandler: boolean): void
   /** Set the root element (called from appendChildToContainer). */
@@ -41,6 +78,7 @@ export declare class GpuixRenderer {
    *   ["removeChild",      parentId, childId]
    *   ["insertBefore",     parentId, childId, beforeId]
    *   ["setStyle",         id, "{styleJson}"]
+   *   ["setClassName",     id, "className"]
    *   ["setText",          id, "content"]
    *   ["setEventListener", id, "eventType", true|false]
    *   ["setRoot",          id]
@@ -50,7 +88,18 @@ export declare class GpuixRenderer {
    * Acquires the tree mutex ONCE for the entire batch.
    */
   applyBatch(json: string): Array<number>
+  /**
+   * Pump OS events and render. Events GPUI dispatches here reach the
+   * synchronous handler, if one is set, before tick() returns.
+   */
   tick(): void
+  /**
+   * Call `handler` synchronously for events dispatched during tick(),
+   * instead of queueing them for the constructor's callback. It returns
+   * `{ stopPropagation, preventDefault }` to control GPUI's dispatch.
+   * Pass null to go back to queued events.
+   */
+  setSyncEventHandler(handler?: ((event: EventPayload) => EventResponse | undefined | null) | undefined | null): void
   isInitialized(): boolean
   getWindowSize(): WindowSize
   setWindowTitle(title: string): void
@@ -97,6 +146,25 @@ export declare class TestGpuixRenderer {
   removeChild(parentId: number, childId: number): void
   insertBefore(parentId: number, childId: number, beforeId: number): void
   setStyle(id: number, styleJson: string): void
+  setClassName(id: number, className: string): void
+  /** Register a named style class. Mirrors GpuixRenderer::register_style_class. */
+  registerStyleClass(name: string, styleJson: string): void
+  /** Register named keyframes. Mirrors GpuixRenderer::register_animation. */
+  registerAnimation(name: string, keyframesJson: string): void
+  /** Set the theme (token name → value JSON object). Mirrors GpuixRenderer::set_theme. */
+  setTheme(themeJson: string): void
+  /** Choose the names used for `key`. Mirrors GpuixRenderer::set_key_names. */
+  setKeyNames(names: string): void
+  /** Add key bindings. Mirrors GpuixRenderer::register_key_bindings. */
+  registerKeyBindings(bindingsJson: string): void
+  /** Remove every key binding. Mirrors GpuixRenderer::clear_key_bindings. */
+  clearKeyBindings(): void
+  /**
+   * Handle events synchronously during simulate_* calls. Mirrors
+   * GpuixRenderer::set_sync_event_handler; handled events are not
+   * returned by drainEvents().
+   */
+  setSyncEventHandler(handler?: ((event: EventPayload) => EventResponse | undefined | null) | undefined | null): void
   setText(id: number, content: string): void
   setEventListener(id: number, eventType: string, hasHandler: boolean): void
   /** Set the root element (called from appendChildToContainer). */
@@ -173,6 +241,16 @@ export declare class TestGpuixRenderer {
    * Button: 0=left, 1=middle, 2=right. Defaults to left (0).
    */
   simulateMouseUp(x: number, y: number, button?: number | undefined | null): void
+  /**
+   * Simulate files dragged from the OS over the window to the given
+   * coordinates. Repeated calls move the drag; GPUI keeps the paths of
+   * the first one.
+   */
+  simulateFileDrag(paths: Array<string>, x: number, y: number): void
+  /** Simulate dropping the dragged files at the given coordinates. */
+  simulateFileDrop(x: number, y: number): void
+  /** Simulate the dragged files leaving the window without a drop. */
+  simulateFileDragExit(): void
   /**
    * Simulate a scroll wheel event at the given position.
    * delta_x and delta_y are in pixels (negative = scroll up/left).
@@ -199,6 +277,11 @@ export declare class TestGpuixRenderer {
    * macOS only — requires Metal GPU rendering via VisualTestAppContext.
    */
   captureScreenshot(path: string): void
+  /**
+   * Read the text currently on the test platform's clipboard.
+   * Returns null if the clipboard is empty or holds non-text content.
+   */
+  readClipboardText(): string | null
   /**
    * Return and clear all collected events since the last drain.
    * Events are collected synchronously — no event loop queuing.
@@ -218,6 +301,13 @@ export declare class TestGpuixRenderer {
   getRootId(): number | null
 }
 
+export interface EventBounds {
+  x: number
+  y: number
+  width: number
+  height: number
+}
+
 export interface EventModifiers {
   shift: boolean
   ctrl: boolean
@@ -234,10 +324,27 @@ export interface EventPayload {
    * e.g. "click", "mouseDown", "mouseEnter", "keyDown", "scroll", etc.
    */
   eventType: string
+  /**
+   * Milliseconds since the first event, from a monotonic clock, so
+   * consecutive payloads can be used for velocities. Set on every event.
+   */
+  timestamp: number
   /** Mouse X position in window coordinates (pixels). */
   x?: number
   /** Mouse Y position in window coordinates (pixels). */
   y?: number
+  /**
+   * Mouse position relative to the element's top-left corner.
+   * Populated for: click, mouseDown, mouseUp, mouseMove, mouseDownOutside, scroll.
+   */
+  localX?: number
+  /** Populated for: click, mouseDown, mouseUp, mouseMove, mouseDownOutside, scroll. */
+  localY?: number
+  /**
+   * The element's bounds in window coordinates as of the last frame.
+   * Populated for: click, mouseDown, mouseUp, mouseMove, mouseDownOutside, scroll.
+   */
+  bounds?: EventBounds
   /**
    * Which mouse button: 0=left, 1=middle, 2=right.
    * Populated for: mouseDown, mouseUp, click, mouseDownOutside, contextMenu.
@@ -260,10 +367,18 @@ export interface EventPayload {
    */
   pressedButton?: number
   /**
-   * Key name, e.g. "a", "enter", "escape", "down", "left", "f1".
+   * Key name, e.g. "a", "enter", "escape", "down", "left", "f1", or the
+   * DOM KeyboardEvent.key name ("a", "Enter", "ArrowDown") after
+   * setKeyNames("dom").
    * Populated for: keyDown, keyUp.
    */
   key?: string
+  /**
+   * Physical key as a DOM KeyboardEvent.code name, e.g. "KeyA",
+   * "Digit1", "Space", independent of the keyboard layout.
+   * Populated for: keyDown, keyUp.
+   */
+  code?: string
   /**
    * The character produced by the key press (e.g. "ß" for option-s).
    * May differ from `key` when modifiers are active.
@@ -300,9 +415,75 @@ export interface EventPayload {
    * Populated for: mouseEnter, mouseLeave.
    */
   hovered?: boolean
+  /**
+   * The `href` of the clicked inline link span.
+   * Populated for: click (on text spans with an `href` prop).
+   */
+  href?: string
+  /**
+   * Selection start offset in UTF-16 code units (JS string index).
+   * Populated for: selectionChange.
+   */
+  selectionStart?: number
+  /**
+   * Selection end offset in UTF-16 code units (exclusive).
+   * Equal to `selection_start` when the selection is collapsed.
+   * Populated for: selectionChange.
+   */
+  selectionEnd?: number
+  /**
+   * The currently selected text ("" when collapsed).
+   * Populated for: selectionChange.
+   */
+  selectedText?: string
+  /**
+   * camelCase style property that finished transitioning.
+   * Populated for: transitionEnd.
+   */
+  property?: string
+  /**
+   * Transition duration in seconds, excluding delay (as in DOM
+   * TransitionEvent.elapsedTime).
+   * Populated for: transitionEnd.
+   */
+  elapsedTime?: number
+  /**
+   * Element ID of the dragged element.
+   * Populated for: dragEnter, dragOver, dragLeave, drop.
+   */
+  dragSource?: number
+  /**
+   * The dragged element's `dragData` (null when it has none).
+   * Populated for: dragEnter, dragOver, dragLeave, drop.
+   */
+  dragData?: any
+  /**
+   * Paths of the files dragged in from the OS.
+   * Populated for: fileDragEnter, fileDragLeave, fileDrop.
+   */
+  paths?: Array<string>
+  /**
+   * Name of the action a key binding dispatched.
+   * Populated for: action.
+   */
+  action?: string
   modifiers?: EventModifiers
 }
 
+/**
+ * What a synchronous event handler returns (see setSyncEventHandler).
+ * Ignored for queued events, which GPUI has already finished dispatching.
+ */
+export interface EventResponse {
+  /** Stop the event reaching the remaining elements on its path. */
+  stopPropagation?: boolean
+  /**
+   * Skip GPUI's default handling, e.g. focusing a focusable element on
+   * mouseDown.
+   */
+  preventDefault?: boolean
+}
+
 export interface WindowOptions {
   title?: string
   width?: number
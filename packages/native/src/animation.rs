@@ -0,0 +1,312 @@
+/// Declarative keyframe animations.
+///
+/// JS registers named keyframes once via registerAnimation(name, json) and
+/// elements reference them with an `animation` style, e.g.
+/// `"pulse 1s ease-in-out infinite alternate"`. build_div() and build_text()
+/// wrap the element in GPUI's `with_animations`, whose AnimationElement keeps
+/// requesting frames from the NodePlatform loop; each frame the animator
+/// samples the keyframes and applies the result on top of the element's
+/// style. Spinners and shimmers run entirely in Rust — React renders once.
+///
+/// Keyframes interpolate the same properties as transitions (see
+/// transition::ANIMATABLE). Properties a keyframe omits fall back to the
+/// element's own style, as in CSS; non-animatable properties are ignored.
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::style::{split_css_tokens, warn_once, StyleDesc};
+use crate::transition::{parse_time, Easing, ANIMATABLE};
+
+struct Keyframe {
+    /// 0.0-1.0.
+    offset: f32,
+    style: StyleDesc,
+}
+
+/// A registered keyframe set, sorted by offset.
+pub struct Keyframes {
+    frames: Vec<Keyframe>,
+}
+
+impl Keyframes {
+    /// Parse `{ "0%": {...}, "50%": {...}, "to": {...} }`. Keys are
+    /// percentages, `from`/`to`, or comma-separated lists (`"0%, 100%"`).
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse keyframes: {}", e))?;
+        let Value::Object(map) = value else {
+            return Err("Keyframes must be a JSON object of offsets to styles".to_string());
+        };
+
+        let mut frames = Vec::new();
+        for (key, style) in map {
+            let style = StyleDesc::deserialize(&style)
+                .map_err(|e| format!("Invalid keyframe {:?}: {}", key, e))?;
+            for selector in key.split(',') {
+                let offset = match selector.trim() {
+                    "from" => 0.0,
+                    "to" => 1.0,
+                    percent => {
+                        percent
+                            .strip_suffix('%')
+                            .and_then(|p| p.trim().parse::<f32>().ok())
+                            .filter(|p| (0.0..=100.0).contains(p))
+                            .ok_or_else(|| format!("Invalid keyframe offset {:?}", selector))?
+                            / 100.0
+                    }
+                };
+                frames.push(Keyframe {
+                    offset,
+                    style: style.clone(),
+                });
+            }
+        }
+        if frames.is_empty() {
+            return Err("Keyframes must have at least one offset".to_string());
+        }
+        frames.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        Ok(Self { frames })
+    }
+
+    /// Style at `progress` (0.0-1.0) containing only animated properties.
+    /// `easing` applies between each pair of keyframes, as in CSS.
+    pub fn sample(&self, base: &StyleDesc, progress: f32, easing: Easing) -> StyleDesc {
+        let mut sampled = StyleDesc::default();
+        for property in ANIMATABLE {
+            // Nearest keyframes on each side that set this property; the
+            // element's own style stands in for missing 0% / 100% frames.
+            let before = self
+                .frames
+                .iter()
+                .rev()
+                .filter(|f| f.offset <= progress)
+                .find_map(|f| Some((f.offset, (property.get)(&f.style)?)));
+            let after = self
+                .frames
+                .iter()
+                .filter(|f| f.offset >= progress)
+                .find_map(|f| Some((f.offset, (property.get)(&f.style)?)));
+            if before.is_none() && after.is_none() {
+                continue;
+            }
+            let base_value = (property.get)(base);
+            let before = before.or(base_value.map(|v| (0.0, v)));
+            let after = after.or(base_value.map(|v| (1.0, v)));
+
+            let value = match (before, after) {
+                (Some((start, from)), Some((end, to))) if end > start => {
+                    let t = easing.apply((progress - start) / (end - start));
+                    from.lerp(to, t).unwrap_or(if t < 0.5 { from } else { to })
+                }
+                (Some((_, value)), _) | (None, Some((_, value))) => value,
+                (None, None) => continue,
+            };
+            (property.set)(&mut sampled, value);
+        }
+        sampled
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FillMode {
+    None,
+    Forwards,
+    Backwards,
+    Both,
+}
+
+/// A parsed `animation` style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationSpec {
+    pub name: String,
+    pub duration: Duration,
+    pub delay: Duration,
+    pub easing: Easing,
+    /// None = infinite.
+    pub iterations: Option<f32>,
+    pub direction: Direction,
+    pub fill_mode: FillMode,
+}
+
+/// Parse `"pulse 1s ease-in-out 200ms infinite alternate both"`. Tokens may
+/// appear in any order; the first time is the duration, the second the delay.
+pub fn parse_animation(value: &str) -> Result<AnimationSpec, String> {
+    let invalid = || format!("invalid animation {:?}", value);
+    let mut spec = AnimationSpec {
+        name: String::new(),
+        duration: Duration::ZERO,
+        delay: Duration::ZERO,
+        easing: Easing::EASE,
+        iterations: Some(1.0),
+        direction: Direction::Normal,
+        fill_mode: FillMode::None,
+    };
+    let mut times = 0;
+    for token in split_css_tokens(value) {
+        if let Some(time) = parse_time(token) {
+            match times {
+                0 => spec.duration = time,
+                1 => spec.delay = time,
+                _ => return Err(invalid()),
+            }
+            times += 1;
+        } else if let Some(easing) = Easing::parse(token) {
+            spec.easing = easing;
+        } else if token == "infinite" {
+            spec.iterations = None;
+        } else if let Ok(count) = token.parse::<f32>() {
+            spec.iterations = Some(count.max(0.0));
+        } else {
+            match token {
+                "normal" => spec.direction = Direction::Normal,
+                "reverse" => spec.direction = Direction::Reverse,
+                "alternate" => spec.direction = Direction::Alternate,
+                "alternate-reverse" => spec.direction = Direction::AlternateReverse,
+                "none" if !spec.name.is_empty() => spec.fill_mode = FillMode::None,
+                "forwards" => spec.fill_mode = FillMode::Forwards,
+                "backwards" => spec.fill_mode = FillMode::Backwards,
+                "both" => spec.fill_mode = FillMode::Both,
+                "running" => {}
+                name if spec.name.is_empty() => spec.name = name.to_string(),
+                _ => return Err(invalid()),
+            }
+        }
+    }
+    if spec.name.is_empty() {
+        return Err(invalid());
+    }
+    Ok(spec)
+}
+
+impl AnimationSpec {
+    /// The GPUI animations driving this spec, run in sequence. Finite
+    /// animations run once over delay + iterations; infinite ones run the
+    /// delay once, then repeat (over two iterations when alternating, so the
+    /// direction can flip).
+    pub fn gpui_animations(&self) -> Vec<gpui::Animation> {
+        match self.iterations {
+            Some(count) => vec![gpui::Animation::new(
+                self.delay + self.duration.mul_f32(count),
+            )],
+            None => {
+                let cycle = if self.alternates() { 2 } else { 1 };
+                let repeat = gpui::Animation::new(self.duration * cycle).repeat();
+                if self.delay.is_zero() {
+                    vec![repeat]
+                } else {
+                    vec![gpui::Animation::new(self.delay), repeat]
+                }
+            }
+        }
+    }
+
+    fn alternates(&self) -> bool {
+        matches!(
+            self.direction,
+            Direction::Alternate | Direction::AlternateReverse
+        )
+    }
+
+    /// Map GPUI's linear delta (0.0-1.0 over gpui_animations()[ix]) to
+    /// keyframe progress. None when the element should show its own style
+    /// (before the delay or after the end without the matching fill mode).
+    pub fn progress(&self, ix: usize, delta: f32) -> Option<f32> {
+        let Some(count) = self.iterations else {
+            if ix == 0 && !self.delay.is_zero() {
+                // The delay phase of an infinite animation.
+                let fills = matches!(self.fill_mode, FillMode::Backwards | FillMode::Both);
+                return fills.then(|| self.directed(0, 0.0));
+            }
+            let cycle = if self.alternates() { 2.0 } else { 1.0 };
+            let elapsed = delta * cycle;
+            let iteration = elapsed.floor().min(cycle - 1.0);
+            return Some(self.directed(iteration as u32, elapsed - iteration));
+        };
+
+        let total = self.delay.as_secs_f32() + self.duration.as_secs_f32() * count;
+        let elapsed = delta * total - self.delay.as_secs_f32();
+        if elapsed < 0.0 {
+            let fills = matches!(self.fill_mode, FillMode::Backwards | FillMode::Both);
+            return fills.then(|| self.directed(0, 0.0));
+        }
+        let duration = self.duration.as_secs_f32();
+        let iterations = if duration > 0.0 {
+            elapsed / duration
+        } else {
+            count
+        };
+        if iterations >= count {
+            let fills = matches!(self.fill_mode, FillMode::Forwards | FillMode::Both);
+            let last = (count.ceil() - 1.0).max(0.0);
+            return fills.then(|| self.directed(last as u32, count - last));
+        }
+        let iteration = iterations.floor();
+        Some(self.directed(iteration as u32, iterations - iteration))
+    }
+
+    fn directed(&self, iteration: u32, local: f32) -> f32 {
+        let reversed = match self.direction {
+            Direction::Normal => false,
+            Direction::Reverse => true,
+            Direction::Alternate => iteration % 2 == 1,
+            Direction::AlternateReverse => iteration % 2 == 0,
+        };
+        if reversed {
+            1.0 - local
+        } else {
+            local
+        }
+    }
+}
+
+/// Everything build_div() needs to animate one element, cloned out of the
+/// tree so the 'static animator closure can own it.
+pub struct ElementAnimation {
+    pub id: gpui::ElementId,
+    pub spec: AnimationSpec,
+    pub keyframes: Arc<Keyframes>,
+    pub base: StyleDesc,
+}
+
+/// Resolve an element's `animation` style against the registered keyframes.
+/// Unknown names and malformed values are logged once and ignored.
+pub fn resolve(
+    element_id: u64,
+    style: &StyleDesc,
+    animations: &HashMap<String, Arc<Keyframes>>,
+) -> Option<ElementAnimation> {
+    let value = style.animation.as_deref().filter(|v| v.trim() != "none")?;
+    let spec = match parse_animation(value) {
+        Ok(spec) => spec,
+        Err(e) => {
+            warn_once(e);
+            return None;
+        }
+    };
+    if spec.duration.is_zero() {
+        return None;
+    }
+    let Some(keyframes) = animations.get(&spec.name) else {
+        warn_once(format!("unknown animation {:?}", spec.name));
+        return None;
+    };
+    Some(ElementAnimation {
+        // Keyed by the animation value, so changing it restarts the animation.
+        id: gpui::ElementId::NamedInteger(format!("__gpuix_anim:{}", value).into(), element_id),
+        keyframes: keyframes.clone(),
+        base: style.clone(),
+        spec,
+    })
+}
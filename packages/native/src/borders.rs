@@ -0,0 +1,134 @@
+/// Borders: widths, color, style and corner radii, and the overlay that
+/// paints sides of different colors.
+use gpui::prelude::*;
+
+use crate::style::{resolve_borders, Borders, DimensionValue, StyleDesc};
+
+/// Apply border widths, color and style, and corner radii. Per-corner
+/// radii override `borderRadius`.
+pub fn apply<E: gpui::Styled>(mut el: E, style: &StyleDesc) -> E {
+    el = apply_corner_radii(el, style);
+    let Some(borders) = resolve_borders(style) else {
+        return el;
+    };
+    let width = |w: Option<f64>| w.map(|w| gpui::px(w as f32).into());
+    let widths = &mut el.style().border_widths;
+    widths.top = width(borders.widths[0]).or(widths.top);
+    widths.right = width(borders.widths[1]).or(widths.right);
+    widths.bottom = width(borders.widths[2]).or(widths.bottom);
+    widths.left = width(borders.widths[3]).or(widths.left);
+    if let Some(color) = borders.color() {
+        el = el.border_color(gpui::rgba(color));
+    }
+    if borders.dashed {
+        el = el.border_dashed();
+    }
+    el
+}
+
+/// Apply `borderRadius`, then the per-corner radii over it.
+pub fn apply_corner_radii<E: gpui::Styled>(mut el: E, style: &StyleDesc) -> E {
+    let px = |radius: Option<DimensionValue>| radius.and_then(|r| r.pixels("borderRadius"));
+    if let Some(radius) = px(style.border_radius) {
+        el = el.rounded(gpui::px(radius as f32));
+    }
+    let corners = [
+        px(style.border_top_left_radius),
+        px(style.border_top_right_radius),
+        px(style.border_bottom_right_radius),
+        px(style.border_bottom_left_radius),
+    ];
+    if corners.iter().any(Option::is_some) {
+        let radius = |r: Option<f64>| r.map(|r| gpui::px(r as f32).into());
+        let radii = &mut el.style().corner_radii;
+        radii.top_left = radius(corners[0]).or(radii.top_left);
+        radii.top_right = radius(corners[1]).or(radii.top_right);
+        radii.bottom_right = radius(corners[2]).or(radii.bottom_right);
+        radii.bottom_left = radius(corners[3]).or(radii.bottom_left);
+    }
+    el
+}
+
+/// GPUI paints a border in one color. When the visible sides differ, in the
+/// base style or while hovered or active, apply() leaves the native border
+/// transparent, keeping its widths for layout, and these overlays paint
+/// each side over it: one absolute child per side, whose
+/// group_hover/group_active styles follow the element's `group`. As in
+/// build_div, a disabled element takes its `disabled` style instead, and
+/// `pointerEvents: "none"` turns hover and active off.
+pub fn overlay(
+    id: u64,
+    style: &StyleDesc,
+    disabled: bool,
+    pointer_events: bool,
+    group: &gpui::SharedString,
+) -> Option<Vec<gpui::AnyElement>> {
+    let state = |sub: &Option<Box<StyleDesc>>| sub.as_deref().and_then(resolve_borders);
+    let mut base = resolve_borders(style)?;
+    let (hover, active) = if disabled {
+        if let Some(disabled) = state(&style.disabled) {
+            base = base.refined(&disabled);
+        }
+        (None, None)
+    } else if pointer_events {
+        (state(&style.hover), state(&style.active))
+    } else {
+        (None, None)
+    };
+    let hovered = hover.as_ref().map(|hover| base.refined(hover));
+    let pressed = active.as_ref().map(|active| {
+        [
+            base.refined(active),
+            hovered.as_ref().unwrap_or(&base).refined(active),
+        ]
+    });
+    let per_side = std::iter::once(&base)
+        .chain(&hovered)
+        .chain(pressed.iter().flatten())
+        .any(|borders| borders.per_side_colors().is_some());
+    if !per_side {
+        return None;
+    }
+
+    let sides = (0..4).map(|side| {
+        let overlay_id = gpui::SharedString::from(format!("__gpuix_{}_border_{}", id, side));
+        let mut overlay = gpui::div()
+            .id(overlay_id)
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full();
+        overlay = apply_corner_radii(overlay, style);
+        if base.dashed {
+            overlay = overlay.border_dashed();
+        }
+        overlay = apply_side(overlay, &base, side);
+        if let Some(ref hover) = hover {
+            overlay = overlay.group_hover(group.clone(), |r| apply_side(r, hover, side));
+        }
+        if let Some(ref active) = active {
+            overlay = overlay.group_active(group.clone(), |r| apply_side(r, active, side));
+        }
+        overlay.into_any_element()
+    });
+    Some(sides.collect())
+}
+
+/// Apply one side of `borders` — its width and color, where set — to a
+/// border overlay.
+fn apply_side<E: gpui::Styled>(mut el: E, borders: &Borders, side: usize) -> E {
+    if let Some(width) = borders.widths[side] {
+        let width = Some(gpui::px(width as f32).into());
+        let widths = &mut el.style().border_widths;
+        match side {
+            0 => widths.top = width,
+            1 => widths.right = width,
+            2 => widths.bottom = width,
+            _ => widths.left = width,
+        }
+    }
+    if let Some(color) = borders.colors[side] {
+        el = el.border_color(gpui::rgba(color));
+    }
+    el
+}
@@ -0,0 +1,122 @@
+/// The bounds canvas: one absolutely positioned canvas filling a div, for
+/// everything that needs the div's bounds.
+///
+/// - Mouse payloads report positions relative to the element (see
+///   events.rs), from the bounds seen in this frame's prepaint.
+/// - Children with calc(% ± length) resolve against the element's size,
+///   sticky elements against their own and their parent's position, and
+///   drag ghosts take their source's size, all from the previous frame's
+///   LayoutBounds. The canvas records them during prepaint and asks for one
+///   more frame when they changed, so everything settles after a single
+///   extra frame.
+/// - A capturing element's window-level listeners (see pointer_capture.rs)
+///   are registered during paint, where local coordinates are known.
+///
+/// build_div() appends it after the real children, so scroll_to_item()
+/// indices still match them.
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use crate::renderer::point_to_xy;
+use crate::retained_tree::{LayoutBounds, RetainedElement, RetainedTree};
+
+/// What one element's canvas does; it is left out when all are None.
+#[derive(Default)]
+pub struct BoundsCanvas {
+    /// Set to the element's bounds for its event listeners.
+    pub target: Option<Rc<Cell<gpui::Bounds<gpui::Pixels>>>>,
+    pub measure: Option<Measure>,
+    pub capture: Option<crate::pointer_capture::Listeners>,
+}
+
+impl BoundsCanvas {
+    pub fn into_element(self) -> Option<gpui::AnyElement> {
+        use gpui::prelude::*;
+
+        let Self {
+            target,
+            measure,
+            capture,
+        } = self;
+        if target.is_none() && measure.is_none() && capture.is_none() {
+            return None;
+        }
+        let canvas = gpui::canvas(
+            move |bounds, window, _cx| {
+                if let Some(target) = target {
+                    target.set(bounds);
+                }
+                if let Some(measure) = measure {
+                    measure.record(bounds, window);
+                }
+            },
+            move |bounds, _, window, _cx| {
+                if let Some(capture) = capture {
+                    capture.paint(bounds, window);
+                }
+            },
+        );
+        Some(canvas.absolute().size_full().into_any_element())
+    }
+}
+
+/// Records an element's LayoutBounds for the next frame.
+pub struct Measure {
+    id: u64,
+    layout_bounds: Arc<Mutex<HashMap<u64, LayoutBounds>>>,
+    /// The nearest scroll container, whose content space the bounds are in.
+    scroll: Option<gpui::ScrollHandle>,
+    /// The element's own sticky shift, taken back out of the bounds.
+    shift: (f64, f64),
+}
+
+impl Measure {
+    pub fn new(
+        id: u64,
+        tree: &RetainedTree,
+        scroll: Option<gpui::ScrollHandle>,
+        shift: (f64, f64),
+    ) -> Self {
+        Self {
+            id,
+            layout_bounds: tree.layout_bounds.clone(),
+            scroll,
+            shift,
+        }
+    }
+
+    fn record(&self, bounds: gpui::Bounds<gpui::Pixels>, window: &mut gpui::Window) {
+        let content_origin = self
+            .scroll
+            .as_ref()
+            .map_or(gpui::Point::default(), |handle| {
+                handle.bounds().origin + handle.offset()
+            });
+        let (x, y) = point_to_xy(bounds.origin - content_origin);
+        let measured = LayoutBounds {
+            x: x - self.shift.0,
+            y: y - self.shift.1,
+            width: f32::from(bounds.size.width) as f64,
+            height: f32::from(bounds.size.height) as f64,
+        };
+        let mut layout_bounds = self.layout_bounds.lock().unwrap();
+        if layout_bounds.insert(self.id, measured) != Some(measured) {
+            window.request_animation_frame();
+        }
+    }
+}
+
+/// True if a child of `element` resolves against its measured size or
+/// position: calc(% ± length) lengths and sticky children.
+pub fn children_need_layout(element: &RetainedElement, tree: &RetainedTree) -> bool {
+    element.children.iter().any(|child_id| {
+        tree.elements
+            .get(child_id)
+            .and_then(|child| child.animated_style.as_ref().or(child.style.as_ref()))
+            .is_some_and(|style| {
+                style.needs_parent_size() || style.position.as_deref() == Some("sticky")
+            })
+    })
+}
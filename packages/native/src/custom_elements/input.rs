@@ -10,6 +10,7 @@
 /// back to React which updates `value` — completing the round-trip.
 use super::{CustomElement, CustomElementFactory, CustomRenderContext};
 use crate::renderer::emit_event_full;
+use crate::sync_dispatch::respond;
 
 // ── Factory ──────────────────────────────────────────────────────────
 
@@ -90,34 +91,35 @@ impl CustomElement for InputElement {
             let callback = ctx.event_callback.clone();
             match event_type.as_str() {
                 "keyDown" if !self.read_only => {
-                    el = el.on_key_down(move |key_event, _window, _cx| {
-                        emit_event_full(&callback, id, "keyDown", |p| {
-                            p.key = Some(key_event.keystroke.key.clone());
-                            p.key_char = key_event.keystroke.key_char.clone();
+                    let key_names = ctx.key_names;
+                    el = el.on_key_down(move |key_event, window, cx| {
+                        let response = emit_event_full(&callback, id, "keyDown", |p| {
+                            crate::keyboard::fill(p, &key_event.keystroke, key_names);
                             p.is_held = Some(key_event.is_held);
-                            p.modifiers = Some(key_event.keystroke.modifiers.into());
                         });
+                        respond(response, window, cx);
                     });
                 }
                 "keyUp" if !self.read_only => {
                     let callback = callback.clone();
-                    el = el.on_key_up(move |key_event, _window, _cx| {
-                        emit_event_full(&callback, id, "keyUp", |p| {
-                            p.key = Some(key_event.keystroke.key.clone());
-                            p.key_char = key_event.keystroke.key_char.clone();
-                            p.modifiers = Some(key_event.keystroke.modifiers.into());
+                    let key_names = ctx.key_names;
+                    el = el.on_key_up(move |key_event, window, cx| {
+                        let response = emit_event_full(&callback, id, "keyUp", |p| {
+                            crate::keyboard::fill(p, &key_event.keystroke, key_names);
                         });
+                        respond(response, window, cx);
                     });
                 }
                 "click" => {
                     let callback = callback.clone();
-                    el = el.on_click(move |click_event, _window, _cx| {
-                        emit_event_full(&callback, id, "click", |p| {
+                    el = el.on_click(move |click_event, window, cx| {
+                        let response = emit_event_full(&callback, id, "click", |p| {
                             let (x, y) = crate::renderer::point_to_xy(click_event.position());
                             p.x = Some(x);
                             p.y = Some(y);
                             p.modifiers = Some(click_event.modifiers().into());
                         });
+                        respond(response, window, cx);
                     });
                 }
                 // Focus/blur handled by FocusHandle subscriptions in sync_focus_handles.
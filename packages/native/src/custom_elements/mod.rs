@@ -35,6 +35,8 @@ pub struct CustomRenderContext<'a> {
     pub style: Option<&'a crate::style::StyleDesc>,
     /// Built child elements from the retained tree for this custom node.
     pub children: Vec<gpui::AnyElement>,
+    /// Names for `key` in keyboard payloads (see keyboard.rs).
+    pub key_names: crate::keyboard::KeyNames,
 }
 
 // ── Traits ───────────────────────────────────────────────────────────
@@ -0,0 +1,335 @@
+/// Drag and drop between elements, and of files from the OS.
+///
+/// A div with `draggable` becomes a drag source through GPUI's on_drag:
+/// once the mouse moves a few pixels with the button held, the element's
+/// `dragData` (any JSON value) travels with the drag as a DragPayload. The
+/// preview under the cursor is the child named by `dragPreview` (a ref id;
+/// like a tooltip's element, it is left out of the div's own layout) or a
+/// translucent ghost the size of the element.
+///
+/// Elements with dragEnter/dragOver/dragLeave/drop listeners are drop
+/// targets. GPUI only has on_drag_move, which every listener receives on
+/// every move, and on_drop, so enter and leave come from comparing the
+/// cursor with each target's bounds against DragTargets, the targets the
+/// drag is currently over. Targets still in it when a drag ends elsewhere
+/// get dragLeave from GpuixView::render().
+///
+/// Files dragged in from the OS arrive as GPUI FileDropEvents (see
+/// NodePlatform::tick), which GPUI turns into a drag of ExternalPaths.
+/// fileDragEnter/fileDragLeave/fileDrop targets use the same listeners for
+/// that type.
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+use crate::element_tree::EventPayload;
+use crate::renderer::{emit_event_full, point_to_xy, EventCallback, GpuixView};
+use crate::retained_tree::{RetainedElement, RetainedTree};
+use crate::style::warn_once;
+use crate::sync_dispatch::respond;
+
+/// Ghost size for sources that haven't been measured yet.
+const GHOST_SIZE: f64 = 32.0;
+
+/// A `draggable` element's drag props.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragSource {
+    pub data: Value,
+    /// Child rendered only as the drag preview.
+    pub preview: Option<u64>,
+}
+
+impl DragSource {
+    /// The element's drag props if `draggable` is true. A `dragPreview`
+    /// that isn't a child of `element` is logged once and ignored.
+    pub fn of(element: &RetainedElement) -> Option<Self> {
+        let draggable = element
+            .custom_props
+            .get("draggable")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        if !draggable {
+            return None;
+        }
+        let preview = element.custom_props.get("dragPreview").and_then(|value| {
+            let id = value.as_u64().filter(|id| element.children.contains(id));
+            if id.is_none() {
+                warn_once(format!(
+                    "dragPreview {} is not a child of element {}",
+                    value, element.id
+                ));
+            }
+            id
+        });
+        Some(Self {
+            data: element
+                .custom_props
+                .get("dragData")
+                .cloned()
+                .unwrap_or(Value::Null),
+            preview,
+        })
+    }
+}
+
+/// The value GPUI carries while dragging (on_drag's `T`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragPayload {
+    pub source: u64,
+    pub data: Value,
+}
+
+/// What a drop target has dragged over it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Dragged {
+    Element(DragPayload),
+    Files(Vec<PathBuf>),
+}
+
+impl Dragged {
+    pub fn files(paths: &gpui::ExternalPaths) -> Self {
+        Dragged::Files(paths.paths().to_vec())
+    }
+
+    /// The event for entering a target.
+    pub fn enter_event(&self) -> &'static str {
+        match self {
+            Dragged::Element(_) => "dragEnter",
+            Dragged::Files(_) => "fileDragEnter",
+        }
+    }
+
+    /// The event for moving over a target. None for files: winit doesn't
+    /// report the cursor while files are dragged over the window.
+    pub fn over_event(&self) -> Option<&'static str> {
+        match self {
+            Dragged::Element(_) => Some("dragOver"),
+            Dragged::Files(_) => None,
+        }
+    }
+
+    /// The event for leaving a target.
+    pub fn leave_event(&self) -> &'static str {
+        match self {
+            Dragged::Element(_) => "dragLeave",
+            Dragged::Files(_) => "fileDragLeave",
+        }
+    }
+
+    /// Fill a drop target event's payload, moving the drag data into it.
+    pub fn fill(self, p: &mut EventPayload, position: gpui::Point<gpui::Pixels>) {
+        let (x, y) = point_to_xy(position);
+        p.x = Some(x);
+        p.y = Some(y);
+        match self {
+            Dragged::Element(payload) => {
+                p.drag_source = Some(payload.source as f64);
+                p.drag_data = Some(payload.data);
+            }
+            Dragged::Files(paths) => {
+                p.paths = Some(
+                    paths
+                        .iter()
+                        .map(|path| path.to_string_lossy().into_owned())
+                        .collect(),
+                );
+            }
+        }
+    }
+}
+
+/// Drop targets the current drag is over, by element id, with what is
+/// dragged so a drag that ends elsewhere can still report it on leave.
+pub type DragTargets = Arc<Mutex<HashMap<u64, Dragged>>>;
+
+/// Record a drag move for target `id` and return the event it produces:
+/// enter when the cursor crosses into the target, over while it stays
+/// inside, leave when it crosses out. Every listener gets every move, so
+/// `dragged` is only called (and the drag data copied) on enter.
+pub fn on_drag_move(
+    targets: &DragTargets,
+    id: u64,
+    inside: bool,
+    dragged: impl FnOnce() -> Dragged,
+) -> Option<&'static str> {
+    let mut targets = targets.lock().unwrap();
+    if !inside {
+        targets.remove(&id).map(|dragged| dragged.leave_event())
+    } else if let Some(dragged) = targets.get(&id) {
+        dragged.over_event()
+    } else {
+        let dragged = dragged();
+        let event = dragged.enter_event();
+        targets.insert(id, dragged);
+        Some(event)
+    }
+}
+
+/// Make `el` a drag source. GPUI starts the drag once the mouse moves a few
+/// pixels with the button held, and then suppresses the element's click.
+pub fn source(
+    el: gpui::Stateful<gpui::Div>,
+    element: &RetainedElement,
+    source: &DragSource,
+    tree: &RetainedTree,
+    cx: &mut gpui::Context<GpuixView>,
+) -> gpui::Stateful<gpui::Div> {
+    use gpui::{AppContext as _, StatefulInteractiveElement as _};
+
+    let payload = DragPayload {
+        source: element.id,
+        data: source.data.clone(),
+    };
+    let view = cx.entity().downgrade();
+    let preview = source.preview;
+    let size = tree
+        .layout_bounds
+        .lock()
+        .unwrap()
+        .get(&element.id)
+        .map(|bounds| (bounds.width, bounds.height));
+    el.on_drag(payload, move |_, _, _, cx| {
+        cx.new(|_| DragPreview::new(view.clone(), preview, size))
+    })
+}
+
+/// Wire the element's drop target events: enter, over and leave for
+/// element drags and files, and the drops themselves. GPUI calls on_drop on
+/// mouse up over the element while a drag is active; the drag ends there.
+/// Files dropped from the OS arrive as ExternalPaths.
+pub fn target(
+    mut el: gpui::Stateful<gpui::Div>,
+    element: &RetainedElement,
+    tree: &RetainedTree,
+    event_callback: &Option<EventCallback>,
+) -> gpui::Stateful<gpui::Div> {
+    use gpui::InteractiveElement as _;
+
+    let id = element.id;
+    let listens = |events: &[&str]| events.iter().any(|event| element.events.contains(*event));
+    if listens(&["dragEnter", "dragOver", "dragLeave"]) {
+        el = on_crossing(
+            el,
+            element,
+            tree,
+            event_callback,
+            |payload: &DragPayload| Dragged::Element(payload.clone()),
+        );
+    }
+    if listens(&["fileDragEnter", "fileDragLeave"]) {
+        el = on_crossing(el, element, tree, event_callback, Dragged::files);
+    }
+    if listens(&["drop"]) {
+        let callback = event_callback.clone();
+        let drag_targets = tree.drag_targets.clone();
+        el = el.on_drop(move |payload: &DragPayload, window, cx| {
+            drag_targets.lock().unwrap().remove(&id);
+            let dragged = Dragged::Element(payload.clone());
+            let response = emit_event_full(&callback, id, "drop", |p| {
+                dragged.fill(p, window.mouse_position());
+            });
+            respond(response, window, cx);
+        });
+    }
+    if listens(&["fileDrop"]) {
+        let callback = event_callback.clone();
+        let drag_targets = tree.drag_targets.clone();
+        el = el.on_drop(move |paths: &gpui::ExternalPaths, window, cx| {
+            drag_targets.lock().unwrap().remove(&id);
+            let dragged = Dragged::files(paths);
+            let response = emit_event_full(&callback, id, "fileDrop", |p| {
+                dragged.fill(p, window.mouse_position());
+            });
+            respond(response, window, cx);
+        });
+    }
+    el
+}
+
+/// Wire a drop target's enter/over/leave events for drags carrying `T`
+/// (see on_drag_move).
+fn on_crossing<T: 'static>(
+    el: gpui::Stateful<gpui::Div>,
+    element: &RetainedElement,
+    tree: &RetainedTree,
+    event_callback: &Option<EventCallback>,
+    dragged: fn(&T) -> Dragged,
+) -> gpui::Stateful<gpui::Div> {
+    use gpui::InteractiveElement as _;
+
+    let id = element.id;
+    let events = element.events.clone();
+    let callback = event_callback.clone();
+    let drag_targets = tree.drag_targets.clone();
+    el.on_drag_move(move |event: &gpui::DragMoveEvent<T>, _window, cx| {
+        let position = event.event.position;
+        let inside = event.bounds.contains(&position);
+        let Some(event_type) = on_drag_move(&drag_targets, id, inside, || dragged(event.drag(cx)))
+        else {
+            return;
+        };
+        // Copy the drag data only for an event someone listens to.
+        if events.contains(event_type) {
+            let current = dragged(event.drag(cx));
+            emit_event_full(&callback, id, event_type, |p| {
+                current.fill(p, position);
+            });
+        }
+    })
+}
+
+/// The view GPUI paints under the cursor during a drag, offset so the
+/// point where the source was grabbed stays under it.
+pub struct DragPreview {
+    view: gpui::WeakEntity<GpuixView>,
+    preview: Option<u64>,
+    /// The source's last measured size, for the ghost.
+    size: Option<(f64, f64)>,
+}
+
+impl DragPreview {
+    pub fn new(
+        view: gpui::WeakEntity<GpuixView>,
+        preview: Option<u64>,
+        size: Option<(f64, f64)>,
+    ) -> Self {
+        Self {
+            view,
+            preview,
+            size,
+        }
+    }
+}
+
+impl gpui::Render for DragPreview {
+    fn render(
+        &mut self,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        use gpui::prelude::*;
+
+        // Rebuilt from the retained tree every frame, so the preview follows
+        // React updates made during the drag.
+        if let Some(id) = self.preview {
+            let element = self
+                .view
+                .update(cx, |view, cx| view.build_detached(id, window, cx));
+            if let Ok(element) = element {
+                return element;
+            }
+        }
+
+        let (width, height) = self.size.unwrap_or((GHOST_SIZE, GHOST_SIZE));
+        gpui::div()
+            .w(gpui::px(width as f32))
+            .h(gpui::px(height as f32))
+            .rounded(gpui::px(4.0))
+            .bg(gpui::rgba(0x89b4fa33))
+            .border_1()
+            .border_color(gpui::rgba(0x89b4fa99))
+            .into_any_element()
+    }
+}
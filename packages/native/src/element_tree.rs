@@ -5,6 +5,9 @@
 /// event types. Fields are optional — each event type populates only the
 /// fields it needs. This avoids N different napi structs while keeping the
 /// FFI surface small.
+use std::sync::OnceLock;
+use std::time::Instant;
+
 use napi_derive::napi;
 
 /// Event payload sent back to JS when a user interacts with an element.
@@ -18,12 +21,29 @@ pub struct EventPayload {
     /// e.g. "click", "mouseDown", "mouseEnter", "keyDown", "scroll", etc.
     pub event_type: String,
 
+    /// Milliseconds since the first event, from a monotonic clock, so
+    /// consecutive payloads can be used for velocities. Set on every event.
+    pub timestamp: f64,
+
     // ── Mouse position ───────────────────────────────────────────────
     /// Mouse X position in window coordinates (pixels).
     pub x: Option<f64>,
     /// Mouse Y position in window coordinates (pixels).
     pub y: Option<f64>,
 
+    /// Mouse position relative to the element's top-left corner.
+    /// Populated for: click, mouseDown, mouseUp, mouseMove,
+    /// mouseDownOutside, scroll.
+    pub local_x: Option<f64>,
+    /// Populated for: click, mouseDown, mouseUp, mouseMove,
+    /// mouseDownOutside, scroll.
+    pub local_y: Option<f64>,
+
+    /// The element's bounds in window coordinates as of the last frame.
+    /// Populated for: click, mouseDown, mouseUp, mouseMove,
+    /// mouseDownOutside, scroll.
+    pub bounds: Option<EventBounds>,
+
     // ── Mouse button ─────────────────────────────────────────────────
     /// Which mouse button: 0=left, 1=middle, 2=right.
     /// Populated for: mouseDown, mouseUp, click, mouseDownOutside, contextMenu.
@@ -43,10 +63,17 @@ pub struct EventPayload {
     pub pressed_button: Option<u32>,
 
     // ── Keyboard ─────────────────────────────────────────────────────
-    /// Key name, e.g. "a", "enter", "escape", "down", "left", "f1".
+    /// Key name, e.g. "a", "enter", "escape", "down", "left", "f1", or the
+    /// DOM KeyboardEvent.key name ("a", "Enter", "ArrowDown") after
+    /// setKeyNames("dom").
     /// Populated for: keyDown, keyUp.
     pub key: Option<String>,
 
+    /// Physical key as a DOM KeyboardEvent.code name, e.g. "KeyA",
+    /// "Digit1", "Space", independent of the keyboard layout.
+    /// Populated for: keyDown, keyUp.
+    pub code: Option<String>,
+
     /// The character produced by the key press (e.g. "ß" for option-s).
     /// May differ from `key` when modifiers are active.
     /// Populated for: keyDown, keyUp.
@@ -78,6 +105,53 @@ pub struct EventPayload {
     /// Populated for: mouseEnter, mouseLeave.
     pub hovered: Option<bool>,
 
+    // ── Links ────────────────────────────────────────────────────────
+    /// The `href` of the clicked inline link span.
+    /// Populated for: click (on text spans with an `href` prop).
+    pub href: Option<String>,
+
+    // ── Text selection ───────────────────────────────────────────────
+    /// Selection start offset in UTF-16 code units (JS string index).
+    /// Populated for: selectionChange.
+    pub selection_start: Option<u32>,
+
+    /// Selection end offset in UTF-16 code units (exclusive).
+    /// Equal to `selection_start` when the selection is collapsed.
+    /// Populated for: selectionChange.
+    pub selection_end: Option<u32>,
+
+    /// The currently selected text ("" when collapsed).
+    /// Populated for: selectionChange.
+    pub selected_text: Option<String>,
+
+    // ── Transitions ──────────────────────────────────────────────────
+    /// camelCase style property that finished transitioning.
+    /// Populated for: transitionEnd.
+    pub property: Option<String>,
+
+    /// Transition duration in seconds, excluding delay (as in DOM
+    /// TransitionEvent.elapsedTime).
+    /// Populated for: transitionEnd.
+    pub elapsed_time: Option<f64>,
+
+    // ── Drag and drop ────────────────────────────────────────────────
+    /// Element ID of the dragged element.
+    /// Populated for: dragEnter, dragOver, dragLeave, drop.
+    pub drag_source: Option<f64>,
+
+    /// The dragged element's `dragData` (null when it has none).
+    /// Populated for: dragEnter, dragOver, dragLeave, drop.
+    pub drag_data: Option<serde_json::Value>,
+
+    /// Paths of the files dragged in from the OS.
+    /// Populated for: fileDragEnter, fileDragLeave, fileDrop.
+    pub paths: Option<Vec<String>>,
+
+    // ── Actions ──────────────────────────────────────────────────────
+    /// Name of the action a key binding dispatched.
+    /// Populated for: action.
+    pub action: Option<String>,
+
     // ── Modifiers ────────────────────────────────────────────────────
     pub modifiers: Option<EventModifiers>,
 }
@@ -87,13 +161,18 @@ impl Default for EventPayload {
         Self {
             element_id: 0.0,
             event_type: String::new(),
+            timestamp: 0.0,
             x: None,
             y: None,
+            local_x: None,
+            local_y: None,
+            bounds: None,
             button: None,
             click_count: None,
             is_right_click: None,
             pressed_button: None,
             key: None,
+            code: None,
             key_char: None,
             is_held: None,
             delta_x: None,
@@ -101,11 +180,59 @@ impl Default for EventPayload {
             precise: None,
             touch_phase: None,
             hovered: None,
+            href: None,
+            selection_start: None,
+            selection_end: None,
+            selected_text: None,
+            property: None,
+            elapsed_time: None,
+            drag_source: None,
+            drag_data: None,
+            paths: None,
+            action: None,
             modifiers: None,
         }
     }
 }
 
+/// What a synchronous event handler returns (see setSyncEventHandler).
+/// Ignored for queued events, which GPUI has already finished dispatching.
+#[derive(Debug, Clone, Default)]
+#[napi(object)]
+pub struct EventResponse {
+    /// Stop the event reaching the remaining elements on its path.
+    pub stop_propagation: Option<bool>,
+    /// Skip GPUI's default handling, e.g. focusing a focusable element on
+    /// mouseDown.
+    pub prevent_default: Option<bool>,
+}
+
+/// Milliseconds since the first call, for EventPayload::timestamp.
+pub fn event_timestamp() -> f64 {
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct EventBounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl From<gpui::Bounds<gpui::Pixels>> for EventBounds {
+    fn from(b: gpui::Bounds<gpui::Pixels>) -> Self {
+        Self {
+            x: f32::from(b.origin.x) as f64,
+            y: f32::from(b.origin.y) as f64,
+            width: f32::from(b.size.width) as f64,
+            height: f32::from(b.size.height) as f64,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[napi(object)]
 pub struct EventModifiers {
@@ -0,0 +1,303 @@
+/// Mouse, scroll, key and action listeners for divs.
+///
+/// Mouse payloads report positions relative to the element too, from its
+/// bounds as its bounds canvas (see bounds.rs) saw them in this frame's
+/// prepaint. Pointer capture and drag and drop wire their events with the
+/// rest of those features, in pointer_capture.rs and drag.rs.
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::element_tree::EventPayload;
+use crate::renderer::{emit_event_full, mouse_button_to_u32, point_to_xy, EventCallback};
+use crate::retained_tree::{RetainedElement, RetainedTree};
+use crate::sync_dispatch::respond;
+
+/// Events that hit-test the element and are dropped under `pointerEvents: "none"`.
+const MOUSE_EVENTS: &[&str] = &[
+    "click",
+    "mouseDown",
+    "mouseUp",
+    "mouseMove",
+    "mouseEnter",
+    "mouseLeave",
+    "scroll",
+    "dragEnter",
+    "dragOver",
+    "dragLeave",
+    "drop",
+    "fileDragEnter",
+    "fileDragLeave",
+    "fileDrop",
+];
+
+/// Events whose payloads carry the element's bounds.
+const POSITIONAL_EVENTS: &[&str] = &[
+    "click",
+    "mouseDown",
+    "mouseUp",
+    "mouseMove",
+    "mouseDownOutside",
+    "scroll",
+];
+
+/// True if the element listens to an event with a position, and so needs
+/// its bounds in `target`.
+pub fn is_positional(element: &RetainedElement) -> bool {
+    POSITIONAL_EVENTS
+        .iter()
+        .any(|event| element.events.contains(*event))
+}
+
+/// Wire the element's listeners. Some events (on_hover, on_click) require a
+/// stateful element (.id()); others (on_mouse_down, on_key_down) work on any
+/// InteractiveElement. `target` holds the element's bounds by the time any
+/// of them fire. Under `pointerEvents: "none"` mouse events are left out.
+pub fn wire(
+    mut el: gpui::Stateful<gpui::Div>,
+    element: &RetainedElement,
+    tree: &RetainedTree,
+    event_callback: &Option<EventCallback>,
+    target: &Rc<Cell<gpui::Bounds<gpui::Pixels>>>,
+    pointer_events: bool,
+) -> gpui::Stateful<gpui::Div> {
+    use gpui::prelude::*;
+
+    let capture_pointer = crate::pointer_capture::is_enabled(element);
+    for event_type in &element.events {
+        if !pointer_events && MOUSE_EVENTS.contains(&event_type.as_str()) {
+            continue;
+        }
+        let id = element.id;
+        let callback = event_callback.clone();
+        let target = target.clone();
+        match event_type.as_str() {
+            // ── Click ────────────────────────────────────────────
+            "click" => {
+                el = el.on_click(move |click_event, window, cx| {
+                    let response = emit_event_full(&callback, id, "click", |p| {
+                        fill_position(p, click_event.position(), target.get());
+                        p.modifiers = Some(click_event.modifiers().into());
+                        p.click_count = Some(click_event.click_count() as u32);
+                        p.is_right_click = Some(click_event.is_right_click());
+                    });
+                    respond(response, window, cx);
+                });
+            }
+
+            // ── Mouse down (all buttons) ─────────────────────────
+            "mouseDown" => {
+                // Wire all three buttons so JS gets right-click, middle-click, etc.
+                for &button in &[
+                    gpui::MouseButton::Left,
+                    gpui::MouseButton::Middle,
+                    gpui::MouseButton::Right,
+                ] {
+                    let callback = callback.clone();
+                    let target = target.clone();
+                    el = el.on_mouse_down(button, move |mouse_event, window, cx| {
+                        let response = emit_event_full(&callback, id, "mouseDown", |p| {
+                            fill_position(p, mouse_event.position, target.get());
+                            p.button = Some(mouse_button_to_u32(mouse_event.button));
+                            p.click_count = Some(mouse_event.click_count as u32);
+                            p.modifiers = Some(mouse_event.modifiers.into());
+                        });
+                        respond(response, window, cx);
+                    });
+                }
+            }
+
+            // Wired with pointer capture, which also sees the
+            // release of a captured press outside the element.
+            "mouseUp" if capture_pointer => {}
+
+            // ── Mouse up (all buttons) ───────────────────────────
+            "mouseUp" => {
+                for &button in &[
+                    gpui::MouseButton::Left,
+                    gpui::MouseButton::Middle,
+                    gpui::MouseButton::Right,
+                ] {
+                    let callback = callback.clone();
+                    let target = target.clone();
+                    el = el.on_mouse_up(button, move |mouse_event, window, cx| {
+                        let response = emit_event_full(&callback, id, "mouseUp", |p| {
+                            fill_position(p, mouse_event.position, target.get());
+                            p.button = Some(mouse_button_to_u32(mouse_event.button));
+                            p.click_count = Some(mouse_event.click_count as u32);
+                            p.modifiers = Some(mouse_event.modifiers.into());
+                        });
+                        respond(response, window, cx);
+                    });
+                }
+            }
+
+            // ── Mouse move ───────────────────────────────────────
+            "mouseMove" => {
+                let capture = tree.pointer_capture.clone();
+                el = el.on_mouse_move(move |mouse_event, window, cx| {
+                    // The capture listener reports these.
+                    if crate::pointer_capture::is_captured_by(&capture, id) {
+                        return;
+                    }
+                    let response = emit_event_full(&callback, id, "mouseMove", |p| {
+                        fill_position(p, mouse_event.position, target.get());
+                        p.modifiers = Some(mouse_event.modifiers.into());
+                        p.pressed_button = mouse_event.pressed_button.map(mouse_button_to_u32);
+                    });
+                    respond(response, window, cx);
+                });
+            }
+
+            // Wired by hover(): they share on_hover with tooltips.
+            "mouseEnter" | "mouseLeave" => {}
+
+            // ── Mouse down outside ───────────────────────────────
+            // Fires when the user clicks OUTSIDE this element.
+            // Critical for "click outside to close" pattern (dropdowns, modals).
+            "mouseDownOutside" => {
+                el = el.on_mouse_down_out(move |mouse_event, window, cx| {
+                    let response = emit_event_full(&callback, id, "mouseDownOutside", |p| {
+                        fill_position(p, mouse_event.position, target.get());
+                        p.button = Some(mouse_button_to_u32(mouse_event.button));
+                        p.modifiers = Some(mouse_event.modifiers.into());
+                    });
+                    respond(response, window, cx);
+                });
+            }
+
+            // ── Scroll wheel ─────────────────────────────────────
+            "scroll" => {
+                el = el.on_scroll_wheel(move |scroll_event, window, cx| {
+                    let response = emit_event_full(&callback, id, "scroll", |p| {
+                        fill_position(p, scroll_event.position, target.get());
+                        p.modifiers = Some(scroll_event.modifiers.into());
+                        p.precise = Some(scroll_event.delta.precise());
+
+                        // Convert ScrollDelta to pixel values.
+                        // For Lines delta, we use a default line height of 20px.
+                        let line_height = gpui::px(20.0);
+                        let pixel_delta = scroll_event.delta.pixel_delta(line_height);
+                        p.delta_x = Some(f64::from(f32::from(pixel_delta.x)));
+                        p.delta_y = Some(f64::from(f32::from(pixel_delta.y)));
+
+                        p.touch_phase = Some(match scroll_event.touch_phase {
+                            gpui::TouchPhase::Started => "started".to_string(),
+                            gpui::TouchPhase::Moved => "moved".to_string(),
+                            gpui::TouchPhase::Ended => "ended".to_string(),
+                        });
+                    });
+                    respond(response, window, cx);
+                });
+            }
+
+            // ── Key down ─────────────────────────────────────────
+            // Requires a focus handle (see build_div). Element must be focused
+            // (clicked or tabbed to) for these to fire.
+            "keyDown" => {
+                let key_names = tree.key_names;
+                el = el.on_key_down(move |key_event, window, cx| {
+                    let response = emit_event_full(&callback, id, "keyDown", |p| {
+                        crate::keyboard::fill(p, &key_event.keystroke, key_names);
+                        p.is_held = Some(key_event.is_held);
+                    });
+                    respond(response, window, cx);
+                });
+            }
+
+            // ── Key up ───────────────────────────────────────────
+            "keyUp" => {
+                let key_names = tree.key_names;
+                el = el.on_key_up(move |key_event, window, cx| {
+                    let response = emit_event_full(&callback, id, "keyUp", |p| {
+                        crate::keyboard::fill(p, &key_event.keystroke, key_names);
+                    });
+                    respond(response, window, cx);
+                });
+            }
+
+            // ── Focus / Blur ─────────────────────────────────────
+            // Event emission is handled by FocusHandle subscriptions
+            // set up in GpuixView::sync_focus_handles(). build_div
+            // attaches the handle to the element with .track_focus().
+            "focus" | "blur" => {}
+
+            // ── Key binding actions ──────────────────────────────
+            // GPUI stops an action at the first listener; it bubbles on to
+            // outer onAction elements unless JS stops it. The root's
+            // listener is keymap::deliver_unhandled, which runs last.
+            "action" if tree.root_id != Some(id) => {
+                el = el.on_action(move |action: &crate::keymap::KeyAction, window, cx| {
+                    let response = emit_event_full(&callback, id, "action", |p| {
+                        p.action = Some(action.name.clone());
+                    });
+                    cx.propagate();
+                    respond(response, window, cx);
+                });
+            }
+
+            // Wired by drag::target().
+            "dragEnter" | "dragOver" | "dragLeave" | "drop" | "fileDragEnter" | "fileDragLeave"
+            | "fileDrop" => {}
+
+            _ => {}
+        }
+    }
+    el
+}
+
+/// Wire mouseEnter and mouseLeave, and the tooltip's hover listener when
+/// it has a `tooltip_delay`. GPUI's on_hover fires with true on enter, false
+/// on leave, and keeps a single listener per element, so they share it.
+pub fn hover(
+    el: gpui::Stateful<gpui::Div>,
+    element: &RetainedElement,
+    tree: &RetainedTree,
+    event_callback: &Option<EventCallback>,
+    tooltip_delay: Option<Duration>,
+) -> gpui::Stateful<gpui::Div> {
+    use gpui::StatefulInteractiveElement as _;
+
+    let callback = |event: &str| {
+        event_callback
+            .clone()
+            .filter(|_| element.events.contains(event))
+    };
+    let callback_enter = callback("mouseEnter");
+    let callback_leave = callback("mouseLeave");
+    if callback_enter.is_none() && callback_leave.is_none() && tooltip_delay.is_none() {
+        return el;
+    }
+    let id = element.id;
+    let tooltips = tree.tooltips.clone();
+    el.on_hover(move |&is_hovered, window, cx| {
+        if is_hovered {
+            emit_event_full(&callback_enter, id, "mouseEnter", |p| {
+                p.hovered = Some(true);
+            });
+        } else {
+            emit_event_full(&callback_leave, id, "mouseLeave", |p| {
+                p.hovered = Some(false);
+            });
+        }
+        if let Some(delay) = tooltip_delay {
+            crate::tooltip::on_hover(&tooltips, id, delay, is_hovered, window, cx);
+        }
+    })
+}
+
+/// Set a mouse payload's position, in the window and relative to the
+/// target, and the target's bounds.
+pub fn fill_position(
+    p: &mut EventPayload,
+    position: gpui::Point<gpui::Pixels>,
+    bounds: gpui::Bounds<gpui::Pixels>,
+) {
+    let (x, y) = point_to_xy(position);
+    let (local_x, local_y) = point_to_xy(position - bounds.origin);
+    p.x = Some(x);
+    p.y = Some(y);
+    p.local_x = Some(local_x);
+    p.local_y = Some(local_y);
+    p.bounds = Some(bounds.into());
+}
@@ -0,0 +1,565 @@
+/// Inline text — flattens a `text` element with nested `text` spans into a
+/// single GPUI StyledText so the whole paragraph lays out and wraps as one
+/// text run instead of one block per child.
+///
+/// Each span contributes a byte range with a HighlightStyle built from its
+/// own style (merged over its ancestor spans). Spans carrying an `href`
+/// custom prop become link ranges rendered through GPUI's InteractiveText:
+/// pointer cursor over the range, underline while hovered, and a "click"
+/// event emitted on the span's element ID with the link's href.
+///
+/// Text with the `selectable` prop is wrapped in a focusable div that
+/// tracks a byte-range selection over the flattened text: drag to select,
+/// double-click selects a word, triple-click selects a line, and
+/// Ctrl/Cmd+C copies the selection to the clipboard. Selection changes are
+/// emitted to JS as "selectionChange" events on the root text element.
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::renderer::{emit_event_full, point_to_xy, EventCallback};
+use crate::retained_tree::{RetainedElement, RetainedTree};
+use crate::style::{parse_color_hex, parse_text_decoration, transform_text, StyleDesc};
+
+/// Per-text-element interaction state that must survive across frames.
+/// Stored in GpuixView::text_states, keyed by the root text element ID.
+#[derive(Default, Clone)]
+pub(crate) struct TextInteractionState {
+    /// Index into InlineText::links of the link under the mouse, if any.
+    /// Written by InteractiveText's hover listener, read on the next render.
+    pub hovered_link: Rc<Cell<Option<usize>>>,
+    /// Current selection for `selectable` text. Written by the mouse
+    /// listeners, read on the next render to paint the highlight.
+    pub selection: Rc<RefCell<TextSelection>>,
+}
+
+/// Byte-range selection over InlineText::text.
+#[derive(Default)]
+pub(crate) struct TextSelection {
+    /// Where the drag started — the fixed end of the selection.
+    anchor: usize,
+    range: Range<usize>,
+    /// true between a single-click mouse-down and the matching mouse-up.
+    selecting: bool,
+}
+
+/// Background painted behind selected text.
+const SELECTION_BACKGROUND: u32 = 0x3390ff66;
+
+/// A clickable link range inside an inline text paragraph.
+pub(crate) struct InlineLink {
+    /// Element ID of the span that declared the `href`.
+    pub element_id: u64,
+    pub href: String,
+    /// Byte range of the span's text in InlineText::text.
+    pub range: Range<usize>,
+}
+
+/// Flattened paragraph: concatenated text, one highlight per segment, links.
+#[derive(Default)]
+pub(crate) struct InlineText {
+    pub text: String,
+    /// Contiguous, non-overlapping, sorted ranges — one per text segment.
+    pub highlights: Vec<(Range<usize>, gpui::HighlightStyle)>,
+    pub links: Vec<InlineLink>,
+}
+
+/// True when every descendant of `element` is a `text` element, so the
+/// subtree can be flattened into a single StyledText.
+pub(crate) fn is_inline_text(element: &RetainedElement, tree: &RetainedTree) -> bool {
+    element.children.iter().all(|child_id| {
+        tree.elements
+            .get(child_id)
+            .map(|child| child.element_type == "text" && is_inline_text(child, tree))
+            .unwrap_or(true)
+    })
+}
+
+/// True when the element has the `selectable` prop set to true.
+pub(crate) fn is_selectable(element: &RetainedElement) -> bool {
+    element
+        .custom_props
+        .get("selectable")
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false)
+}
+
+/// Flatten `root` and its span descendants depth-first.
+/// The root's own style is applied by the wrapping div in build_text(), so
+/// only descendant spans contribute highlight styles.
+pub(crate) fn collect_inline_text(root: &RetainedElement, tree: &RetainedTree) -> InlineText {
+    let mut inline = InlineText::default();
+    let transform = root.style.as_ref().and_then(|s| s.text_transform.as_deref());
+    collect_segments(root, tree, gpui::HighlightStyle::default(), transform, &mut inline);
+    inline
+}
+
+fn collect_segments(
+    element: &RetainedElement,
+    tree: &RetainedTree,
+    highlight: gpui::HighlightStyle,
+    transform: Option<&str>,
+    inline: &mut InlineText,
+) {
+    let start = inline.text.len();
+
+    if let Some(ref content) = element.content {
+        if !content.is_empty() {
+            inline.text.push_str(&transform_text(content, transform));
+            inline
+                .highlights
+                .push((start..inline.text.len(), highlight));
+        }
+    }
+
+    for child_id in &element.children {
+        let Some(child) = tree.elements.get(child_id) else {
+            continue;
+        };
+        let child_highlight = match crate::renderer::resolve_style(child, tree) {
+            Some(style) => merge_highlight(highlight, span_highlight(&style)),
+            None => highlight,
+        };
+        let child_transform = child
+            .style
+            .as_ref()
+            .and_then(|style| style.text_transform.as_deref())
+            .or(transform);
+        collect_segments(child, tree, child_highlight, child_transform, inline);
+    }
+
+    if let Some(href) = element
+        .custom_props
+        .get("href")
+        .and_then(|value| value.as_str())
+    {
+        let end = inline.text.len();
+        if end > start {
+            inline.links.push(InlineLink {
+                element_id: element.id,
+                href: href.to_string(),
+                range: start..end,
+            });
+        }
+    }
+}
+
+/// Map the text-related subset of a span's StyleDesc to a HighlightStyle.
+/// `textDecoration: "none"` maps to zero-thickness lines so it can clear a
+/// decoration inherited from an ancestor span.
+fn span_highlight(style: &StyleDesc) -> gpui::HighlightStyle {
+    let color = style
+        .color
+        .as_deref()
+        .and_then(parse_color_hex)
+        .map(|hex| gpui::rgba(hex).into());
+    let decoration = parse_text_decoration(style);
+    let decoration_color = decoration
+        .as_ref()
+        .and_then(|d| d.color)
+        .map(|hex| gpui::rgba(hex).into())
+        .or(color);
+    let thickness = |on: bool| if on { gpui::px(1.0) } else { gpui::px(0.0) };
+
+    gpui::HighlightStyle {
+        color,
+        font_weight: style
+            .font_weight
+            .as_ref()
+            .map(crate::renderer::parse_font_weight),
+        font_style: match style.font_style.as_deref() {
+            Some("italic") | Some("oblique") => Some(gpui::FontStyle::Italic),
+            Some("normal") => Some(gpui::FontStyle::Normal),
+            _ => None,
+        },
+        background_color: style
+            .background_color
+            .as_deref()
+            .or(style.background.as_deref())
+            .and_then(parse_color_hex)
+            .map(|hex| gpui::rgba(hex).into()),
+        underline: decoration.as_ref().map(|d| gpui::UnderlineStyle {
+            thickness: thickness(d.underline),
+            color: decoration_color,
+            wavy: d.wavy,
+        }),
+        strikethrough: decoration.as_ref().map(|d| gpui::StrikethroughStyle {
+            thickness: thickness(d.line_through),
+            color: decoration_color,
+        }),
+        ..Default::default()
+    }
+}
+
+/// Child span fields override the parent's (CSS inheritance, not blending).
+fn merge_highlight(
+    mut parent: gpui::HighlightStyle,
+    child: gpui::HighlightStyle,
+) -> gpui::HighlightStyle {
+    if child.color.is_some() {
+        parent.color = child.color;
+    }
+    if child.font_weight.is_some() {
+        parent.font_weight = child.font_weight;
+    }
+    if child.font_style.is_some() {
+        parent.font_style = child.font_style;
+    }
+    if child.background_color.is_some() {
+        parent.background_color = child.background_color;
+    }
+    if child.underline.is_some() {
+        parent.underline = child.underline;
+    }
+    if child.strikethrough.is_some() {
+        parent.strikethrough = child.strikethrough;
+    }
+    parent
+}
+
+/// Build the GPUI element for a flattened paragraph.
+/// Without links this is a plain StyledText; with links it is wrapped in
+/// InteractiveText so link ranges get hit testing, cursor and click handling.
+/// Selectable text is additionally wrapped in a focusable selection div.
+pub(crate) fn render_inline_text(
+    element: &RetainedElement,
+    mut inline: InlineText,
+    state: &TextInteractionState,
+    focus_handle: Option<&gpui::FocusHandle>,
+    event_callback: &Option<EventCallback>,
+) -> gpui::AnyElement {
+    use gpui::IntoElement;
+
+    let root_id = element.id;
+    let selectable = is_selectable(element);
+
+    // Underline the hovered link. Segments never straddle a link boundary
+    // (links are whole spans), so containment is enough.
+    if let Some(link) = state
+        .hovered_link
+        .get()
+        .and_then(|ix| inline.links.get(ix))
+    {
+        for (range, highlight) in inline.highlights.iter_mut() {
+            if range.start >= link.range.start && range.end <= link.range.end {
+                highlight.underline = Some(gpui::UnderlineStyle {
+                    thickness: gpui::px(1.0),
+                    color: highlight.color,
+                    wavy: false,
+                });
+            }
+        }
+    }
+
+    if selectable {
+        let mut selection = state.selection.borrow_mut();
+        // The text may have changed since the selection was made.
+        let range = selection.range.clone();
+        if range.end > inline.text.len()
+            || !inline.text.is_char_boundary(range.start)
+            || !inline.text.is_char_boundary(range.end)
+        {
+            *selection = TextSelection::default();
+        } else if !range.is_empty() {
+            inline.highlights = highlight_selection(inline.highlights, &range);
+        }
+    }
+
+    let text = gpui::SharedString::from(inline.text);
+    let styled = gpui::StyledText::new(text.clone()).with_highlights(inline.highlights);
+    let layout = styled.layout().clone();
+
+    let content = if inline.links.is_empty() {
+        styled.into_any_element()
+    } else {
+        let ranges: Vec<Range<usize>> =
+            inline.links.iter().map(|link| link.range.clone()).collect();
+        let targets: Vec<(u64, String)> = inline
+            .links
+            .into_iter()
+            .map(|link| (link.element_id, link.href))
+            .collect();
+
+        let text_id = gpui::SharedString::from(format!("__gpuix_text_{}", root_id));
+        let hovered_link = state.hovered_link.clone();
+        let hover_ranges = ranges.clone();
+        let callback = event_callback.clone();
+
+        gpui::InteractiveText::new(text_id, styled)
+            .on_click(ranges, move |range_ix, window, _cx| {
+                if let Some((span_id, href)) = targets.get(range_ix) {
+                    emit_event_full(&callback, *span_id, "click", |p| {
+                        let (x, y) = point_to_xy(window.mouse_position());
+                        p.x = Some(x);
+                        p.y = Some(y);
+                        p.href = Some(href.clone());
+                    });
+                }
+            })
+            .on_hover(move |char_ix, _event, _window, _cx| {
+                // InteractiveText notifies the view when the hovered index
+                // changes, so the next render picks up the new underline.
+                let hovered = char_ix
+                    .and_then(|ix| hover_ranges.iter().position(|range| range.contains(&ix)));
+                hovered_link.set(hovered);
+            })
+            .into_any_element()
+    };
+
+    if !selectable {
+        return content;
+    }
+
+    render_selectable(
+        root_id,
+        content,
+        text,
+        layout,
+        state,
+        focus_handle,
+        element.events.contains("selectionChange"),
+        event_callback,
+    )
+}
+
+/// Wrap paragraph content in a div that drives mouse selection and copy.
+#[allow(clippy::too_many_arguments)]
+fn render_selectable(
+    root_id: u64,
+    content: gpui::AnyElement,
+    text: gpui::SharedString,
+    layout: gpui::TextLayout,
+    state: &TextInteractionState,
+    focus_handle: Option<&gpui::FocusHandle>,
+    emit_changes: bool,
+    event_callback: &Option<EventCallback>,
+) -> gpui::AnyElement {
+    use gpui::prelude::*;
+
+    let notify = move |callback: &Option<EventCallback>, text: &str, range: &Range<usize>| {
+        if emit_changes {
+            emit_selection_change(callback, root_id, text, range);
+        }
+    };
+
+    let mut el = gpui::div()
+        .id(gpui::SharedString::from(format!("__gpuix_text_sel_{}", root_id)))
+        .cursor(gpui::CursorStyle::IBeam)
+        .child(content);
+
+    if let Some(handle) = focus_handle {
+        el = el.track_focus(handle);
+    }
+
+    // Mouse down: collapse, select word (2 clicks) or line (3+ clicks).
+    {
+        let selection = state.selection.clone();
+        let layout = layout.clone();
+        let text = text.clone();
+        let callback = event_callback.clone();
+        let focus_handle = focus_handle.cloned();
+        el = el.on_mouse_down(gpui::MouseButton::Left, move |event, window, cx| {
+            let ix = index_for_position(&layout, event.position);
+            let mut selection = selection.borrow_mut();
+            let range = match event.click_count {
+                0 | 1 if event.modifiers.shift => {
+                    ix.min(selection.anchor)..ix.max(selection.anchor)
+                }
+                0 | 1 => {
+                    selection.anchor = ix;
+                    ix..ix
+                }
+                2 => word_range(&text, ix),
+                _ => line_range(&text, ix),
+            };
+            if event.click_count > 1 {
+                selection.anchor = range.start;
+            }
+            selection.selecting = event.click_count <= 1;
+            if selection.range != range {
+                selection.range = range;
+                notify(&callback, &text, &selection.range);
+            }
+            if let Some(ref handle) = focus_handle {
+                handle.focus(window, cx);
+            }
+            window.refresh();
+        });
+    }
+
+    // Drag: extend from the anchor to the character under the mouse.
+    // Window listeners, registered while selecting, keep following the
+    // mouse past the end of a line and outside the element, and end the
+    // drag wherever the button is released.
+    {
+        let selection = state.selection.clone();
+        let layout = layout.clone();
+        let text = text.clone();
+        let callback = event_callback.clone();
+        el = el.child(
+            gpui::canvas(
+                |_, _, _| {},
+                move |_, _, window, _cx| {
+                    if !selection.borrow().selecting {
+                        return;
+                    }
+                    window.on_mouse_event({
+                        let selection = selection.clone();
+                        move |event: &gpui::MouseMoveEvent, phase, window, _cx| {
+                            let mut selection = selection.borrow_mut();
+                            if phase != gpui::DispatchPhase::Bubble || !selection.selecting {
+                                return;
+                            }
+                            // The release never reached the window.
+                            if event.pressed_button != Some(gpui::MouseButton::Left) {
+                                selection.selecting = false;
+                                return;
+                            }
+                            let ix = index_for_position(&layout, event.position);
+                            let range = ix.min(selection.anchor)..ix.max(selection.anchor);
+                            if selection.range != range {
+                                selection.range = range;
+                                notify(&callback, &text, &selection.range);
+                                window.refresh();
+                            }
+                        }
+                    });
+                    window.on_mouse_event(
+                        move |event: &gpui::MouseUpEvent, phase, _window, _cx| {
+                            if phase == gpui::DispatchPhase::Bubble
+                                && event.button == gpui::MouseButton::Left
+                            {
+                                selection.borrow_mut().selecting = false;
+                            }
+                        },
+                    );
+                },
+            )
+            .absolute()
+            .size_full(),
+        );
+    }
+
+    // Clicking anywhere else clears the selection, like a browser page.
+    {
+        let selection = state.selection.clone();
+        let text = text.clone();
+        let callback = event_callback.clone();
+        el = el.on_mouse_down_out(move |_event, window, _cx| {
+            let mut selection = selection.borrow_mut();
+            selection.selecting = false;
+            if !selection.range.is_empty() {
+                selection.range = selection.anchor..selection.anchor;
+                notify(&callback, &text, &selection.range);
+                window.refresh();
+            }
+        });
+    }
+
+    // Ctrl/Cmd+C copies the selected text.
+    {
+        let selection = state.selection.clone();
+        el = el.on_key_down(move |event, _window, cx| {
+            let keystroke = &event.keystroke;
+            if keystroke.key == "c" && keystroke.modifiers.secondary() {
+                let range = selection.borrow().range.clone();
+                if let Some(selected) = text.get(range).filter(|s| !s.is_empty()) {
+                    cx.write_to_clipboard(gpui::ClipboardItem::new_string(selected.to_string()));
+                    cx.stop_propagation();
+                }
+            }
+        });
+    }
+
+    el.into_any_element()
+}
+
+/// Byte index of the character boundary closest to `position`.
+fn index_for_position(layout: &gpui::TextLayout, position: gpui::Point<gpui::Pixels>) -> usize {
+    match layout.index_for_position(position) {
+        Ok(ix) | Err(ix) => ix,
+    }
+}
+
+/// Range of the word containing `ix`. Falls back to the single character
+/// under the cursor for punctuation and whitespace.
+fn word_range(text: &str, ix: usize) -> Range<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let ix = ix.min(text.len());
+
+    let start = text[..ix]
+        .char_indices()
+        .rev()
+        .take_while(|&(_, c)| is_word(c))
+        .last()
+        .map(|(i, _)| i)
+        .unwrap_or(ix);
+    let end = text[ix..]
+        .char_indices()
+        .find(|&(_, c)| !is_word(c))
+        .map(|(i, _)| ix + i)
+        .unwrap_or(text.len());
+
+    if start == end {
+        let next = text[ix..].chars().next().map(char::len_utf8).unwrap_or(0);
+        return ix..ix + next;
+    }
+    start..end
+}
+
+/// Range of the line containing `ix`, delimited by newlines.
+fn line_range(text: &str, ix: usize) -> Range<usize> {
+    let ix = ix.min(text.len());
+    let start = text[..ix].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let end = text[ix..].find('\n').map(|i| ix + i).unwrap_or(text.len());
+    start..end
+}
+
+/// Split highlight segments at the selection boundaries and set the
+/// selection background on the parts inside it. Segments cover the whole
+/// text, so the output stays sorted and non-overlapping.
+fn highlight_selection(
+    highlights: Vec<(Range<usize>, gpui::HighlightStyle)>,
+    selection: &Range<usize>,
+) -> Vec<(Range<usize>, gpui::HighlightStyle)> {
+    let background = Some(gpui::rgba(SELECTION_BACKGROUND).into());
+    let mut result = Vec::with_capacity(highlights.len() + 2);
+
+    for (range, highlight) in highlights {
+        let inner = range.start.max(selection.start)..range.end.min(selection.end);
+        if inner.is_empty() {
+            result.push((range, highlight));
+            continue;
+        }
+        if range.start < inner.start {
+            result.push((range.start..inner.start, highlight));
+        }
+        result.push((
+            inner.clone(),
+            gpui::HighlightStyle {
+                background_color: background,
+                ..highlight
+            },
+        ));
+        if inner.end < range.end {
+            result.push((inner.end..range.end, highlight));
+        }
+    }
+    result
+}
+
+/// Emit "selectionChange" with UTF-16 offsets and the selected text.
+fn emit_selection_change(
+    callback: &Option<EventCallback>,
+    root_id: u64,
+    text: &str,
+    range: &Range<usize>,
+) {
+    // JS strings index by UTF-16 code units, not bytes.
+    let utf16_offset = |byte: usize| text[..byte].encode_utf16().count() as u32;
+    emit_event_full(callback, root_id, "selectionChange", |p| {
+        p.selection_start = Some(utf16_offset(range.start));
+        p.selection_end = Some(utf16_offset(range.end));
+        p.selected_text = Some(text[range.clone()].to_string());
+    });
+}
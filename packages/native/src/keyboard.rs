@@ -0,0 +1,162 @@
+/// Key names and physical key codes for keyDown/keyUp payloads.
+///
+/// GPUI keystrokes carry GPUI key names ("enter", "down", "a") and nothing
+/// about the physical key. NodePlatform::tick() dispatches each winit
+/// KeyboardInput inside with_code(), and the key listeners read the code
+/// back while GPUI delivers the event. Keys dispatched without one (the
+/// test renderer's simulate_* calls) get the code of that key on a US
+/// layout.
+///
+/// With setKeyNames("dom"), `key` holds DOM KeyboardEvent.key names instead
+/// ("Enter", "ArrowDown", "A" with shift), so web keyboard handling can be
+/// reused as is.
+use std::cell::RefCell;
+
+use crate::element_tree::EventPayload;
+
+/// Which names `key` uses in payloads.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum KeyNames {
+    #[default]
+    Gpui,
+    Dom,
+}
+
+impl KeyNames {
+    pub fn parse(names: &str) -> Result<Self, String> {
+        match names {
+            "gpui" => Ok(KeyNames::Gpui),
+            "dom" => Ok(KeyNames::Dom),
+            _ => Err(format!(
+                "Unknown key names {:?} (expected \"gpui\" or \"dom\")",
+                names
+            )),
+        }
+    }
+}
+
+thread_local! {
+    /// Physical key of the KeyboardInput being dispatched.
+    static CURRENT_CODE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run `dispatch` with `code` as the physical key of the keys it delivers.
+pub fn with_code<R>(code: String, dispatch: impl FnOnce() -> R) -> R {
+    let previous = CURRENT_CODE.with(|current| current.replace(Some(code)));
+    let result = dispatch();
+    CURRENT_CODE.with(|current| *current.borrow_mut() = previous);
+    result
+}
+
+/// Fill a keyDown/keyUp payload's key, keyChar, code and modifiers.
+pub fn fill(p: &mut EventPayload, keystroke: &gpui::Keystroke, names: KeyNames) {
+    p.key = Some(match names {
+        KeyNames::Gpui => keystroke.key.clone(),
+        KeyNames::Dom => dom_key(keystroke),
+    });
+    p.key_char = keystroke.key_char.clone();
+    p.code = Some(
+        CURRENT_CODE
+            .with(|current| current.borrow().clone())
+            .unwrap_or_else(|| us_code(&keystroke.key)),
+    );
+    p.modifiers = Some(keystroke.modifiers.into());
+}
+
+/// DOM names of GPUI's named keys. Keys where the two agree apart from
+/// case ("Enter", "F1") are listed too, since GPUI's are lowercase.
+fn dom_named_key(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "enter" => "Enter",
+        "backspace" => "Backspace",
+        "tab" => "Tab",
+        "escape" => "Escape",
+        "delete" => "Delete",
+        "space" => " ",
+        "left" => "ArrowLeft",
+        "right" => "ArrowRight",
+        "up" => "ArrowUp",
+        "down" => "ArrowDown",
+        "home" => "Home",
+        "end" => "End",
+        "pageup" => "PageUp",
+        "pagedown" => "PageDown",
+        "insert" => "Insert",
+        "contextmenu" => "ContextMenu",
+        "printscreen" => "PrintScreen",
+        "pause" => "Pause",
+        "numlock" => "NumLock",
+        "scrolllock" => "ScrollLock",
+        "f1" => "F1",
+        "f2" => "F2",
+        "f3" => "F3",
+        "f4" => "F4",
+        "f5" => "F5",
+        "f6" => "F6",
+        "f7" => "F7",
+        "f8" => "F8",
+        "f9" => "F9",
+        "f10" => "F10",
+        "f11" => "F11",
+        "f12" => "F12",
+        "f13" => "F13",
+        "f14" => "F14",
+        "f15" => "F15",
+        "f16" => "F16",
+        "f17" => "F17",
+        "f18" => "F18",
+        "f19" => "F19",
+        "f20" => "F20",
+        "unidentified" => "Unidentified",
+        "dead" => "Dead",
+        _ => return None,
+    })
+}
+
+/// The DOM `key` for a keystroke: the named key, else the character it
+/// types. With ctrl or cmd held there is no typed character, so it is the
+/// key itself, uppercased with shift like a browser does.
+fn dom_key(keystroke: &gpui::Keystroke) -> String {
+    if let Some(name) = dom_named_key(&keystroke.key) {
+        return name.to_string();
+    }
+    match &keystroke.key_char {
+        Some(key_char) if !key_char.is_empty() => key_char.clone(),
+        _ if keystroke.modifiers.shift => keystroke.key.to_uppercase(),
+        _ => keystroke.key.clone(),
+    }
+}
+
+/// The physical key that types GPUI key `key` on a US layout.
+fn us_code(key: &str) -> String {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii_alphabetic() {
+            return format!("Key{}", c.to_ascii_uppercase());
+        }
+        if c.is_ascii_digit() {
+            return format!("Digit{}", c);
+        }
+        let code = match c {
+            '-' => "Minus",
+            '=' => "Equal",
+            '[' => "BracketLeft",
+            ']' => "BracketRight",
+            '\\' => "Backslash",
+            ';' => "Semicolon",
+            '\'' => "Quote",
+            ',' => "Comma",
+            '.' => "Period",
+            '/' => "Slash",
+            '`' => "Backquote",
+            _ => "Unidentified",
+        };
+        return code.to_string();
+    }
+    match key {
+        "space" => "Space",
+        // DOM codes of the other named keys match their DOM key names.
+        _ => dom_named_key(key).unwrap_or("Unidentified"),
+    }
+    .to_string()
+}
@@ -0,0 +1,102 @@
+/// Key bindings registered from JS, dispatched as GPUI actions.
+///
+/// registerKeyBindings() binds keystroke sequences to action names. GPUI's
+/// keymap does the matching, including multi-stroke sequences
+/// ("ctrl-k ctrl-s") and `context` predicates, which are checked against
+/// the `keyContext` props of the focused element and its ancestors. Every
+/// binding dispatches the same KeyAction, carrying the name.
+///
+/// Every element with an `action` listener on the focused element's path
+/// gets the action, innermost first, until a synchronous handler returns
+/// stopPropagation. The root element's listener gets it last, from an app
+/// listener rather than its own element, so it also sees every action
+/// while nothing is focused and bindings without a context work as global
+/// shortcuts.
+use std::sync::{Arc, Mutex};
+
+use serde::Deserialize;
+
+use crate::renderer::{emit_event_full, EventCallback};
+use crate::retained_tree::RetainedTree;
+
+/// The action of every JS binding. Built from a binding rather than by
+/// name, so it isn't registered with GPUI's action registry.
+#[derive(Debug, Clone, PartialEq, gpui::Action)]
+#[action(namespace = gpuix, no_json, no_register)]
+pub struct KeyAction {
+    pub name: String,
+}
+
+/// One binding, as passed to registerKeyBindings().
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct KeyBindingSpec {
+    /// Space-separated keystrokes, e.g. "ctrl-k ctrl-s".
+    pub keys: String,
+    pub action: String,
+    /// Key context predicate, e.g. "Editor" or "Editor && !readonly".
+    #[serde(default)]
+    pub context: Option<String>,
+}
+
+impl KeyBindingSpec {
+    fn to_binding(&self) -> gpui::KeyBinding {
+        gpui::KeyBinding::new(
+            &self.keys,
+            KeyAction {
+                name: self.action.clone(),
+            },
+            self.context.as_deref(),
+        )
+    }
+}
+
+/// Parse a JSON array of bindings. GPUI panics on invalid keystrokes and
+/// contexts when binding, so both are checked here.
+pub fn parse_bindings(json: &str) -> Result<Vec<KeyBindingSpec>, String> {
+    let specs: Vec<KeyBindingSpec> =
+        serde_json::from_str(json).map_err(|e| format!("Invalid key bindings: {}", e))?;
+    for spec in &specs {
+        if spec.keys.split_whitespace().next().is_none() {
+            return Err(format!("Key binding for {:?} has no keys", spec.action));
+        }
+        for keystroke in spec.keys.split_whitespace() {
+            gpui::Keystroke::parse(keystroke)
+                .map_err(|e| format!("Invalid keystroke {:?}: {}", keystroke, e))?;
+        }
+        if let Some(context) = &spec.context {
+            gpui::KeyBindingContextPredicate::parse(context)
+                .map_err(|e| format!("Invalid key context {:?}: {}", context, e))?;
+        }
+    }
+    Ok(specs)
+}
+
+/// Replace the app's key bindings with `specs`.
+pub fn bind(specs: &[KeyBindingSpec], cx: &mut gpui::App) {
+    cx.clear_key_bindings();
+    cx.bind_keys(specs.iter().map(KeyBindingSpec::to_binding));
+}
+
+/// Send actions that bubbled past every element listener to the root
+/// element. Called once when the window is opened.
+pub fn deliver_unhandled(
+    tree: Arc<Mutex<RetainedTree>>,
+    callback: Option<EventCallback>,
+    cx: &mut gpui::App,
+) {
+    cx.on_action(move |action: &KeyAction, _cx| {
+        let root = {
+            let tree = tree.lock().unwrap();
+            tree.root_id.filter(|id| {
+                tree.elements
+                    .get(id)
+                    .is_some_and(|root| root.events.contains("action"))
+            })
+        };
+        if let Some(root) = root {
+            emit_event_full(&callback, root, "action", |p| {
+                p.action = Some(action.name.clone());
+            });
+        }
+    });
+}
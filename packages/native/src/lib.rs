@@ -1,11 +1,26 @@
 #![deny(clippy::all)]
 
+mod animation;
+mod borders;
+mod bounds;
 mod custom_elements;
+mod drag;
 mod element_tree;
+mod events;
+mod inline_text;
+mod keyboard;
+mod keymap;
 mod platform;
+mod pointer_capture;
 mod renderer;
 mod retained_tree;
 mod style;
+mod style_class;
+mod sync_dispatch;
+mod theme;
+mod tooltip;
+mod transition;
+mod units;
 
 #[cfg(all(feature = "test-support", target_os = "macos"))]
 mod test_renderer;
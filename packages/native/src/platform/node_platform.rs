@@ -58,6 +58,10 @@ pub struct NodePlatform {
     event_loop: RefCell<Option<winit::event_loop::EventLoop<()>>>,
     /// Shared window state — allows tick() to access callbacks and dispatch events
     window_state: RefCell<Option<Rc<crate::platform::node_window::NodeWindowState>>>,
+    /// OS clipboard. None when no clipboard is available (e.g. headless Linux),
+    /// in which case `fallback_clipboard` keeps copies within the process.
+    clipboard: RefCell<Option<arboard::Clipboard>>,
+    fallback_clipboard: RefCell<Option<ClipboardItem>>,
 }
 
 impl NodePlatform {
@@ -84,6 +88,8 @@ impl NodePlatform {
             wgpu_context: RefCell::new(None),
             event_loop: RefCell::new(None),
             window_state: RefCell::new(None),
+            clipboard: RefCell::new(arboard::Clipboard::new().ok()),
+            fallback_clipboard: RefCell::new(None),
         }
     }
 
@@ -92,9 +98,10 @@ impl NodePlatform {
     /// the window isn't marked dirty. Set to true when render() received a new tree.
     pub fn tick(&self, force_render: bool) {
         use gpui::{
-            point, px, KeyDownEvent, KeyUpEvent, Keystroke, Modifiers, ModifiersChangedEvent,
-            MouseButton, MouseDownEvent, MouseExitEvent, MouseMoveEvent, MouseUpEvent,
-            PlatformInput, RequestFrameOptions, ScrollDelta, ScrollWheelEvent, TouchPhase,
+            point, px, FileDropEvent, KeyDownEvent, KeyUpEvent, Keystroke, Modifiers,
+            ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseExitEvent, MouseMoveEvent,
+            MouseUpEvent, PlatformInput, RequestFrameOptions, ScrollDelta, ScrollWheelEvent,
+            TouchPhase,
         };
         use std::sync::atomic::{AtomicU64, Ordering as AtOrd};
         use std::time::Duration;
@@ -138,10 +145,16 @@ impl NodePlatform {
         }
 
         let events = handler.events;
+        // A drag that ends without a drop refreshes nothing in GPUI, so
+        // render once more to let drop targets see it end.
+        let mut force_render = force_render;
+        let mut hovered_files = Vec::new();
+        let mut dropped_files = Vec::new();
 
         // Process collected events
         if let Some(ref state) = *self.window_state.borrow() {
-            for event in events {
+            let mut events = events.into_iter().peekable();
+            while let Some(event) = events.next() {
                 match event {
                     WindowEvent::CursorMoved { position, .. } => {
                         let scale = state.scale_factor.get();
@@ -151,11 +164,16 @@ impl NodePlatform {
                         );
                         state.mouse_position.set(pos);
 
-                        let input = PlatformInput::MouseMove(MouseMoveEvent {
-                            position: pos,
-                            pressed_button: state.pressed_button.get(),
-                            modifiers: state.modifiers.get(),
-                        });
+                        // GPUI expects moves during a file drag as Pending.
+                        let input = if state.dragged_files.borrow().is_empty() {
+                            PlatformInput::MouseMove(MouseMoveEvent {
+                                position: pos,
+                                pressed_button: state.pressed_button.get(),
+                                modifiers: state.modifiers.get(),
+                            })
+                        } else {
+                            PlatformInput::FileDrop(FileDropEvent::Pending { position: pos })
+                        };
                         let mut cbs = state.callbacks.borrow_mut();
                         if let Some(ref mut cb) = cbs.input {
                             cb(input);
@@ -276,9 +294,12 @@ impl NodePlatform {
                             }
                         };
 
+                        // GPUI keystrokes have no physical key; key listeners
+                        // read it from crate::keyboard during dispatch.
+                        let code = winit_physical_key_to_code(&event.physical_key);
                         let mut cbs = state.callbacks.borrow_mut();
                         if let Some(ref mut cb) = cbs.input {
-                            cb(input);
+                            crate::keyboard::with_code(code, || cb(input));
                         }
                     }
                     WindowEvent::ModifiersChanged(mods_event) => {
@@ -365,6 +386,59 @@ impl NodePlatform {
                             cb(focused);
                         }
                     }
+                    // ── Files dragged in from the OS ─────────────────────
+                    // winit sends one HoveredFile/DroppedFile per file, so
+                    // each run of them becomes a single GPUI event. GPUI
+                    // keeps the paths of the first Entered for the whole
+                    // drag, so files hovered later restart it.
+                    WindowEvent::HoveredFile(path) => {
+                        hovered_files.push(path);
+                        if matches!(events.peek(), Some(WindowEvent::HoveredFile(_))) {
+                            continue;
+                        }
+                        let mut paths = state.dragged_files.take();
+                        let restart = !paths.is_empty();
+                        paths.append(&mut hovered_files);
+                        let entered = FileDropEvent::Entered {
+                            position: state.mouse_position.get(),
+                            paths: external_paths(&paths),
+                        };
+                        state.dragged_files.replace(paths);
+                        if restart {
+                            dispatch_file_drop(state, [FileDropEvent::Exited, entered]);
+                        } else {
+                            dispatch_file_drop(state, [entered]);
+                        }
+                    }
+                    WindowEvent::DroppedFile(path) => {
+                        dropped_files.push(path);
+                        if matches!(events.peek(), Some(WindowEvent::DroppedFile(_))) {
+                            continue;
+                        }
+                        let dropped = std::mem::take(&mut dropped_files);
+                        let hovered = state.dragged_files.take();
+                        let position = state.mouse_position.get();
+                        let mut inputs = Vec::new();
+                        // Some platforms drop without hovering first.
+                        if hovered != dropped {
+                            if !hovered.is_empty() {
+                                inputs.push(FileDropEvent::Exited);
+                            }
+                            inputs.push(FileDropEvent::Entered {
+                                position,
+                                paths: external_paths(&dropped),
+                            });
+                        }
+                        inputs.push(FileDropEvent::Submit { position });
+                        dispatch_file_drop(state, inputs);
+                        force_render = true;
+                    }
+                    WindowEvent::HoveredFileCancelled => {
+                        if !state.dragged_files.take().is_empty() {
+                            dispatch_file_drop(state, [FileDropEvent::Exited]);
+                            force_render = true;
+                        }
+                    }
                     WindowEvent::CloseRequested => {
                         let mut cbs = state.callbacks.borrow_mut();
                         let should_close = cbs.should_close.as_mut().map(|cb| cb()).unwrap_or(true);
@@ -680,10 +754,24 @@ impl Platform for NodePlatform {
     fn on_thermal_state_change(&self, _callback: Box<dyn FnMut()>) {}
 
     fn read_from_clipboard(&self) -> Option<ClipboardItem> {
-        None
+        if let Some(clipboard) = self.clipboard.borrow_mut().as_mut() {
+            return clipboard.get_text().ok().map(ClipboardItem::new_string);
+        }
+        self.fallback_clipboard.borrow().clone()
     }
 
-    fn write_to_clipboard(&self, _item: ClipboardItem) {}
+    fn write_to_clipboard(&self, item: ClipboardItem) {
+        if let Some(clipboard) = self.clipboard.borrow_mut().as_mut() {
+            // Only plain text is supported; images are dropped.
+            if let Some(text) = item.text() {
+                if let Err(e) = clipboard.set_text(text) {
+                    log::warn!("[GPUIX] failed to write clipboard: {}", e);
+                }
+            }
+            return;
+        }
+        *self.fallback_clipboard.borrow_mut() = Some(item);
+    }
 
     // macOS has a "Find Pasteboard" (shared across apps for Cmd+E/Cmd+G).
     #[cfg(target_os = "macos")]
@@ -732,6 +820,24 @@ impl Platform for NodePlatform {
     }
 }
 
+/// Send file drop events to GPUI's input callback, in order.
+fn dispatch_file_drop(
+    state: &crate::platform::node_window::NodeWindowState,
+    events: impl IntoIterator<Item = gpui::FileDropEvent>,
+) {
+    let mut cbs = state.callbacks.borrow_mut();
+    if let Some(ref mut cb) = cbs.input {
+        for event in events {
+            cb(gpui::PlatformInput::FileDrop(event));
+        }
+    }
+}
+
+/// GPUI's ExternalPaths from winit's per-file paths.
+fn external_paths(paths: &[PathBuf]) -> gpui::ExternalPaths {
+    gpui::ExternalPaths(paths.iter().cloned().collect())
+}
+
 /// Convert winit's Key enum to the GPUI key string.
 /// Equivalent of gpui_web's dom_key_to_gpui_key but for winit's types.
 fn winit_key_to_gpui_key(key: &winit::keyboard::Key) -> String {
@@ -786,6 +892,18 @@ fn winit_key_to_gpui_key(key: &winit::keyboard::Key) -> String {
     }
 }
 
+/// DOM `KeyboardEvent.code` name of a winit physical key. winit's KeyCode
+/// variants follow the same W3C list, except for the Super keys.
+fn winit_physical_key_to_code(key: &winit::keyboard::PhysicalKey) -> String {
+    use winit::keyboard::{KeyCode, PhysicalKey};
+    match key {
+        PhysicalKey::Code(KeyCode::SuperLeft) => "MetaLeft".to_string(),
+        PhysicalKey::Code(KeyCode::SuperRight) => "MetaRight".to_string(),
+        PhysicalKey::Code(code) => format!("{code:?}"),
+        PhysicalKey::Unidentified(_) => "Unidentified".to_string(),
+    }
+}
+
 fn is_modifier_only_key(key: &str) -> bool {
     matches!(key, "control" | "alt" | "shift" | "platform" | "capslock")
 }
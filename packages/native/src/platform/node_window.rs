@@ -17,6 +17,7 @@ use gpui::{
 use gpui_wgpu::{WgpuContext, WgpuRenderer, WgpuSurfaceConfig};
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
 use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::time::Instant;
@@ -90,6 +91,9 @@ pub struct NodeWindowState {
     pub is_active: Cell<bool>,
     pub is_hovered: Cell<bool>,
     pub is_fullscreen: Cell<bool>,
+    /// Files dragged over the window from the OS, already announced to GPUI
+    /// with FileDropEvent::Entered. Empty when no file drag is in progress.
+    pub dragged_files: RefCell<Vec<PathBuf>>,
     pub title: RefCell<String>,
     pub sprite_atlas: Arc<dyn PlatformAtlas>,
 }
@@ -192,6 +196,7 @@ impl NodeWindow {
             is_active: Cell::new(true),
             is_hovered: Cell::new(false),
             is_fullscreen: Cell::new(false),
+            dragged_files: RefCell::new(Vec::new()),
             title: RefCell::new(String::new()),
             sprite_atlas,
         });
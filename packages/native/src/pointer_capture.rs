@@ -0,0 +1,177 @@
+/// Pointer capture for divs with the `capturePointer` prop.
+///
+/// GPUI only sends mouse moves to the element under the cursor, so a slider
+/// thumb or splitter dragged quickly loses them. After a mouse down on a
+/// capturing element, its mouseMove and the releasing mouseUp come from
+/// window-level Listeners that its bounds canvas (see bounds.rs) registers
+/// during paint, wherever the cursor is. That includes outside the window:
+/// the OS keeps sending a window the mouse events of a press that started
+/// in it. The element's own mouseMove listener stands down meanwhile so
+/// nothing fires twice, and its mouseUp always comes from the window
+/// listener, which can't tell GPUI's bubble-phase listener that it already
+/// reported the release.
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+use crate::events::fill_position;
+use crate::renderer::{emit_event_full, mouse_button_to_u32, EventCallback};
+use crate::retained_tree::{RetainedElement, RetainedTree};
+use crate::sync_dispatch::respond;
+
+/// The element holding the pointer and the button that started it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capture {
+    pub element: u64,
+    pub button: gpui::MouseButton,
+}
+
+/// At most one capture at a time, shared with the mouse listeners.
+pub type PointerCapture = Arc<Mutex<Option<Capture>>>;
+
+/// True if the element has `capturePointer` set.
+pub fn is_enabled(element: &RetainedElement) -> bool {
+    element
+        .custom_props
+        .get("capturePointer")
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+/// True while `id` holds the pointer.
+pub fn is_captured_by(capture: &PointerCapture, id: u64) -> bool {
+    capture
+        .lock()
+        .unwrap()
+        .is_some_and(|capture| capture.element == id)
+}
+
+/// True while any element holds the pointer.
+pub fn is_active(capture: &PointerCapture) -> bool {
+    capture.lock().unwrap().is_some()
+}
+
+/// Start a capture for `id` unless another press already holds one.
+pub fn begin(capture: &PointerCapture, id: u64, button: gpui::MouseButton) {
+    let mut capture = capture.lock().unwrap();
+    if capture.is_none() {
+        *capture = Some(Capture {
+            element: id,
+            button,
+        });
+    }
+}
+
+/// Drop `id`'s capture without a mouseUp, e.g. when the release never
+/// reached the window.
+pub fn cancel(capture: &PointerCapture, id: u64) {
+    let mut capture = capture.lock().unwrap();
+    if capture.is_some_and(|capture| capture.element == id) {
+        *capture = None;
+    }
+}
+
+/// End `id`'s capture if `button` started it, returning whether it did.
+pub fn release(capture: &PointerCapture, id: u64, button: gpui::MouseButton) -> bool {
+    let mut capture = capture.lock().unwrap();
+    let held = *capture
+        == Some(Capture {
+            element: id,
+            button,
+        });
+    if held {
+        *capture = None;
+    }
+    held
+}
+
+/// Start a capture when the element is pressed.
+pub fn on_press(
+    el: gpui::Stateful<gpui::Div>,
+    id: u64,
+    capture: &PointerCapture,
+) -> gpui::Stateful<gpui::Div> {
+    use gpui::InteractiveElement as _;
+
+    let capture = capture.clone();
+    el.on_any_mouse_down(move |mouse_event, _window, _cx| {
+        begin(&capture, id, mouse_event.button);
+    })
+}
+
+/// A capturing element's window-level mouse listeners.
+pub struct Listeners {
+    id: u64,
+    capture: PointerCapture,
+    callback_move: Option<EventCallback>,
+    callback_up: Option<EventCallback>,
+}
+
+impl Listeners {
+    pub fn new(
+        element: &RetainedElement,
+        tree: &RetainedTree,
+        event_callback: &Option<EventCallback>,
+    ) -> Self {
+        let callback = |event: &str| {
+            event_callback
+                .clone()
+                .filter(|_| element.events.contains(event))
+        };
+        Self {
+            id: element.id,
+            capture: tree.pointer_capture.clone(),
+            callback_move: callback("mouseMove"),
+            callback_up: callback("mouseUp"),
+        }
+    }
+
+    /// Register the listeners for this frame, with the element's `bounds`
+    /// for local coordinates.
+    pub fn paint(self, bounds: gpui::Bounds<gpui::Pixels>, window: &mut gpui::Window) {
+        let Self {
+            id,
+            capture,
+            callback_move,
+            callback_up,
+        } = self;
+        // What's visible of the element, for releases that aren't
+        // captured: a plain mouseUp over it.
+        let visible = bounds.intersect(&window.content_mask().bounds);
+        window.on_mouse_event({
+            let capture = capture.clone();
+            move |mouse_event: &gpui::MouseMoveEvent, phase, window, cx| {
+                if phase != gpui::DispatchPhase::Capture || !is_captured_by(&capture, id) {
+                    return;
+                }
+                // The release never reached the window.
+                if mouse_event.pressed_button.is_none() {
+                    cancel(&capture, id);
+                    return;
+                }
+                let response = emit_event_full(&callback_move, id, "mouseMove", |p| {
+                    fill_position(p, mouse_event.position, bounds);
+                    p.modifiers = Some(mouse_event.modifiers.into());
+                    p.pressed_button = mouse_event.pressed_button.map(mouse_button_to_u32);
+                });
+                respond(response, window, cx);
+            }
+        });
+        window.on_mouse_event(move |mouse_event: &gpui::MouseUpEvent, phase, window, cx| {
+            if phase != gpui::DispatchPhase::Capture {
+                return;
+            }
+            let released = release(&capture, id, mouse_event.button);
+            if !released && (is_active(&capture) || !visible.contains(&mouse_event.position)) {
+                return;
+            }
+            let response = emit_event_full(&callback_up, id, "mouseUp", |p| {
+                fill_position(p, mouse_event.position, bounds);
+                p.button = Some(mouse_button_to_u32(mouse_event.button));
+                p.click_count = Some(mouse_event.click_count as u32);
+                p.modifiers = Some(mouse_event.modifiers.into());
+            });
+            respond(response, window, cx);
+        });
+    }
+}
@@ -17,23 +17,25 @@
 use gpui::AppContext as _;
 use napi::bindgen_prelude::*;
 use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi::Env;
 use napi_derive::napi;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
 use crate::custom_elements::{CustomElementRegistry, CustomRenderContext};
-use crate::element_tree::EventPayload;
+use crate::element_tree::{EventPayload, EventResponse};
+use crate::inline_text::{self, TextInteractionState};
 use crate::platform::NodePlatform;
 use crate::retained_tree::RetainedTree;
-use crate::style::{parse_color_hex, StyleDesc};
+use crate::style::{parse_color_hex, BackgroundValue, DimensionValue, StyleDesc};
 
 /// Parse a CSS font-weight value (string or number) into a GPUI FontWeight.
 /// Accepts named keywords ("bold", "semibold"), numeric strings ("700"),
 /// and raw numbers (700). Falls back to 400 (normal) for unrecognized values.
-fn parse_font_weight(value: &crate::style::FontWeightValue) -> gpui::FontWeight {
+pub(crate) fn parse_font_weight(value: &crate::style::FontWeightValue) -> gpui::FontWeight {
     match value {
         crate::style::FontWeightValue::Num(n) => {
             gpui::FontWeight((*n as f32).clamp(1.0, 1000.0))
@@ -89,6 +91,14 @@ thread_local! {
     /// the last one to render wins. Acceptable for now (single-window only).
     /// TODO: Scope by renderer/window ID when multi-window support is added.
     static SCROLL_HANDLES: RefCell<HashMap<u64, gpui::ScrollHandle>> = RefCell::new(HashMap::new());
+    /// Whether the last input was a keystroke rather than a mouse down on a
+    /// focusable element — what `focusVisible` styles key off. Starts true,
+    /// so programmatic focus before any input shows focusVisible styles.
+    static KEYBOARD_MODALITY: Cell<bool> = const { Cell::new(true) };
+    /// Set while building the contents of a deferred draw (a zIndex, fixed
+    /// or pinned sticky layer, or a tooltip). GPUI panics on a deferred draw
+    /// inside another, so nested layers paint in tree order instead.
+    static IN_LAYER: Cell<bool> = const { Cell::new(false) };
 }
 
 /// The main GPUI renderer exposed to Node.js.
@@ -162,14 +172,17 @@ impl GpuixRenderer {
                         ..Default::default()
                     },
                     |_window, cx| {
-                        cx.new(|_| GpuixView {
+                        crate::keymap::deliver_unhandled(tree.clone(), callback.clone(), cx);
+                        cx.new(|cx| GpuixView {
                             tree: tree.clone(),
                             event_callback: callback.clone(),
                             window_title: title,
                             focus_handles: HashMap::new(),
-                            _focus_subscriptions: Vec::new(),
+                            _focus_subscriptions: vec![GpuixView::observe_input_modality(cx)],
                             custom_registry: CustomElementRegistry::with_defaults(),
                             scroll_handles: HashMap::new(),
+                            text_states: HashMap::new(),
+                            transitions: Default::default(),
                         })
                     },
                 )
@@ -239,10 +252,92 @@ impl GpuixRenderer {
     #[napi]
     pub fn set_style(&self, id: f64, style_json: String) -> Result<()> {
         let id = to_element_id(id)?;
-        let style: StyleDesc = serde_json::from_str(&style_json)
+        let style = crate::theme::parse_style_input(&style_json)
             .map_err(|e| Error::from_reason(format!("Failed to parse style: {}", e)))?;
         let mut tree = self.tree.lock().unwrap();
-        tree.set_style(id, style);
+        tree.set_style_input(id, style);
+        Ok(())
+    }
+
+    /// Set the element's style classes from a whitespace-separated className.
+    /// Classes are merged in order, then the inline style from setStyle.
+    #[napi]
+    pub fn set_class_name(&self, id: f64, class_name: String) -> Result<()> {
+        let id = to_element_id(id)?;
+        let mut tree = self.tree.lock().unwrap();
+        tree.set_class_name(id, &class_name);
+        Ok(())
+    }
+
+    /// Register (or replace) a named style class, referenced by elements via
+    /// setClassName. Elements already using the class restyle on the next frame.
+    #[napi]
+    pub fn register_style_class(&self, name: String, style_json: String) -> Result<()> {
+        self.tree
+            .lock()
+            .unwrap()
+            .register_style_class(&name, &style_json)
+            .map_err(Error::from_reason)?;
+        self.needs_redraw.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Register (or replace) named keyframes, e.g.
+    /// `{"from": {"opacity": 1}, "50%": {"opacity": 0.4}, "to": {"opacity": 1}}`.
+    /// Elements reference them with an `animation` style like `"pulse 1s infinite"`.
+    #[napi]
+    pub fn register_animation(&self, name: String, keyframes_json: String) -> Result<()> {
+        self.tree
+            .lock()
+            .unwrap()
+            .register_animation(&name, &keyframes_json)
+            .map_err(Error::from_reason)?;
+        self.needs_redraw.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Set the theme: a JSON object mapping token names to colors, lengths
+    /// or font names, e.g. `{"accent": "#89b4fa", "gap": 8}`. Styles refer
+    /// to tokens as `"var(--accent)"` in any field. Replaces the previous
+    /// theme and re-resolves themed styles on the next frame.
+    #[napi]
+    pub fn set_theme(&self, theme_json: String) -> Result<()> {
+        let theme = crate::theme::Theme::from_json(&theme_json).map_err(Error::from_reason)?;
+        self.tree.lock().unwrap().set_theme(theme);
+        self.needs_redraw.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Add key bindings from a JSON array of
+    /// `{"keys": "ctrl-k ctrl-s", "action": "save", "context": "Editor"}`.
+    /// Matches are sent as `action` events; see keymap.rs.
+    #[napi]
+    pub fn register_key_bindings(&self, bindings_json: String) -> Result<()> {
+        self.tree
+            .lock()
+            .unwrap()
+            .register_key_bindings(&bindings_json)
+            .map_err(Error::from_reason)?;
+        self.needs_redraw.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Remove every key binding registered with registerKeyBindings().
+    #[napi]
+    pub fn clear_key_bindings(&self) -> Result<()> {
+        self.tree.lock().unwrap().clear_key_bindings();
+        self.needs_redraw.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Choose the names used for `key` in keyboard payloads: "gpui"
+    /// (default: "enter", "down") or "dom" (KeyboardEvent.key: "Enter",
+    /// "ArrowDown"). Listeners pick it up on the next frame.
+    #[napi]
+    pub fn set_key_names(&self, names: String) -> Result<()> {
+        let names = crate::keyboard::KeyNames::parse(&names).map_err(Error::from_reason)?;
+        self.tree.lock().unwrap().key_names = names;
+        self.needs_redraw.store(true, Ordering::SeqCst);
         Ok(())
     }
 
@@ -312,6 +407,7 @@ impl GpuixRenderer {
     ///   ["removeChild",      parentId, childId]
     ///   ["insertBefore",     parentId, childId, beforeId]
     ///   ["setStyle",         id, "{styleJson}"]
+    ///   ["setClassName",     id, "className"]
     ///   ["setText",          id, "content"]
     ///   ["setEventListener", id, "eventType", true|false]
     ///   ["setRoot",          id]
@@ -331,8 +427,10 @@ impl GpuixRenderer {
 
     // ── Frame loop ───────────────────────────────────────────────────
 
+    /// Pump OS events and render. Events GPUI dispatches here reach the
+    /// synchronous handler, if one is set, before tick() returns.
     #[napi]
-    pub fn tick(&self) -> Result<()> {
+    pub fn tick(&self, env: Env) -> Result<()> {
         let initialized = *self.initialized.lock().unwrap();
         if !initialized {
             return Err(Error::from_reason(
@@ -342,13 +440,26 @@ impl GpuixRenderer {
 
         let force_render = self.needs_redraw.swap(false, Ordering::SeqCst);
 
-        NODE_PLATFORM.with(|p| {
-            if let Some(ref platform) = *p.borrow() {
-                platform.tick(force_render);
-            }
-        });
+        crate::sync_dispatch::dispatching(env, || {
+            NODE_PLATFORM.with(|p| {
+                if let Some(ref platform) = *p.borrow() {
+                    platform.tick(force_render);
+                }
+            });
+            Ok(())
+        })
+    }
 
-        Ok(())
+    /// Call `handler` synchronously for events dispatched during tick(),
+    /// instead of queueing them for the constructor's callback. It returns
+    /// `{ stopPropagation, preventDefault }` to control GPUI's dispatch.
+    /// Pass null to go back to queued events.
+    #[napi]
+    pub fn set_sync_event_handler(
+        &self,
+        handler: Option<Function<EventPayload, Option<EventResponse>>>,
+    ) -> Result<()> {
+        crate::sync_dispatch::set_handler(handler)
     }
 
     #[napi]
@@ -448,9 +559,48 @@ pub(crate) struct GpuixView {
     /// Created lazily for elements with overflow: "scroll" (or per-axis scroll).
     /// Handles persist across renders so GPUI maintains scroll offset state.
     pub(crate) scroll_handles: HashMap<u64, gpui::ScrollHandle>,
+    /// Per-text-element interaction state (hovered link) keyed by element ID.
+    /// Created lazily by build_text() for paragraphs with inline spans.
+    pub(crate) text_states: HashMap<u64, TextInteractionState>,
+    /// Running CSS transitions, advanced once per frame before building.
+    pub(crate) transitions: crate::transition::Transitions,
 }
 
 impl GpuixView {
+    /// Build one retained element outside the root tree, e.g. a drag
+    /// preview (see drag::DragPreview).
+    pub(crate) fn build_detached(
+        &mut self,
+        id: u64,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> gpui::AnyElement {
+        let tree_arc = self.tree.clone();
+        let tree = tree_arc.lock().unwrap();
+        build_element(
+            id,
+            &tree,
+            &self.event_callback,
+            &self.focus_handles,
+            &mut self.scroll_handles,
+            &mut self.text_states,
+            &mut self.custom_registry,
+            window,
+            cx,
+        )
+    }
+
+    /// Switch to keyboard modality on any keystroke, refreshing the window so
+    /// focusVisible styles appear on the focused element. Stored with the
+    /// focus subscriptions when the view is created.
+    pub(crate) fn observe_input_modality(cx: &mut gpui::App) -> gpui::Subscription {
+        cx.observe_keystrokes(|_event, window, _cx| {
+            if !KEYBOARD_MODALITY.replace(true) {
+                window.refresh();
+            }
+        })
+    }
+
     /// Sync focus handles with the current element tree.
     /// Creates handles for new focusable elements, subscribes on_focus/on_blur,
     /// and cleans up handles for destroyed elements.
@@ -463,10 +613,19 @@ impl GpuixView {
     ) {
         // Create handles for elements that need focus but don't have one yet.
         for (&id, element) in &tree.elements {
+            // Selectable text needs focus to receive Ctrl/Cmd+C.
+            // Focus styles need a handle to track focus with. A tabIndex
+            // asks for focus outright, e.g. for a keyContext scope.
             let needs_focus = element.events.contains("keyDown")
                 || element.events.contains("keyUp")
                 || element.events.contains("focus")
-                || element.events.contains("blur");
+                || element.events.contains("blur")
+                || element.custom_props.contains_key("tabIndex")
+                || element
+                    .style
+                    .as_ref()
+                    .is_some_and(|s| s.focus.is_some() || s.focus_visible.is_some())
+                || (element.element_type == "text" && inline_text::is_selectable(element));
 
             if needs_focus && !self.focus_handles.contains_key(&id) {
                 let handle = cx.focus_handle();
@@ -511,16 +670,58 @@ impl gpui::Render for GpuixView {
     ) -> impl gpui::IntoElement {
         use gpui::IntoElement;
 
+        let _rendering = crate::sync_dispatch::Rendering::begin();
+
         window.set_window_title(&self.window_title);
 
         // Clone Arc so we don't borrow self.tree — frees self for focus_handles access.
         let tree_arc = self.tree.clone();
-        let tree = tree_arc.lock().unwrap();
+        let mut tree = tree_arc.lock().unwrap();
         let callback = self.event_callback.clone();
 
+        // Recompute class-based and themed styles if registerStyleClass()
+        // or setTheme() changed their inputs.
+        tree.resolve_styles();
+
+        // vw/vh lengths resolve against the current viewport.
+        let viewport = window.viewport_size();
+        tree.viewport = (
+            f32::from(viewport.width) as f64,
+            f32::from(viewport.height) as f64,
+        );
+
+        // Advance CSS transitions: writes interpolated styles into the tree
+        // and keeps frames coming while any transition is running.
+        let transition_frame = self.transitions.update(&mut tree, std::time::Instant::now());
+        if transition_frame.running {
+            window.request_animation_frame();
+        }
+        for finished in transition_frame.finished {
+            let listening = tree
+                .elements
+                .get(&finished.element_id)
+                .is_some_and(|el| el.events.contains("transitionEnd"));
+            if listening {
+                emit_event_full(&callback, finished.element_id, "transitionEnd", |p| {
+                    p.property = Some(finished.property.to_string());
+                    p.elapsed_time = Some(finished.elapsed.as_secs_f64());
+                });
+            }
+        }
+
+        // Font sizes for em/rem lengths, after transitions may have changed them.
+        let mut font_sizes = std::mem::take(&mut tree.font_sizes);
+        font_sizes.update(&tree);
+        tree.font_sizes = font_sizes;
+
         // Sync focus handles before building elements.
         self.sync_focus_handles(&tree, &callback, window, cx);
 
+        // Bindings registered or cleared since the last frame; see keymap.rs.
+        if std::mem::take(&mut tree.key_bindings_dirty) {
+            crate::keymap::bind(&tree.key_bindings, cx);
+        }
+
         // Ensure custom element instances are destroyed when their IDs disappear.
         self.custom_registry
             .prune_missing(|id| tree.elements.contains_key(&id));
@@ -530,6 +731,51 @@ impl gpui::Render for GpuixView {
         // from scroll to non-scroll) is handled inside build_div().
         self.scroll_handles
             .retain(|id, _| tree.elements.contains_key(id));
+        self.text_states
+            .retain(|id, _| tree.elements.contains_key(id));
+        tree.layout_bounds
+            .lock()
+            .unwrap()
+            .retain(|id, _| tree.elements.contains_key(id));
+        tree.tooltips
+            .lock()
+            .unwrap()
+            .retain(|id, _| tree.elements.contains_key(id));
+        {
+            // Released when its holder is gone or stopped capturing: lost
+            // `capturePointer`, became disabled or got pointerEvents: "none"
+            // mid-press. The holder registers no capture listeners anymore.
+            let mut capture = tree.pointer_capture.lock().unwrap();
+            let holds = |id: &u64| {
+                tree.elements.get(id).is_some_and(|el| {
+                    crate::pointer_capture::is_enabled(el)
+                        && !is_disabled(el, &tree)
+                        && has_pointer_events(el, &tree)
+                })
+            };
+            if capture.is_some_and(|capture| !holds(&capture.element)) {
+                *capture = None;
+            }
+        }
+
+        // Drop targets a drag was still over when it ended (dropped
+        // elsewhere or cancelled) never saw the cursor leave.
+        if !cx.has_active_drag() {
+            let left: Vec<_> = tree.drag_targets.lock().unwrap().drain().collect();
+            let position = window.mouse_position();
+            for (id, dragged) in left {
+                let leave = dragged.leave_event();
+                let listening = tree
+                    .elements
+                    .get(&id)
+                    .is_some_and(|el| el.events.contains(leave));
+                if listening {
+                    emit_event_full(&callback, id, leave, |p| {
+                        dragged.fill(p, position);
+                    });
+                }
+            }
+        }
 
         // Build the element tree. custom_registry, focus_handles, and scroll_handles
         // are different fields of self, so Rust allows borrowing all simultaneously.
@@ -540,6 +786,7 @@ impl gpui::Render for GpuixView {
                 &callback,
                 &self.focus_handles,
                 &mut self.scroll_handles,
+                &mut self.text_states,
                 &mut self.custom_registry,
                 window,
                 cx,
@@ -569,6 +816,7 @@ pub(crate) fn build_element(
     event_callback: &Option<EventCallback>,
     focus_handles: &HashMap<u64, gpui::FocusHandle>,
     scroll_handles: &mut HashMap<u64, gpui::ScrollHandle>,
+    text_states: &mut HashMap<u64, TextInteractionState>,
     custom_registry: &mut CustomElementRegistry,
     window: &mut gpui::Window,
     cx: &mut gpui::Context<GpuixView>,
@@ -588,6 +836,7 @@ pub(crate) fn build_element(
                 event_callback,
                 focus_handles,
                 scroll_handles,
+                text_states,
                 custom_registry,
                 window,
                 cx,
@@ -601,6 +850,7 @@ pub(crate) fn build_element(
                 event_callback,
                 focus_handles,
                 scroll_handles,
+                text_states,
                 custom_registry,
                 window,
                 cx,
@@ -620,6 +870,7 @@ pub(crate) fn build_element(
                         event_callback,
                         focus_handles,
                         scroll_handles,
+                        text_states,
                         custom_registry,
                         window,
                         cx,
@@ -665,13 +916,15 @@ pub(crate) fn build_element(
                     .cloned()
                     .collect();
 
+                let style = resolve_style(element, tree);
                 let ctx = CustomRenderContext {
                     id,
                     events: &filtered_events,
                     event_callback,
                     focus_handle: focus_handles.get(&id),
-                    style: element.style.as_ref(),
+                    style: style.as_deref(),
                     children: custom_children,
+                    key_names: tree.key_names,
                 };
 
                 instance.render(ctx, window, cx)
@@ -689,6 +942,7 @@ pub(crate) fn build_div(
     event_callback: &Option<EventCallback>,
     focus_handles: &HashMap<u64, gpui::FocusHandle>,
     scroll_handles: &mut HashMap<u64, gpui::ScrollHandle>,
+    text_states: &mut HashMap<u64, TextInteractionState>,
     custom_registry: &mut CustomElementRegistry,
     window: &mut gpui::Window,
     cx: &mut gpui::Context<GpuixView>,
@@ -697,283 +951,621 @@ pub(crate) fn build_div(
 
     let element_id_str = format!("__gpuix_{}", element.id);
     let mut el = gpui::div().id(gpui::SharedString::from(element_id_str));
-
-    if let Some(ref style) = element.style {
+    el = apply_parent_justify_items(el, element, tree);
+
+    // pointerEvents: "none" — GPUI hitboxes don't block each other by
+    // default, so passing through just means registering no mouse handlers,
+    // hover/active styles or cursor on this element.
+    let pointer_events = has_pointer_events(element, tree);
+    // disabled: shows the `disabled` style in place of interactive
+    // pseudo-styles, takes no focus and emits no events. Descendants of a
+    // disabled element are disabled too.
+    let disabled = is_disabled(element, tree);
+    let interactive = pointer_events && !disabled;
+
+    let group = group_name(element);
+    if let Some(ref group) = group {
+        el = el.group(group.clone());
+    }
+
+    let mut animation = None;
+    let mut layer = Layer::default();
+    let mut sticky = None;
+    let mut borders = None;
+    if let Some(style) = resolve_style(element, tree).as_deref() {
         el = apply_styles(el, style);
+        animation = crate::animation::resolve(element.id, style, &tree.animations);
+        layer = Layer::of(style);
+        sticky = sticky_offset(element, style, tree, scroll_handles);
+        let border_group = group
+            .clone()
+            .unwrap_or_else(|| format!("__gpuix_{}_borders", element.id).into());
+        borders =
+            crate::borders::overlay(element.id, style, disabled, pointer_events, &border_group);
+        if borders.is_some() && group.is_none() {
+            el = el.group(border_group);
+        }
+        if !pointer_events {
+            el.style().mouse_cursor = None;
+        }
+        el = apply_pseudo_styles(el, element, style, tree, disabled, pointer_events);
+    }
+
+    // ── Sticky positioning ───────────────────────────────────────────
+    // Shifted by how far the element has scrolled past its top/left
+    // threshold, read from the nearest scroll container's handle. GPUI
+    // re-renders on every scroll, so the offset never lags. While pinned,
+    // the element paints above the content scrolling beneath it.
+    if let Some(ref sticky) = sticky {
+        el = el
+            .left(gpui::px(sticky.shift.0 as f32))
+            .top(gpui::px(sticky.shift.1 as f32));
+        if sticky.pinned() {
+            layer.scrollport = Some(sticky.scroll.clone());
+        }
+    }
+    // Everything built below — children and tooltip — is inside this
+    // element's layer, if it has one.
+    layer.nested = IN_LAYER.get();
+    let _layer_scope = layer.defers().then(LayerScope::enter);
+
+    el = apply_overflow_scroll(el, element, scroll_handles);
+    el = apply_focus(el, element, focus_handles, disabled);
+
+    // ── Events ───────────────────────────────────────────────────────
+    // See events.rs, pointer_capture.rs, drag.rs and tooltip.rs. Listeners
+    // that need the element's bounds get them from its bounds canvas.
+    let mut bounds = crate::bounds::BoundsCanvas::default();
+    let target = Rc::default();
+    if !disabled {
+        el = crate::events::wire(el, element, tree, event_callback, &target, pointer_events);
+    }
+    if interactive && crate::events::is_positional(element) {
+        bounds.target = Some(target);
+    }
+    let tooltip = crate::tooltip::Tooltip::of(element);
+    let tooltip_delay = tooltip.as_ref().filter(|_| interactive).map(|t| t.delay);
+    if tooltip_delay.is_none() {
+        tree.tooltips.lock().unwrap().remove(&element.id);
+    }
+    let drag_source = crate::drag::DragSource::of(element).filter(|_| interactive);
+    if interactive {
+        el = crate::events::hover(el, element, tree, event_callback, tooltip_delay);
+        if crate::pointer_capture::is_enabled(element) {
+            el = crate::pointer_capture::on_press(el, element.id, &tree.pointer_capture);
+            bounds.capture = Some(crate::pointer_capture::Listeners::new(
+                element,
+                tree,
+                event_callback,
+            ));
+        }
+        if let Some(ref source) = drag_source {
+            el = crate::drag::source(el, element, source, tree, cx);
+        }
+        el = crate::drag::target(el, element, tree, event_callback);
+    }
+
+    // Children with calc(% ± length) or sticky positioning, sticky elements
+    // and drag sources need their measured layout (see bounds.rs).
+    if sticky.is_some()
+        || drag_source.is_some()
+        || crate::bounds::children_need_layout(element, tree)
+    {
+        let scroll = nearest_scroll_container(element, tree, scroll_handles)
+            .map(|(_, handle)| handle.clone());
+        let shift = sticky.as_ref().map_or((0.0, 0.0), |sticky| sticky.shift);
+        bounds.measure = Some(crate::bounds::Measure::new(element.id, tree, scroll, shift));
+    }
+
+    // Text content
+    if let Some(ref content) = element.content {
+        el = el.child(content.clone());
+    }
 
-        // ── Pseudo-selector styles (hover / active) ──────────────────
-        // GPUI's .hover() and .active() take a closure that receives a
-        // StyleRefinement and returns it with modifications. Since
-        // StyleRefinement implements Styled, we can reuse apply_styles().
+    // Children, in paint order (negative zIndex first). A tooltip's
+    // element child is only built inside the tooltip, and a drag preview
+    // only while dragging.
+    let tooltip_child = tooltip.as_ref().and_then(|t| t.element());
+    let preview_child = drag_source.as_ref().and_then(|source| source.preview);
+    for child_id in paint_order(element, tree) {
+        if Some(child_id) == tooltip_child || Some(child_id) == preview_child {
+            continue;
+        }
+        el = el.child(build_element(
+            child_id,
+            tree,
+            event_callback,
+            focus_handles,
+            scroll_handles,
+            text_states,
+            custom_registry,
+            window,
+            cx,
+        ));
+    }
+
+    // Per-side border colors, over the transparent native border.
+    if let Some(borders) = borders {
+        el = el.children(borders);
+    }
+
+    // The canvas goes after the real children, so scroll_to_item() indices
+    // still match them.
+    if let Some(bounds) = bounds.into_element() {
+        el = el.child(bounds);
+    }
+
+    // ── Tooltip ──────────────────────────────────────────────────────
+    // Shown once the hover delay has elapsed (see tooltip::on_hover).
+    let tooltip_visible = tooltip_delay.is_some()
+        && tree.tooltips.lock().unwrap().get(&element.id)
+            == Some(&crate::tooltip::TooltipPhase::Visible);
+    if let Some(tooltip) = tooltip.filter(|_| tooltip_visible) {
+        let deferred = !IN_LAYER.get();
+        let _tooltip_scope = LayerScope::enter();
+        let content = match tooltip.content {
+            crate::tooltip::TooltipContent::Text(ref text) => crate::tooltip::text_content(text),
+            crate::tooltip::TooltipContent::Element(child_id) => build_element(
+                child_id,
+                tree,
+                event_callback,
+                focus_handles,
+                scroll_handles,
+                text_states,
+                custom_registry,
+                window,
+                cx,
+            ),
+        };
+        el = el.child(crate::tooltip::overlay(
+            tooltip.placement,
+            content,
+            deferred,
+        ));
+    }
+
+    // ── Keyframe animation ───────────────────────────────────────────
+    let el = animate(el, animation, element, tree, apply_styles);
+
+    layer.wrap(el, window)
+}
+
+/// Pseudo-selector styles. GPUI's .hover(), .active(), .focus() and
+/// .group_hover() take a closure that receives a StyleRefinement and
+/// returns it with modifications. Since StyleRefinement implements Styled,
+/// we can reuse apply_styles(). A disabled element takes its `disabled`
+/// style instead.
+fn apply_pseudo_styles(
+    mut el: gpui::Stateful<gpui::Div>,
+    element: &crate::retained_tree::RetainedElement,
+    style: &StyleDesc,
+    tree: &RetainedTree,
+    disabled: bool,
+    pointer_events: bool,
+) -> gpui::Stateful<gpui::Div> {
+    use gpui::prelude::*;
+
+    if disabled {
+        if let Some(ref disabled_style) = style.disabled {
+            el = apply_styles(el, disabled_style);
+        }
+        return el;
+    }
+    if pointer_events {
         if let Some(ref hover_style) = style.hover {
             el = el.hover(|refinement| apply_styles(refinement, hover_style));
         }
         if let Some(ref active_style) = style.active {
             el = el.active(|refinement| apply_styles(refinement, active_style));
         }
+        if let Some(ref group_hover_style) = style.group_hover {
+            if let Some(group) = ancestor_group(element, tree) {
+                el = el.group_hover(group, |refinement| {
+                    apply_styles(refinement, group_hover_style)
+                });
+            }
+        }
+        // Called during prepaint, so it owns a copy of the style.
+        // Applies to element drags and files dragged in from the OS.
+        if let Some(drag_over_style) = style.drag_over.clone() {
+            let drag_over_style = Rc::new(drag_over_style);
+            let files_style = drag_over_style.clone();
+            el = el
+                .drag_over::<crate::drag::DragPayload>(move |refinement, _, _, _| {
+                    apply_styles(refinement, &drag_over_style)
+                })
+                .drag_over::<gpui::ExternalPaths>(move |refinement, _, _, _| {
+                    apply_styles(refinement, &files_style)
+                });
+        }
+    }
+    // GPUI has a single focus style, so focusVisible is layered onto it
+    // when focus came from the keyboard. Modality changes refresh the
+    // window (observe_input_modality), rebuilding this.
+    let focus_visible = style
+        .focus_visible
+        .as_deref()
+        .filter(|_| KEYBOARD_MODALITY.get());
+    if style.focus.is_some() || focus_visible.is_some() {
+        el = el.focus(|mut refinement| {
+            for focus_style in [style.focus.as_deref(), focus_visible]
+                .into_iter()
+                .flatten()
+            {
+                refinement = apply_styles(refinement, focus_style);
+            }
+            refinement
+        });
+    }
+    el
+}
+
+/// Overflow: scroll. overflow_scroll() requires StatefulInteractiveElement
+/// (only on Stateful<Div>), so we handle it here rather than in
+/// apply_styles (which takes E: Styled).
+///
+/// CSS precedence: axis-specific props (overflowX/Y) override the shorthand
+/// (overflow). E.g. { overflow: "scroll", overflowY: "hidden" } → scroll X only.
+fn apply_overflow_scroll(
+    mut el: gpui::Stateful<gpui::Div>,
+    element: &crate::retained_tree::RetainedElement,
+    scroll_handles: &mut HashMap<u64, gpui::ScrollHandle>,
+) -> gpui::Stateful<gpui::Div> {
+    use gpui::prelude::*;
+
+    let Some(ref style) = element.style else {
+        // No style at all — remove stale handle if it existed.
+        scroll_handles.remove(&element.id);
+        return el;
+    };
+    // Resolve each axis: axis-specific overrides shorthand.
+    let resolved_x = style.overflow_x.as_deref().or(style.overflow.as_deref());
+    let resolved_y = style.overflow_y.as_deref().or(style.overflow.as_deref());
+
+    let needs_scroll_x = resolved_x == Some("scroll");
+    let needs_scroll_y = resolved_y == Some("scroll");
+
+    if needs_scroll_x && needs_scroll_y {
+        el = el.overflow_scroll();
+    } else if needs_scroll_x {
+        el = el.overflow_x_scroll();
+    } else if needs_scroll_y {
+        el = el.overflow_y_scroll();
+    }
+
+    // Attach a persistent ScrollHandle when scrolling is enabled.
+    // The handle persists across renders (stored in GpuixView::scroll_handles)
+    // so GPUI maintains the scroll offset between frames.
+    if needs_scroll_x || needs_scroll_y {
+        let handle = scroll_handles
+            .entry(element.id)
+            .or_insert_with(gpui::ScrollHandle::new);
+        el = el.track_scroll(handle);
+    } else {
+        // Element is no longer scrollable — remove stale handle.
+        scroll_handles.remove(&element.id);
+    }
+    el
+}
+
+/// If a FocusHandle was pre-created for this element (by sync_focus_handles),
+/// attach it via track_focus. This makes the element focusable — clicking it
+/// or tabbing to it gives it keyboard focus. The handle persists across renders
+/// because it's stored in GpuixView::focus_handles.
+/// Pointer focus hides focusVisible styles: the capture phase runs before
+/// GPUI focuses the element on mouse down. A `keyContext` scopes key
+/// bindings; see keymap.rs.
+fn apply_focus(
+    mut el: gpui::Stateful<gpui::Div>,
+    element: &crate::retained_tree::RetainedElement,
+    focus_handles: &HashMap<u64, gpui::FocusHandle>,
+    disabled: bool,
+) -> gpui::Stateful<gpui::Div> {
+    use gpui::prelude::*;
+
+    if let Some(handle) = focus_handles.get(&element.id).filter(|_| !disabled) {
+        el = el
+            .track_focus(handle)
+            .capture_any_mouse_down(|_, _, _| KEYBOARD_MODALITY.set(false));
+    }
+    if let Some(context) = element.custom_props.get("keyContext") {
+        match context.as_str().map(gpui::KeyContext::parse) {
+            Some(Ok(context)) => el = el.key_context(context),
+            _ => crate::style::warn_once(format!(
+                "Invalid keyContext {} on element {}",
+                context, element.id
+            )),
+        }
     }
+    el
+}
 
-    // ── Overflow: scroll ─────────────────────────────────────────────
-    // overflow_scroll() requires StatefulInteractiveElement (only on Stateful<Div>),
-    // so we handle it here rather than in apply_styles (which takes E: Styled).
-    //
-    // CSS precedence: axis-specific props (overflowX/Y) override the shorthand
-    // (overflow). E.g. { overflow: "scroll", overflowY: "hidden" } → scroll X only.
-    if let Some(ref style) = element.style {
-        // Resolve each axis: axis-specific overrides shorthand.
-        let resolved_x = style
-            .overflow_x
-            .as_deref()
-            .or(style.overflow.as_deref());
-        let resolved_y = style
-            .overflow_y
-            .as_deref()
-            .or(style.overflow.as_deref());
-
-        let needs_scroll_x = resolved_x == Some("scroll");
-        let needs_scroll_y = resolved_y == Some("scroll");
-
-        if needs_scroll_x && needs_scroll_y {
-            el = el.overflow_scroll();
-        } else if needs_scroll_x {
-            el = el.overflow_x_scroll();
-        } else if needs_scroll_y {
-            el = el.overflow_y_scroll();
+/// Wrap `el` in its keyframe animation, if any. AnimationElement requests a
+/// frame while running, so the NodePlatform loop keeps redrawing without
+/// React re-rendering. Each frame layers the sampled keyframe style over the
+/// element's own style with `apply`.
+fn animate<E: gpui::Styled + gpui::IntoElement + 'static>(
+    el: E,
+    animation: Option<crate::animation::ElementAnimation>,
+    element: &crate::retained_tree::RetainedElement,
+    tree: &RetainedTree,
+    apply: fn(E, &StyleDesc) -> E,
+) -> gpui::AnyElement {
+    use gpui::AnimationExt as _;
+    let Some(crate::animation::ElementAnimation {
+        id,
+        spec,
+        keyframes,
+        base,
+    }) = animation
+    else {
+        return el.into_any_element();
+    };
+    let lengths = crate::units::length_context(element, tree);
+    el.with_animations(id, spec.gpui_animations(), move |el, ix, delta| {
+        let Some(progress) = spec.progress(ix, delta) else {
+            return el;
+        };
+        let mut frame = keyframes.sample(&base, progress, spec.easing);
+        if frame.uses_relative_lengths() {
+            frame = frame.with_resolved_lengths(&lengths);
         }
+        apply(el, &frame)
+    })
+    .into_any_element()
+}
 
-        // Attach a persistent ScrollHandle when scrolling is enabled.
-        // The handle persists across renders (stored in GpuixView::scroll_handles)
-        // so GPUI maintains the scroll offset between frames.
-        if needs_scroll_x || needs_scroll_y {
-            let handle = scroll_handles
-                .entry(element.id)
-                .or_insert_with(gpui::ScrollHandle::new);
-            el = el.track_scroll(handle);
+/// Stacking for one element: `position: fixed`, pinned sticky elements and
+/// positive `zIndex`.
+#[derive(Default)]
+struct Layer {
+    fixed: bool,
+    z_index: i32,
+    /// Scroll container of a pinned sticky element.
+    scrollport: Option<gpui::ScrollHandle>,
+    /// Inside another layer, which already defers this element.
+    nested: bool,
+}
+
+impl Layer {
+    fn of(style: &StyleDesc) -> Self {
+        Self {
+            fixed: style.position.as_deref() == Some("fixed"),
+            z_index: style.z_index.unwrap_or(0),
+            scrollport: None,
+            nested: false,
+        }
+    }
+
+    fn defers(&self) -> bool {
+        !self.nested && (self.fixed || self.scrollport.is_some() || self.z_index > 0)
+    }
+
+    /// GPUI paints in tree order, except for deferred draws, which paint
+    /// after everything else in priority order. Positive zIndex defers the
+    /// element (it keeps its place in layout). Fixed elements are deferred
+    /// too, inside a window-sized box anchored at the window origin, so
+    /// their insets are relative to the window and they neither scroll nor
+    /// get clipped with their ancestors. Pinned sticky elements are deferred
+    /// and clipped back to their scroll container. GPUI can't defer inside a
+    /// deferred draw, so a nested layer keeps its fixed box but paints in
+    /// tree order within the outer one.
+    fn wrap(self, el: gpui::AnyElement, window: &gpui::Window) -> gpui::AnyElement {
+        use gpui::prelude::*;
+
+        let defers = self.defers();
+        let el = if self.fixed {
+            let viewport = window.viewport_size();
+            gpui::anchored()
+                .position(gpui::point(gpui::px(0.0), gpui::px(0.0)))
+                .child(gpui::div().w(viewport.width).h(viewport.height).child(el))
+                .into_any_element()
+        } else if let Some(scroll) = self.scrollport.filter(|_| defers) {
+            ScrollportClip { child: el, scroll }.into_any_element()
         } else {
-            // Element is no longer scrollable — remove stale handle.
-            scroll_handles.remove(&element.id);
+            el
+        };
+        if !defers {
+            return el;
         }
-    } else {
-        // No style at all — remove stale handle if it existed.
-        scroll_handles.remove(&element.id);
+        gpui::deferred(el)
+            .with_priority(self.z_index.max(0) as usize)
+            .into_any_element()
     }
+}
 
-    // If a FocusHandle was pre-created for this element (by sync_focus_handles),
-    // attach it via track_focus. This makes the element focusable — clicking it
-    // or tabbing to it gives it keyboard focus. The handle persists across renders
-    // because it's stored in GpuixView::focus_handles.
-    if let Some(handle) = focus_handles.get(&element.id) {
-        el = el.track_focus(handle);
-    }
-
-    // Wire up events.
-    // Some events (on_hover, on_click) require a stateful element (.id()),
-    // which we already set above. Others (on_mouse_down, on_key_down) work
-    // on any InteractiveElement.
-    for event_type in &element.events {
-        let id = element.id;
-        let callback = event_callback.clone();
-        match event_type.as_str() {
-            // ── Click ────────────────────────────────────────────
-            "click" => {
-                el = el.on_click(move |click_event, _window, _cx| {
-                    emit_event_full(&callback, id, "click", |p| {
-                        let (x, y) = point_to_xy(click_event.position());
-                        p.x = Some(x);
-                        p.y = Some(y);
-                        p.modifiers = Some(click_event.modifiers().into());
-                        p.click_count = Some(click_event.click_count() as u32);
-                        p.is_right_click = Some(click_event.is_right_click());
-                    });
-                });
-            }
+/// Marks what's built while it's held as inside a deferred draw.
+struct LayerScope {
+    previous: bool,
+}
 
-            // ── Mouse down (all buttons) ─────────────────────────
-            "mouseDown" => {
-                // Wire all three buttons so JS gets right-click, middle-click, etc.
-                for &button in &[
-                    gpui::MouseButton::Left,
-                    gpui::MouseButton::Middle,
-                    gpui::MouseButton::Right,
-                ] {
-                    let callback = callback.clone();
-                    el = el.on_mouse_down(button, move |mouse_event, _window, _cx| {
-                        emit_event_full(&callback, id, "mouseDown", |p| {
-                            let (x, y) = point_to_xy(mouse_event.position);
-                            p.x = Some(x);
-                            p.y = Some(y);
-                            p.button = Some(mouse_button_to_u32(mouse_event.button));
-                            p.click_count = Some(mouse_event.click_count as u32);
-                            p.modifiers = Some(mouse_event.modifiers.into());
-                        });
-                    });
-                }
-            }
+impl LayerScope {
+    fn enter() -> Self {
+        Self {
+            previous: IN_LAYER.replace(true),
+        }
+    }
+}
 
-            // ── Mouse up (all buttons) ───────────────────────────
-            "mouseUp" => {
-                for &button in &[
-                    gpui::MouseButton::Left,
-                    gpui::MouseButton::Middle,
-                    gpui::MouseButton::Right,
-                ] {
-                    let callback = callback.clone();
-                    el = el.on_mouse_up(button, move |mouse_event, _window, _cx| {
-                        emit_event_full(&callback, id, "mouseUp", |p| {
-                            let (x, y) = point_to_xy(mouse_event.position);
-                            p.x = Some(x);
-                            p.y = Some(y);
-                            p.button = Some(mouse_button_to_u32(mouse_event.button));
-                            p.click_count = Some(mouse_event.click_count as u32);
-                            p.modifiers = Some(mouse_event.modifiers.into());
-                        });
-                    });
-                }
-            }
+impl Drop for LayerScope {
+    fn drop(&mut self) {
+        IN_LAYER.set(self.previous);
+    }
+}
 
-            // ── Mouse move ───────────────────────────────────────
-            "mouseMove" => {
-                el = el.on_mouse_move(move |mouse_event, _window, _cx| {
-                    emit_event_full(&callback, id, "mouseMove", |p| {
-                        let (x, y) = point_to_xy(mouse_event.position);
-                        p.x = Some(x);
-                        p.y = Some(y);
-                        p.modifiers = Some(mouse_event.modifiers.into());
-                        p.pressed_button = mouse_event.pressed_button.map(mouse_button_to_u32);
-                    });
-                });
-            }
+/// Deferred draws paint outside their ancestors' content masks. This
+/// re-applies the mask of a scroll container, using the bounds its handle
+/// recorded earlier in the same prepaint.
+struct ScrollportClip {
+    child: gpui::AnyElement,
+    scroll: gpui::ScrollHandle,
+}
 
-            // ── Hover (mouseEnter + mouseLeave) ──────────────────
-            // GPUI's on_hover fires with true on enter, false on leave.
-            // We split into two distinct event types for the React side.
-            "mouseEnter" | "mouseLeave" => {
-                // Only wire once even if both mouseEnter and mouseLeave are registered.
-                // Check if we already wired on_hover via the other event.
-                let has_enter = element.events.contains("mouseEnter");
-                let has_leave = element.events.contains("mouseLeave");
-                // Wire on first encounter (mouseEnter sorts before mouseLeave).
-                if event_type.as_str() == "mouseEnter" || !has_enter {
-                    let callback_enter = if has_enter {
-                        event_callback.clone()
-                    } else {
-                        None
-                    };
-                    let callback_leave = if has_leave {
-                        event_callback.clone()
-                    } else {
-                        None
-                    };
-                    el = el.on_hover(move |&is_hovered, _window, _cx| {
-                        if is_hovered {
-                            emit_event_full(&callback_enter, id, "mouseEnter", |p| {
-                                p.hovered = Some(true);
-                            });
-                        } else {
-                            emit_event_full(&callback_leave, id, "mouseLeave", |p| {
-                                p.hovered = Some(false);
-                            });
-                        }
-                    });
-                }
-            }
+impl gpui::IntoElement for ScrollportClip {
+    type Element = Self;
 
-            // ── Mouse down outside ───────────────────────────────
-            // Fires when the user clicks OUTSIDE this element.
-            // Critical for "click outside to close" pattern (dropdowns, modals).
-            "mouseDownOutside" => {
-                el = el.on_mouse_down_out(move |mouse_event, _window, _cx| {
-                    emit_event_full(&callback, id, "mouseDownOutside", |p| {
-                        let (x, y) = point_to_xy(mouse_event.position);
-                        p.x = Some(x);
-                        p.y = Some(y);
-                        p.button = Some(mouse_button_to_u32(mouse_event.button));
-                        p.modifiers = Some(mouse_event.modifiers.into());
-                    });
-                });
-            }
+    fn into_element(self) -> Self {
+        self
+    }
+}
 
-            // ── Scroll wheel ─────────────────────────────────────
-            "scroll" => {
-                el = el.on_scroll_wheel(move |scroll_event, _window, _cx| {
-                    emit_event_full(&callback, id, "scroll", |p| {
-                        let (x, y) = point_to_xy(scroll_event.position);
-                        p.x = Some(x);
-                        p.y = Some(y);
-                        p.modifiers = Some(scroll_event.modifiers.into());
-                        p.precise = Some(scroll_event.delta.precise());
-
-                        // Convert ScrollDelta to pixel values.
-                        // For Lines delta, we use a default line height of 20px.
-                        let line_height = gpui::px(20.0);
-                        let pixel_delta = scroll_event.delta.pixel_delta(line_height);
-                        p.delta_x = Some(f64::from(f32::from(pixel_delta.x)));
-                        p.delta_y = Some(f64::from(f32::from(pixel_delta.y)));
-
-                        p.touch_phase = Some(match scroll_event.touch_phase {
-                            gpui::TouchPhase::Started => "started".to_string(),
-                            gpui::TouchPhase::Moved => "moved".to_string(),
-                            gpui::TouchPhase::Ended => "ended".to_string(),
-                        });
-                    });
-                });
-            }
+impl gpui::Element for ScrollportClip {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
 
-            // ── Key down ─────────────────────────────────────────
-            // Requires .focusable() (set above). Element must be focused
-            // (clicked or tabbed to) for these to fire.
-            "keyDown" => {
-                el = el.on_key_down(move |key_event, _window, _cx| {
-                    emit_event_full(&callback, id, "keyDown", |p| {
-                        p.key = Some(key_event.keystroke.key.clone());
-                        p.key_char = key_event.keystroke.key_char.clone();
-                        p.is_held = Some(key_event.is_held);
-                        p.modifiers = Some(key_event.keystroke.modifiers.into());
-                    });
-                });
-            }
+    fn id(&self) -> Option<gpui::ElementId> {
+        None
+    }
 
-            // ── Key up ───────────────────────────────────────────
-            "keyUp" => {
-                el = el.on_key_up(move |key_event, _window, _cx| {
-                    emit_event_full(&callback, id, "keyUp", |p| {
-                        p.key = Some(key_event.keystroke.key.clone());
-                        p.key_char = key_event.keystroke.key_char.clone();
-                        p.modifiers = Some(key_event.keystroke.modifiers.into());
-                    });
-                });
-            }
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
 
-            // ── Focus / Blur ─────────────────────────────────────
-            // Event emission is handled by FocusHandle subscriptions
-            // set up in GpuixView::sync_focus_handles(). The handle is
-            // attached to this element via .track_focus() above.
-            "focus" | "blur" => {}
+    fn request_layout(
+        &mut self,
+        _id: Option<&gpui::GlobalElementId>,
+        _inspector_id: Option<&gpui::InspectorElementId>,
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) -> (gpui::LayoutId, ()) {
+        (self.child.request_layout(window, cx), ())
+    }
 
-            _ => {}
-        }
+    fn prepaint(
+        &mut self,
+        _id: Option<&gpui::GlobalElementId>,
+        _inspector_id: Option<&gpui::InspectorElementId>,
+        _bounds: gpui::Bounds<gpui::Pixels>,
+        _request_layout: &mut (),
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) {
+        let mask = gpui::ContentMask {
+            bounds: self.scroll.bounds(),
+        };
+        window.with_content_mask(Some(mask), |window| {
+            self.child.prepaint(window, cx);
+        });
     }
 
-    // Text content
-    if let Some(ref content) = element.content {
-        el = el.child(content.clone());
+    fn paint(
+        &mut self,
+        _id: Option<&gpui::GlobalElementId>,
+        _inspector_id: Option<&gpui::InspectorElementId>,
+        _bounds: gpui::Bounds<gpui::Pixels>,
+        _request_layout: &mut (),
+        _prepaint: &mut (),
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) {
+        let mask = gpui::ContentMask {
+            bounds: self.scroll.bounds(),
+        };
+        window.with_content_mask(Some(mask), |window| self.child.paint(window, cx));
     }
+}
 
-    // Children
-    for &child_id in &element.children {
-        el = el.child(build_element(
-            child_id,
-            tree,
-            event_callback,
-            focus_handles,
-            scroll_handles,
-            custom_registry,
-            window,
-            cx,
-        ));
+/// Where a `position: "sticky"` element is drawn this frame.
+struct Sticky {
+    /// (x, y) offset from its place in the layout.
+    shift: (f64, f64),
+    /// Its nearest scroll container.
+    scroll: gpui::ScrollHandle,
+}
+
+impl Sticky {
+    fn pinned(&self) -> bool {
+        self.shift != (0.0, 0.0)
     }
+}
 
-    el.into_any_element()
+/// The nearest ancestor with `overflow: "scroll"`, and its handle. Ancestors
+/// are built first, so their handles are already current for this frame.
+fn nearest_scroll_container<'a>(
+    element: &crate::retained_tree::RetainedElement,
+    tree: &RetainedTree,
+    scroll_handles: &'a HashMap<u64, gpui::ScrollHandle>,
+) -> Option<(u64, &'a gpui::ScrollHandle)> {
+    let mut current = element.parent;
+    while let Some(id) = current {
+        if let Some(handle) = scroll_handles.get(&id) {
+            return Some((id, handle));
+        }
+        current = tree.elements.get(&id).and_then(|el| el.parent);
+    }
+    None
+}
+
+/// Sticky offset for `element`, as in CSS: once its natural position
+/// scrolls past `top` (or `left`) from the edge of the nearest scroll
+/// container, it is pushed back to the threshold, but never out of its
+/// parent. Thresholds may be percentages of the container. None unless the
+/// element is sticky inside a scroll container; without a measurement from
+/// a previous frame the shift is zero.
+fn sticky_offset(
+    element: &crate::retained_tree::RetainedElement,
+    style: &StyleDesc,
+    tree: &RetainedTree,
+    scroll_handles: &HashMap<u64, gpui::ScrollHandle>,
+) -> Option<Sticky> {
+    if style.position.as_deref() != Some("sticky") {
+        return None;
+    }
+    let (container_id, scroll) = nearest_scroll_container(element, tree, scroll_handles)?;
+    let mut sticky = Sticky {
+        shift: (0.0, 0.0),
+        scroll: scroll.clone(),
+    };
+
+    let layout_bounds = tree.layout_bounds.lock().unwrap();
+    let Some(own) = layout_bounds.get(&element.id) else {
+        return Some(sticky);
+    };
+    // A scroll container's own bounds are outside its content space, so its
+    // direct children stay pinned across all of its content.
+    let parent = element
+        .parent
+        .filter(|id| *id != container_id)
+        .and_then(|id| layout_bounds.get(&id));
+    let (offset_x, offset_y) = point_to_xy(scroll.offset());
+    let viewport = scroll.bounds().size;
+    let threshold = |inset: Option<DimensionValue>, basis: gpui::Pixels| {
+        let calc = inset?.to_calc()?;
+        Some(calc.px + calc.percent * f32::from(basis) as f64)
+    };
+
+    if let Some(top) = threshold(style.top, viewport.height) {
+        let mut dy = (top - (own.y + offset_y)).max(0.0);
+        if let Some(parent) = parent {
+            dy = dy.min((parent.y + parent.height - own.y - own.height).max(0.0));
+        }
+        sticky.shift.1 = dy;
+    }
+    if let Some(left) = threshold(style.left, viewport.width) {
+        let mut dx = (left - (own.x + offset_x)).max(0.0);
+        if let Some(parent) = parent {
+            dx = dx.min((parent.x + parent.width - own.x - own.width).max(0.0));
+        }
+        sticky.shift.0 = dx;
+    }
+    Some(sticky)
+}
+
+/// Children in paint order: absolutely positioned children with a negative
+/// zIndex first (lowest first), then the rest in tree order. In-flow
+/// children are never reordered, since that would change their layout.
+fn paint_order(element: &crate::retained_tree::RetainedElement, tree: &RetainedTree) -> Vec<u64> {
+    let below = |id: &u64| {
+        tree.elements
+            .get(id)
+            .and_then(|child| child.animated_style.as_ref().or(child.style.as_ref()))
+            .filter(|style| matches!(style.position.as_deref(), Some("absolute")))
+            .and_then(|style| style.z_index)
+            .filter(|z| *z < 0)
+    };
+    let mut order = element.children.clone();
+    order.sort_by_key(|id| below(id).unwrap_or(0));
+    order
 }
 
 pub(crate) fn build_text(
@@ -982,14 +1574,17 @@ pub(crate) fn build_text(
     event_callback: &Option<EventCallback>,
     focus_handles: &HashMap<u64, gpui::FocusHandle>,
     scroll_handles: &mut HashMap<u64, gpui::ScrollHandle>,
+    text_states: &mut HashMap<u64, TextInteractionState>,
     custom_registry: &mut CustomElementRegistry,
     window: &mut gpui::Window,
     cx: &mut gpui::Context<GpuixView>,
 ) -> gpui::AnyElement {
     use gpui::prelude::*;
 
+    let selectable = inline_text::is_selectable(element);
+
     // Fast path: plain text leaf without style.
-    if element.style.is_none() && element.children.is_empty() {
+    if element.style.is_none() && element.children.is_empty() && !selectable {
         return element
             .content
             .clone()
@@ -997,45 +1592,35 @@ pub(crate) fn build_text(
             .into_any_element();
     }
 
-    let mut el = gpui::div();
+    let mut el = apply_parent_justify_items(gpui::div(), element, tree);
 
-    if let Some(ref style) = element.style {
-        if let Some(hex) = style.color.as_ref().and_then(|c| parse_color_hex(c)) {
-            el = el.text_color(gpui::rgba(hex));
-        }
-        if let Some(size) = style.font_size {
-            el = el.text_size(gpui::px(size as f32));
-        }
-        if let Some(ref bg) = style.background_color.as_ref().or(style.background.as_ref()) {
-            if let Some(hex) = parse_color_hex(bg) {
-                el = el.bg(gpui::rgba(hex));
-            }
-        }
-        if let Some(ref family) = style.font_family {
-            el = el.font_family(family.clone());
-        }
-        if let Some(ref weight) = style.font_weight {
-            el = el.font_weight(parse_font_weight(weight));
-        }
-        match style.white_space.as_deref() {
-            Some("nowrap") => el = el.whitespace_nowrap(),
-            Some("normal") => el = el.whitespace_normal(),
-            _ => {}
-        }
-        match style.text_overflow.as_deref() {
-            Some("ellipsis") => el = el.text_ellipsis(),
-            Some("ellipsis-start") => el = el.text_ellipsis_start(),
-            _ => {}
-        }
-        if let Some(clamp) = style.line_clamp {
-            if clamp >= 1.0 {
-                el = el.line_clamp(clamp as usize);
-            }
-        }
+    let mut animation = None;
+    if let Some(style) = resolve_style(element, tree).as_deref() {
+        el = apply_text_element_styles(el, style);
+        animation = crate::animation::resolve(element.id, style, &tree.animations);
+    }
+
+    // Inline spans: nested `text` children are flattened into one StyledText
+    // so the paragraph wraps as a unit (and links become clickable ranges)
+    // instead of laying out one block per child. Selectable text always takes
+    // this path, since selection works on the flattened string.
+    if (selectable || !element.children.is_empty()) && inline_text::is_inline_text(element, tree)
+    {
+        let inline = inline_text::collect_inline_text(element, tree);
+        let state = text_states.entry(element.id).or_default();
+        let el = el.child(inline_text::render_inline_text(
+            element,
+            inline,
+            state,
+            focus_handles.get(&element.id),
+            event_callback,
+        ));
+        return animate(el, animation, element, tree, apply_text_element_styles);
     }
 
     if let Some(ref content) = element.content {
-        el = el.child(content.clone());
+        let transform = element.style.as_ref().and_then(|s| s.text_transform.as_deref());
+        el = el.child(crate::style::transform_text(content, transform));
     }
 
     for &child_id in &element.children {
@@ -1045,39 +1630,375 @@ pub(crate) fn build_text(
             event_callback,
             focus_handles,
             scroll_handles,
+            text_states,
             custom_registry,
             window,
             cx,
         ));
     }
 
-    el.into_any_element()
+    animate(el, animation, element, tree, apply_text_element_styles)
+}
+
+/// The subset of styles a text element's wrapper div takes.
+fn apply_text_element_styles<E: gpui::Styled>(mut el: E, style: &StyleDesc) -> E {
+    el = apply_display(el, style);
+    el = apply_grid_placement(el, style);
+    if let Some(hex) = style.color.as_ref().and_then(|c| parse_color_hex(c)) {
+        el = el.text_color(gpui::rgba(hex));
+    }
+    el = apply_background(el, style);
+    apply_text_styles(el, style)
 }
 
 // ── Style application ────────────────────────────────────────────────
 
-pub(crate) fn apply_width<E: gpui::Styled>(el: E, dim: &crate::style::DimensionValue) -> E {
+/// GPUI length for a DimensionValue. resolve_style() has already turned
+/// relative units into pixels; any that get here unresolved (e.g. from a
+/// keyframe) fall back to the default 16px font size.
+pub(crate) fn to_length(dim: DimensionValue) -> gpui::Length {
+    match dim.resolve(&crate::units::LengthContext::default(), None) {
+        DimensionValue::Pixels(v) => gpui::px(v as f32).into(),
+        DimensionValue::Percentage(v) => gpui::relative(v as f32).into(),
+        _ => gpui::Length::Auto,
+    }
+}
+
+/// Like to_length() for fields that can't be `auto` (padding, gap).
+pub(crate) fn to_definite_length(dim: DimensionValue) -> Option<gpui::DefiniteLength> {
+    match to_length(dim) {
+        gpui::Length::Definite(length) => Some(length),
+        gpui::Length::Auto => None,
+    }
+}
+
+pub(crate) fn apply_width<E: gpui::Styled>(el: E, dim: &DimensionValue) -> E {
     match dim {
-        crate::style::DimensionValue::Pixels(v) => el.w(gpui::px(*v as f32)),
-        crate::style::DimensionValue::Percentage(v) if *v >= 0.999 => el.w_full(),
-        crate::style::DimensionValue::Percentage(v) => el.w(gpui::relative(*v as f32)),
-        crate::style::DimensionValue::Auto => el,
+        DimensionValue::Auto => el,
+        dim => el.w(to_length(*dim)),
     }
 }
 
-pub(crate) fn apply_height<E: gpui::Styled>(el: E, dim: &crate::style::DimensionValue) -> E {
+pub(crate) fn apply_height<E: gpui::Styled>(el: E, dim: &DimensionValue) -> E {
     match dim {
-        crate::style::DimensionValue::Pixels(v) => el.h(gpui::px(*v as f32)),
-        crate::style::DimensionValue::Percentage(v) if *v >= 0.999 => el.h_full(),
-        crate::style::DimensionValue::Percentage(v) => el.h(gpui::relative(*v as f32)),
-        crate::style::DimensionValue::Auto => el,
+        DimensionValue::Auto => el,
+        dim => el.h(to_length(*dim)),
     }
 }
 
+/// Text properties shared by apply_styles() (inherited by descendants via
+/// GPUI's text style cascade) and build_text().
+/// `letterSpacing` has no GPUI equivalent and is skipped with a warning.
+pub(crate) fn apply_text_styles<E: gpui::Styled>(mut el: E, style: &StyleDesc) -> E {
+    if style
+        .letter_spacing
+        .is_some_and(|spacing| spacing != DimensionValue::Pixels(0.0))
+    {
+        crate::style::warn_once(
+            "unsupported letterSpacing: GPUI's text system has no letter spacing, skipping it"
+                .to_string(),
+        );
+    }
+    if let Some(size) = style.font_size {
+        let default = crate::units::LengthContext::default();
+        if let DimensionValue::Pixels(size) = size.resolve(&default, Some(default.font_size)) {
+            el = el.text_size(gpui::px(size as f32));
+        }
+    }
+    if let Some(ref family) = style.font_family {
+        el = el.font_family(family.clone());
+    }
+    if let Some(ref weight) = style.font_weight {
+        el = el.font_weight(parse_font_weight(weight));
+    }
+    match style.font_style.as_deref() {
+        Some("italic") | Some("oblique") => el = el.italic(),
+        Some("normal") => el = el.not_italic(),
+        _ => {}
+    }
+    match style.text_align.as_deref() {
+        Some("left") | Some("start") => el = el.text_left(),
+        Some("center") => el = el.text_center(),
+        Some("right") | Some("end") => el = el.text_right(),
+        _ => {}
+    }
+    if let Some(line_height) = style.line_height.and_then(to_definite_length) {
+        el = el.line_height(line_height);
+    }
+    if let Some(decoration) = crate::style::parse_text_decoration(style) {
+        if decoration.underline {
+            el = el.underline();
+            if decoration.wavy {
+                el = el.text_decoration_wavy();
+            }
+            if let Some(hex) = decoration.color {
+                el = el.text_decoration_color(gpui::rgba(hex));
+            }
+        } else {
+            el = el.text_decoration_none();
+        }
+        // GPUI draws strikethrough in the text color; there is no
+        // Styled setter for its color or style.
+        if decoration.line_through {
+            el = el.line_through();
+        }
+    }
+    match style.white_space.as_deref() {
+        Some("nowrap") => el = el.whitespace_nowrap(),
+        Some("normal") => el = el.whitespace_normal(),
+        _ => {}
+    }
+    match style.text_overflow.as_deref() {
+        Some("ellipsis") => el = el.text_ellipsis(),
+        Some("ellipsis-start") => el = el.text_ellipsis_start(),
+        _ => {}
+    }
+    if let Some(clamp) = style.line_clamp {
+        if clamp >= 1.0 {
+            el = el.line_clamp(clamp as usize);
+        }
+    }
+    el
+}
+
+/// The element's style with `currentColor` resolved against its inherited
+/// text color and relative lengths (em, rem, vw, vh, calc) resolved to
+/// pixels. Borrowed (no clone) when the style uses neither.
+pub(crate) fn resolve_style<'a>(
+    element: &'a crate::retained_tree::RetainedElement,
+    tree: &RetainedTree,
+) -> Option<std::borrow::Cow<'a, StyleDesc>> {
+    let style = element.animated_style.as_ref().or(element.style.as_ref())?;
+    let mut resolved = std::borrow::Cow::Borrowed(style);
+    if style.uses_current_color() {
+        let color = inherited_text_color(element, tree);
+        resolved = std::borrow::Cow::Owned(resolved.with_current_color(&color));
+    }
+    if style.uses_relative_lengths() {
+        let lengths = crate::units::length_context(element, tree);
+        resolved = std::borrow::Cow::Owned(resolved.with_resolved_lengths(&lengths));
+    }
+    Some(resolved)
+}
+
+/// True if `element` or one of its ancestors has the `disabled` prop, as
+/// everything inside a disabled fieldset is disabled in HTML.
+fn is_disabled(element: &crate::retained_tree::RetainedElement, tree: &RetainedTree) -> bool {
+    let mut current = Some(element);
+    while let Some(el) = current {
+        let disabled = el.custom_props.get("disabled").and_then(|v| v.as_bool());
+        if disabled == Some(true) {
+            return true;
+        }
+        current = el.parent.and_then(|id| tree.elements.get(&id));
+    }
+    false
+}
+
+/// The `group` prop: a name descendants' `groupHover` styles react to.
+fn group_name(element: &crate::retained_tree::RetainedElement) -> Option<gpui::SharedString> {
+    let name = element.custom_props.get("group")?.as_str()?;
+    Some(gpui::SharedString::from(name.to_string()))
+}
+
+/// The group of the nearest ancestor with a `group` prop, so nested groups
+/// shadow outer ones.
+fn ancestor_group(
+    element: &crate::retained_tree::RetainedElement,
+    tree: &RetainedTree,
+) -> Option<gpui::SharedString> {
+    let mut current = element.parent.and_then(|id| tree.elements.get(&id));
+    while let Some(el) = current {
+        if let Some(group) = group_name(el) {
+            return Some(group);
+        }
+        current = el.parent.and_then(|parent_id| tree.elements.get(&parent_id));
+    }
+    None
+}
+
+/// False if the nearest `pointerEvents` on `element` or its ancestors is
+/// `"none"` (the property is inherited, as in CSS).
+fn has_pointer_events(element: &crate::retained_tree::RetainedElement, tree: &RetainedTree) -> bool {
+    let mut current = Some(element);
+    while let Some(el) = current {
+        match el.style.as_ref().and_then(|s| s.pointer_events.as_deref()) {
+            Some("none") => return false,
+            Some(_) => return true,
+            None => {}
+        }
+        current = el.parent.and_then(|parent_id| tree.elements.get(&parent_id));
+    }
+    true
+}
+
+/// The text color `element` renders with: its own `color`, else the nearest
+/// ancestor's (GPUI cascades text_color down the element tree). GPUI's
+/// default text color is black.
+fn inherited_text_color(element: &crate::retained_tree::RetainedElement, tree: &RetainedTree) -> String {
+    let mut current = Some(element);
+    while let Some(el) = current {
+        if let Some(color) = el.style.as_ref().and_then(|s| s.color.as_deref()) {
+            if !color.eq_ignore_ascii_case("currentcolor") {
+                return color.to_string();
+            }
+        }
+        current = el.parent.and_then(|parent_id| tree.elements.get(&parent_id));
+    }
+    "#000000".to_string()
+}
+
+/// Apply `backgroundColor` (solid) or `background` (color or linear gradient).
+/// GPUI paints one background, so the two don't layer: a gradient replaces
+/// `backgroundColor` rather than painting over it, and `backgroundColor`
+/// replaces a solid `background`. Gradients with more than two stops are
+/// rejected by `parse_background`, which leaves `backgroundColor` in effect.
+fn apply_background<E: gpui::Styled>(el: E, style: &StyleDesc) -> E {
+    let parsed = style
+        .background
+        .as_deref()
+        .and_then(crate::style::parse_background);
+    let background = match (&style.background_color, parsed) {
+        (Some(color), Some(gradient @ BackgroundValue::LinearGradient { .. })) => {
+            crate::style::warn_once(format!(
+                "backgroundColor {:?} under a gradient background: GPUI paints one background, using the gradient",
+                color
+            ));
+            Some(gradient)
+        }
+        (Some(color), _) => parse_color_hex(color).map(BackgroundValue::Solid),
+        (None, parsed) => parsed,
+    };
+    match background {
+        Some(BackgroundValue::Solid(hex)) => el.bg(gpui::rgba(hex)),
+        Some(BackgroundValue::LinearGradient { angle, from, to }) => el.bg(gpui::linear_gradient(
+            angle,
+            gpui::linear_color_stop(gpui::rgba(from.0), from.1),
+            gpui::linear_color_stop(gpui::rgba(to.0), to.1),
+        )),
+        None => el,
+    }
+}
+
+/// Convert `boxShadow` to GPUI shadows. Inset shadows and unparseable
+/// colors are skipped with a warning (GPUI only paints outer shadows).
+/// In CSS the first shadow is on top, while GPUI paints them in order, so
+/// the list is reversed.
+fn build_box_shadows(value: &crate::style::BoxShadowValue) -> Vec<gpui::BoxShadow> {
+    value
+        .shadows
+        .iter()
+        .rev()
+        .filter(|shadow| {
+            if shadow.inset {
+                crate::style::warn_once(format!(
+                    "inset box shadow {:?}: GPUI only paints outer shadows, skipping it",
+                    shadow
+                ));
+            }
+            !shadow.inset
+        })
+        .filter_map(|shadow| {
+            let color = parse_color_hex(&shadow.color)?;
+            Some(gpui::BoxShadow {
+                color: gpui::rgba(color).into(),
+                offset: gpui::point(
+                    gpui::px(shadow.offset_x as f32),
+                    gpui::px(shadow.offset_y as f32),
+                ),
+                blur_radius: gpui::px(shadow.blur as f32),
+                spread_radius: gpui::px(shadow.spread as f32),
+            })
+        })
+        .collect()
+}
+
+/// Grid item placement (`gridColumn` / `gridRow`).
+/// Span is applied first: GPUI's col_span() resets both ends of the range,
+/// while col_start()/col_end() replace one end, giving "1 / span 2".
+pub(crate) fn apply_grid_placement<E: gpui::Styled>(mut el: E, style: &StyleDesc) -> E {
+    if let Some(column) = style.grid_column {
+        if let Some(span) = column.span {
+            el = el.col_span(span);
+        }
+        if let Some(start) = column.start {
+            el = el.col_start(start);
+        }
+        if let Some(end) = column.end {
+            el = el.col_end(end);
+        }
+    }
+    if let Some(row) = style.grid_row {
+        if let Some(span) = row.span {
+            el = el.row_span(span);
+        }
+        if let Some(start) = row.start {
+            el = el.row_start(start);
+        }
+        if let Some(end) = row.end {
+            el = el.row_end(end);
+        }
+    }
+    el
+}
+
+/// GPUI has no justify-items, so a grid parent's `justifyItems` is emulated
+/// with auto inline margins on each item (taffy honors auto margins in grid
+/// cells). Applied before the item's own styles so explicit margins win.
+pub(crate) fn apply_parent_justify_items<E: gpui::Styled>(
+    mut el: E,
+    element: &crate::retained_tree::RetainedElement,
+    tree: &RetainedTree,
+) -> E {
+    let parent_style = element
+        .parent
+        .and_then(|parent_id| tree.elements.get(&parent_id))
+        .and_then(|parent| parent.style.as_ref());
+    let Some(parent_style) = parent_style else {
+        return el;
+    };
+    if parent_style.display.as_deref() != Some("grid") {
+        return el;
+    }
+    match parent_style.justify_items.as_deref() {
+        Some("center") => el = el.mx_auto(),
+        Some("start") | Some("left") => el = el.mr_auto(),
+        Some("end") | Some("right") => el = el.ml_auto(),
+        _ => {}
+    }
+    el
+}
+
+/// `display` and `visibility`. display: none only drops the element from
+/// layout and paint — it is still built every frame, so its retained
+/// subtree, scroll offsets and custom element instances survive being
+/// hidden. visibility: hidden keeps the layout box; GPUI skips painting the
+/// element, its children and their event handlers.
+fn apply_display<E: gpui::Styled>(mut el: E, style: &StyleDesc) -> E {
+    match style.display.as_deref() {
+        Some("flex") => el = el.flex(),
+        Some("grid") => el = el.grid(),
+        Some("block") => el = el.block(),
+        Some("none") => el = el.hidden(),
+        _ => {}
+    }
+    match style.visibility.as_deref() {
+        Some("hidden") => el.style().visibility = Some(gpui::Visibility::Hidden),
+        Some("visible") => el.style().visibility = Some(gpui::Visibility::Visible),
+        _ => {}
+    }
+    el
+}
+
 pub(crate) fn apply_styles<E: gpui::Styled>(mut el: E, style: &StyleDesc) -> E {
-    if style.display.as_deref() == Some("flex") {
-        el = el.flex();
+    el = apply_display(el, style);
+    if let Some(template) = style.grid_template_columns {
+        el = el.grid_cols(template.count);
+    }
+    if let Some(template) = style.grid_template_rows {
+        el = el.grid_rows(template.count);
     }
+    el = apply_grid_placement(el, style);
     if style.flex_direction.as_deref() == Some("column") {
         el = el.flex_col();
     }
@@ -1118,8 +2039,17 @@ pub(crate) fn apply_styles<E: gpui::Styled>(mut el: E, style: &StyleDesc) -> E {
         Some("baseline") => { el.style().align_self = Some(gpui::AlignItems::Baseline); }
         _ => {}
     }
-    if let Some(gap) = style.gap {
-        el = el.gap(gpui::px(gap as f32));
+    if let Some(gap) = style.gap.and_then(to_definite_length) {
+        el = el.gap(gap);
+    }
+    if let Some(gap) = style.row_gap.and_then(to_definite_length) {
+        el = el.gap_y(gap);
+    }
+    if let Some(gap) = style.column_gap.and_then(to_definite_length) {
+        el = el.gap_x(gap);
+    }
+    if let Some(basis) = style.flex_basis {
+        el = el.flex_basis(to_length(basis));
     }
     if let Some(ref w) = style.width {
         el = apply_width(el, w);
@@ -1127,129 +2057,83 @@ pub(crate) fn apply_styles<E: gpui::Styled>(mut el: E, style: &StyleDesc) -> E {
     if let Some(ref h) = style.height {
         el = apply_height(el, h);
     }
-    if let Some(ref min_w) = style.min_width {
-        match min_w {
-            crate::style::DimensionValue::Pixels(v) => el = el.min_w(gpui::px(*v as f32)),
-            crate::style::DimensionValue::Percentage(v) => el = el.min_w(gpui::relative(*v as f32)),
-            crate::style::DimensionValue::Auto => {}
-        }
+    if let Some(min_w) = style.min_width.filter(|d| *d != DimensionValue::Auto) {
+        el = el.min_w(to_length(min_w));
     }
-    if let Some(ref min_h) = style.min_height {
-        match min_h {
-            crate::style::DimensionValue::Pixels(v) => el = el.min_h(gpui::px(*v as f32)),
-            crate::style::DimensionValue::Percentage(v) => el = el.min_h(gpui::relative(*v as f32)),
-            crate::style::DimensionValue::Auto => {}
-        }
+    if let Some(min_h) = style.min_height.filter(|d| *d != DimensionValue::Auto) {
+        el = el.min_h(to_length(min_h));
     }
-    if let Some(ref max_w) = style.max_width {
-        match max_w {
-            crate::style::DimensionValue::Pixels(v) => el = el.max_w(gpui::px(*v as f32)),
-            crate::style::DimensionValue::Percentage(v) => el = el.max_w(gpui::relative(*v as f32)),
-            crate::style::DimensionValue::Auto => {}
-        }
+    if let Some(max_w) = style.max_width.filter(|d| *d != DimensionValue::Auto) {
+        el = el.max_w(to_length(max_w));
     }
-    if let Some(ref max_h) = style.max_height {
-        match max_h {
-            crate::style::DimensionValue::Pixels(v) => el = el.max_h(gpui::px(*v as f32)),
-            crate::style::DimensionValue::Percentage(v) => el = el.max_h(gpui::relative(*v as f32)),
-            crate::style::DimensionValue::Auto => {}
-        }
+    if let Some(max_h) = style.max_height.filter(|d| *d != DimensionValue::Auto) {
+        el = el.max_h(to_length(max_h));
     }
-    if let Some(p) = style.padding {
-        el = el.p(gpui::px(p as f32));
+    // Padding can't be auto; percentages refer to the parent's width.
+    if let Some(p) = style.padding.and_then(to_definite_length) {
+        el = el.p(p);
     }
-    if let Some(pt) = style.padding_top {
-        el = el.pt(gpui::px(pt as f32));
+    if let Some(pt) = style.padding_top.and_then(to_definite_length) {
+        el = el.pt(pt);
     }
-    if let Some(pr) = style.padding_right {
-        el = el.pr(gpui::px(pr as f32));
+    if let Some(pr) = style.padding_right.and_then(to_definite_length) {
+        el = el.pr(pr);
     }
-    if let Some(pb) = style.padding_bottom {
-        el = el.pb(gpui::px(pb as f32));
+    if let Some(pb) = style.padding_bottom.and_then(to_definite_length) {
+        el = el.pb(pb);
     }
-    if let Some(pl) = style.padding_left {
-        el = el.pl(gpui::px(pl as f32));
+    if let Some(pl) = style.padding_left.and_then(to_definite_length) {
+        el = el.pl(pl);
     }
+    // Margins may be auto: `margin: "0 auto"`-style centering via marginLeft/Right.
     if let Some(m) = style.margin {
-        el = el.m(gpui::px(m as f32));
+        el = el.m(to_length(m));
     }
     if let Some(mt) = style.margin_top {
-        el = el.mt(gpui::px(mt as f32));
+        el = el.mt(to_length(mt));
     }
     if let Some(mr) = style.margin_right {
-        el = el.mr(gpui::px(mr as f32));
+        el = el.mr(to_length(mr));
     }
     if let Some(mb) = style.margin_bottom {
-        el = el.mb(gpui::px(mb as f32));
+        el = el.mb(to_length(mb));
     }
     if let Some(ml) = style.margin_left {
-        el = el.ml(gpui::px(ml as f32));
+        el = el.ml(to_length(ml));
     }
     match style.position.as_deref() {
-        Some("absolute") => el = el.absolute(),
-        Some("relative") => el = el.relative(),
+        // Fixed elements are absolute inside a window-sized layer (build_div).
+        Some("absolute") | Some("fixed") => el = el.absolute(),
+        // Sticky offsets follow the scroll position (build_div); its insets
+        // are thresholds, not offsets.
+        Some("relative") | Some("sticky") => el = el.relative(),
         _ => {}
     }
-    if let Some(top) = style.top {
-        el = el.top(gpui::px(top as f32));
-    }
-    if let Some(right) = style.right {
-        el = el.right(gpui::px(right as f32));
-    }
-    if let Some(bottom) = style.bottom {
-        el = el.bottom(gpui::px(bottom as f32));
-    }
-    if let Some(left) = style.left {
-        el = el.left(gpui::px(left as f32));
-    }
-    if let Some(ref bg) = style
-        .background_color
-        .as_ref()
-        .or(style.background.as_ref())
-    {
-        if let Some(hex) = parse_color_hex(bg) {
-            el = el.bg(gpui::rgba(hex));
+    if style.position.as_deref() != Some("sticky") {
+        if let Some(top) = style.top {
+            el = el.top(to_length(top));
         }
-    }
-    if let Some(ref color) = style.color {
-        if let Some(hex) = parse_color_hex(color) {
-            el = el.text_color(gpui::rgba(hex));
+        if let Some(right) = style.right {
+            el = el.right(to_length(right));
         }
-    }
-    if let Some(ref family) = style.font_family {
-        el = el.font_family(family.clone());
-    }
-    if let Some(ref weight) = style.font_weight {
-        el = el.font_weight(parse_font_weight(weight));
-    }
-    match style.white_space.as_deref() {
-        Some("nowrap") => el = el.whitespace_nowrap(),
-        Some("normal") => el = el.whitespace_normal(),
-        _ => {}
-    }
-    match style.text_overflow.as_deref() {
-        Some("ellipsis") => el = el.text_ellipsis(),
-        Some("ellipsis-start") => el = el.text_ellipsis_start(),
-        _ => {}
-    }
-    if let Some(clamp) = style.line_clamp {
-        if clamp >= 1.0 {
-            el = el.line_clamp(clamp as usize);
+        if let Some(bottom) = style.bottom {
+            el = el.bottom(to_length(bottom));
         }
-    }
-    if let Some(radius) = style.border_radius {
-        el = el.rounded(gpui::px(radius as f32));
-    }
-    if let Some(width) = style.border_width {
-        if width > 0.0 {
-            el = el.border(gpui::px(width as f32));
+        if let Some(left) = style.left {
+            el = el.left(to_length(left));
         }
     }
-    if let Some(ref color) = style.border_color {
+    el = apply_background(el, style);
+    if let Some(ref color) = style.color {
         if let Some(hex) = parse_color_hex(color) {
-            el = el.border_color(gpui::rgba(hex));
+            el = el.text_color(gpui::rgba(hex));
         }
     }
+    el = apply_text_styles(el, style);
+    el = crate::borders::apply(el, style);
+    if let Some(ref box_shadow) = style.box_shadow {
+        el = el.shadow(build_box_shadows(box_shadow));
+    }
     if let Some(opacity) = style.opacity {
         el = el.opacity(opacity as f32);
     }
@@ -1298,21 +2182,29 @@ pub(crate) fn mouse_button_to_u32(button: gpui::MouseButton) -> u32 {
 /// caller customize it via a closure, then sends it through the callback.
 /// Production: queues on Node.js event loop via ThreadsafeFunction.
 /// Tests: pushes to a synchronous Vec for drainEvents().
+/// With a synchronous handler set, input dispatched by tick() calls it
+/// directly instead, and its response is returned for respond().
 pub(crate) fn emit_event_full(
     callback: &Option<EventCallback>,
     element_id: u64,
     event_type: &str,
     build: impl FnOnce(&mut EventPayload),
-) {
-    if let Some(cb) = callback {
-        let mut payload = EventPayload {
-            element_id: element_id as f64,
-            event_type: event_type.to_string(),
-            ..Default::default()
-        };
-        build(&mut payload);
-        cb(payload);
+) -> EventResponse {
+    let Some(cb) = callback else {
+        return EventResponse::default();
+    };
+    let mut payload = EventPayload {
+        element_id: element_id as f64,
+        event_type: event_type.to_string(),
+        timestamp: crate::element_tree::event_timestamp(),
+        ..Default::default()
+    };
+    build(&mut payload);
+    if let Some(response) = crate::sync_dispatch::call(&payload) {
+        return response;
     }
+    cb(payload);
+    EventResponse::default()
 }
 
 // ── Batch processing ─────────────────────────────────────────────
@@ -1326,7 +2218,8 @@ enum BatchOp {
     AppendChild { parent_id: u64, child_id: u64 },
     RemoveChild { parent_id: u64, child_id: u64 },
     InsertBefore { parent_id: u64, child_id: u64, before_id: u64 },
-    SetStyle { id: u64, style: StyleDesc },
+    SetStyle { id: u64, style: crate::theme::StyleInput },
+    SetClassName { id: u64, class_name: String },
     SetText { id: u64, content: String },
     SetEventListener { id: u64, event_type: String, has_handler: bool },
     SetRoot { id: u64 },
@@ -1370,7 +2263,7 @@ fn parse_batch_ops(ops: &[serde_json::Value]) -> Result<Vec<BatchOp>> {
             },
             "setStyle" => {
                 let style_json = batch_str(arr, 2, i)?;
-                let style: StyleDesc = serde_json::from_str(&style_json).map_err(|e| {
+                let style = crate::theme::parse_style_input(&style_json).map_err(|e| {
                     Error::from_reason(format!("Batch op {} setStyle parse error: {}", i, e))
                 })?;
                 BatchOp::SetStyle {
@@ -1378,6 +2271,10 @@ fn parse_batch_ops(ops: &[serde_json::Value]) -> Result<Vec<BatchOp>> {
                     style,
                 }
             }
+            "setClassName" => BatchOp::SetClassName {
+                id: batch_id(arr, 1, i)?,
+                class_name: batch_str(arr, 2, i)?,
+            },
             "setText" => BatchOp::SetText {
                 id: batch_id(arr, 1, i)?,
                 content: batch_str(arr, 2, i)?,
@@ -1467,7 +2364,10 @@ pub(crate) fn apply_batch_to_tree(
                 tree.insert_before(parent_id, child_id, before_id);
             }
             BatchOp::SetStyle { id, style } => {
-                tree.set_style(id, style);
+                tree.set_style_input(id, style);
+            }
+            BatchOp::SetClassName { id, class_name } => {
+                tree.set_class_name(id, &class_name);
             }
             BatchOp::SetText { id, content } => {
                 tree.set_text(id, content);
@@ -7,19 +7,40 @@
 /// All IDs are u64 — JS generates them with an incrementing counter,
 /// passes them as numbers across napi (no string allocation).
 use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
+use crate::animation::Keyframes;
+use crate::drag::DragTargets;
+use crate::keyboard::KeyNames;
+use crate::keymap::KeyBindingSpec;
+use crate::pointer_capture::PointerCapture;
 use crate::style::StyleDesc;
+use crate::style_class::StyleClasses;
+use crate::theme::{StyleInput, Theme};
+use crate::tooltip::TooltipStates;
+use crate::units::FontSizes;
 
 pub struct RetainedElement {
     pub id: u64,
     pub element_type: String,
+    /// Effective style: classes and inline style merged, theme resolved.
     pub style: Option<StyleDesc>,
+    /// Raw inline style JSON as sent by setStyle. Kept so `style` can be
+    /// recomputed when the theme or a referenced class changes.
+    pub style_source: Option<serde_json::Value>,
+    /// Style class names from className, merged in order before the inline style.
+    pub class_names: Vec<String>,
+    /// Mid-transition style written by transition::Transitions each frame;
+    /// rendered instead of `style` while set.
+    pub animated_style: Option<StyleDesc>,
     pub content: Option<String>,
     pub events: HashSet<String>,
     pub children: Vec<u64>,
     pub parent: Option<u64>,
-    /// Props for custom elements (input, editor, diff, etc.).
-    /// Keyed by prop name, values are JSON. Ignored for "div" and "text".
+    /// Props for custom elements (input, editor, diff, etc.), plus the few
+    /// forwarded for divs (`disabled`, `group`, `tooltip`, drag props,
+    /// `capturePointer`) and text spans.
+    /// Keyed by prop name, values are JSON.
     pub custom_props: HashMap<String, serde_json::Value>,
 }
 
@@ -29,6 +50,9 @@ impl RetainedElement {
             id,
             element_type,
             style: None,
+            style_source: None,
+            class_names: Vec::new(),
+            animated_style: None,
             content: None,
             events: HashSet::new(),
             children: Vec::new(),
@@ -38,10 +62,78 @@ impl RetainedElement {
     }
 }
 
+/// An element's laid-out bounds, relative to the content of its nearest
+/// scroll container (the window when there is none), so they stay valid
+/// while that container scrolls.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutBounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
 pub struct RetainedTree {
     pub elements: HashMap<u64, RetainedElement>,
     /// The root element ID set by appendChildToContainer.
     pub root_id: Option<u64>,
+    pub theme: Theme,
+    pub style_classes: StyleClasses,
+    /// Keyframes registered via registerAnimation, by name.
+    pub animations: HashMap<String, Arc<Keyframes>>,
+    /// Window viewport (width, height), set by GpuixView::render() for
+    /// `vw`/`vh` lengths.
+    pub viewport: (f64, f64),
+    /// Laid-out bounds of elements whose children use calc() with
+    /// percentages, and of sticky elements and their parents. Written during
+    /// prepaint and read on the next frame.
+    pub layout_bounds: Arc<Mutex<HashMap<u64, LayoutBounds>>>,
+    /// Font sizes for em/rem lengths, recomputed by GpuixView::render()
+    /// before each frame is built.
+    pub font_sizes: FontSizes,
+    /// Tooltip phase of hovered elements with a `tooltip` prop, written by
+    /// their hover listeners and read when building the next frame.
+    pub tooltips: TooltipStates,
+    /// Drop targets the current drag is over, written by their drag
+    /// listeners; see drag.rs.
+    pub drag_targets: DragTargets,
+    /// The element holding the pointer after a mouse down, if any; see
+    /// pointer_capture.rs.
+    pub pointer_capture: PointerCapture,
+    /// Names used for `key` in keyboard payloads, set by setKeyNames().
+    pub key_names: KeyNames,
+    /// Bindings from registerKeyBindings(), in registration order.
+    pub key_bindings: Vec<KeyBindingSpec>,
+    /// Set when key_bindings changed; GpuixView::render() rebinds them.
+    pub key_bindings_dirty: bool,
+    /// Set by set_theme() and register_style_class(); derived styles are
+    /// recomputed on the next render.
+    styles_dirty: bool,
+}
+
+/// Compute an element's effective style from its classes and inline JSON,
+/// logging (not failing) on type errors — a token or class whose value
+/// doesn't fit its field drops just that property (see theme::resolve_style).
+fn compute_style(
+    element: &RetainedElement,
+    classes: &StyleClasses,
+    theme: &Theme,
+) -> Option<StyleDesc> {
+    let raw = if element.class_names.is_empty() {
+        element.style_source.clone()?
+    } else {
+        classes.merge(&element.class_names, element.style_source.as_ref())
+    };
+    Some(crate::theme::resolve_style(&raw, theme))
+}
+
+/// True if `style` depends on state outside the element (theme or classes).
+fn is_derived(element: &RetainedElement) -> bool {
+    !element.class_names.is_empty()
+        || element
+            .style_source
+            .as_ref()
+            .is_some_and(crate::theme::contains_vars)
 }
 
 impl RetainedTree {
@@ -49,6 +141,19 @@ impl RetainedTree {
         Self {
             elements: HashMap::new(),
             root_id: None,
+            theme: Theme::default(),
+            style_classes: StyleClasses::default(),
+            animations: HashMap::new(),
+            viewport: (0.0, 0.0),
+            layout_bounds: Arc::default(),
+            font_sizes: FontSizes::default(),
+            tooltips: Arc::default(),
+            drag_targets: Arc::default(),
+            pointer_capture: Arc::default(),
+            key_names: KeyNames::default(),
+            key_bindings: Vec::new(),
+            key_bindings_dirty: false,
+            styles_dirty: false,
         }
     }
 
@@ -125,10 +230,87 @@ impl RetainedTree {
         }
     }
 
-    pub fn set_style(&mut self, id: u64, style: StyleDesc) {
-        if let Some(element) = self.elements.get_mut(&id) {
-            // StyleDesc with all None fields = no style (cleared)
-            element.style = Some(style);
+    /// Set a style parsed by theme::parse_style_input(). Styles without
+    /// classes or tokens use the pre-parsed StyleDesc; the rest are computed
+    /// against the current classes and theme immediately.
+    pub fn set_style_input(&mut self, id: u64, input: StyleInput) {
+        let Some(element) = self.elements.get_mut(&id) else {
+            return;
+        };
+        element.style_source = Some(input.raw);
+        element.style = match input.parsed {
+            Some(style) if element.class_names.is_empty() => Some(style),
+            _ => compute_style(element, &self.style_classes, &self.theme),
+        };
+    }
+
+    /// Set the element's classes from a whitespace-separated className.
+    pub fn set_class_name(&mut self, id: u64, class_name: &str) {
+        let Some(element) = self.elements.get_mut(&id) else {
+            return;
+        };
+        let class_names = crate::style_class::parse_class_names(class_name);
+        if class_names == element.class_names {
+            return;
+        }
+        element.class_names = class_names;
+        element.style = compute_style(element, &self.style_classes, &self.theme);
+    }
+
+    /// Register or replace a style class. Elements using it are recomputed
+    /// by resolve_styles(), which GpuixView::render() calls before building.
+    pub fn register_style_class(&mut self, name: &str, style_json: &str) -> Result<(), String> {
+        self.style_classes.register(name, style_json)?;
+        self.styles_dirty = true;
+        Ok(())
+    }
+
+    /// Add key bindings after the existing ones; later bindings win when
+    /// several match. They are bound by the next GpuixView::render().
+    pub fn register_key_bindings(&mut self, bindings_json: &str) -> Result<(), String> {
+        let specs = crate::keymap::parse_bindings(bindings_json)?;
+        self.key_bindings.extend(specs);
+        self.key_bindings_dirty = true;
+        Ok(())
+    }
+
+    /// Remove every key binding on the next GpuixView::render().
+    pub fn clear_key_bindings(&mut self) {
+        self.key_bindings.clear();
+        self.key_bindings_dirty = true;
+    }
+
+    /// Register or replace named keyframes. Running animations pick up the
+    /// new keyframes on the next frame.
+    pub fn register_animation(&mut self, name: &str, keyframes_json: &str) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(format!("Invalid animation name {:?}", name));
+        }
+        let keyframes = Keyframes::from_json(keyframes_json)
+            .map_err(|e| format!("Animation {:?}: {}", name, e))?;
+        self.animations.insert(name.to_string(), Arc::new(keyframes));
+        Ok(())
+    }
+
+    /// Replace the theme. Themed styles are re-resolved by resolve_styles(),
+    /// which GpuixView::render() calls before building elements.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.styles_dirty = true;
+    }
+
+    /// Recompute every class-based or themed style if the theme or a class
+    /// changed since the last call.
+    pub fn resolve_styles(&mut self) {
+        if !self.styles_dirty {
+            return;
+        }
+        self.styles_dirty = false;
+        for element in self.elements.values_mut() {
+            if is_derived(element) {
+                element.style = compute_style(element, &self.style_classes, &self.theme);
+            }
         }
     }
 
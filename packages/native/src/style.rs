@@ -9,21 +9,95 @@ pub enum FontWeightValue {
     Str(String),
 }
 
-/// A dimension value that can be a number (pixels) or a string (percentage, auto, etc.)
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+/// A length: a number (pixels) or a CSS string — `"50%"`, `"auto"`,
+/// `"1.5rem"`, `"2em"`, `"50vw"`, `"10vh"` or `"calc(100% - 24px)"`.
+/// Relative units are resolved to pixels per element at render time
+/// (see units.rs); only Pixels, Percentage and Auto reach GPUI.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DimensionValue {
     Pixels(f64),
     Percentage(f64), // 0.0 to 1.0
+    Rem(f64),
+    Em(f64),
+    /// Fraction of the viewport width (`50vw` → 0.5).
+    Vw(f64),
+    /// Fraction of the viewport height.
+    Vh(f64),
+    Calc(CalcLength),
     Auto,
 }
 
+/// A `calc()` expression, kept as a sum of per-unit terms. Percentages,
+/// vw and vh are fractions like in DimensionValue.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CalcLength {
+    pub px: f64,
+    pub percent: f64,
+    pub em: f64,
+    pub rem: f64,
+    pub vw: f64,
+    pub vh: f64,
+}
+
 impl Default for DimensionValue {
     fn default() -> Self {
         DimensionValue::Auto
     }
 }
 
+impl std::fmt::Display for DimensionValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // Round away float noise from the fraction conversions (0.07 * 100).
+        let n = |v: f64| (v * 1e6).round() / 1e6;
+        match *self {
+            DimensionValue::Pixels(v) => write!(f, "{}px", n(v)),
+            DimensionValue::Percentage(v) => write!(f, "{}%", n(v * 100.0)),
+            DimensionValue::Rem(v) => write!(f, "{}rem", n(v)),
+            DimensionValue::Em(v) => write!(f, "{}em", n(v)),
+            DimensionValue::Vw(v) => write!(f, "{}vw", n(v * 100.0)),
+            DimensionValue::Vh(v) => write!(f, "{}vh", n(v * 100.0)),
+            DimensionValue::Calc(calc) => {
+                let terms = [
+                    (n(calc.percent * 100.0), "%"),
+                    (n(calc.px), "px"),
+                    (n(calc.em), "em"),
+                    (n(calc.rem), "rem"),
+                    (n(calc.vw * 100.0), "vw"),
+                    (n(calc.vh * 100.0), "vh"),
+                ];
+                write!(f, "calc(")?;
+                let mut first = true;
+                for (value, unit) in terms.into_iter().filter(|(v, _)| *v != 0.0) {
+                    match (first, value < 0.0) {
+                        (true, _) => write!(f, "{}{}", value, unit)?,
+                        (false, true) => write!(f, " - {}{}", -value, unit)?,
+                        (false, false) => write!(f, " + {}{}", value, unit)?,
+                    }
+                    first = false;
+                }
+                if first {
+                    write!(f, "0px")?;
+                }
+                write!(f, ")")
+            }
+            DimensionValue::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+/// Pixels serialize as numbers, everything else as its CSS string.
+impl Serialize for DimensionValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            DimensionValue::Pixels(v) => serializer.serialize_f64(*v),
+            other => serializer.collect_str(other),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for DimensionValue {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -37,7 +111,7 @@ impl<'de> Deserialize<'de> for DimensionValue {
             type Value = DimensionValue;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("a number or a string like '100%' or 'auto'")
+                formatter.write_str("a number or a string like '100%', '2rem', 'calc(100% - 8px)' or 'auto'")
             }
 
             fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
@@ -65,21 +139,7 @@ impl<'de> Deserialize<'de> for DimensionValue {
             where
                 E: de::Error,
             {
-                if v == "auto" {
-                    Ok(DimensionValue::Auto)
-                } else if v.ends_with('%') {
-                    let num_str = v.trim_end_matches('%');
-                    match num_str.parse::<f64>() {
-                        Ok(n) => Ok(DimensionValue::Percentage(n / 100.0)),
-                        Err(_) => Err(de::Error::custom(format!("invalid percentage: {}", v))),
-                    }
-                } else {
-                    // Try to parse as a number
-                    match v.parse::<f64>() {
-                        Ok(n) => Ok(DimensionValue::Pixels(n)),
-                        Err(_) => Err(de::Error::custom(format!("invalid dimension: {}", v))),
-                    }
-                }
+                crate::units::parse_dimension(v).map_err(de::Error::custom)
             }
         }
 
@@ -87,6 +147,286 @@ impl<'de> Deserialize<'de> for DimensionValue {
     }
 }
 
+/// Number-or-string helper for values with a custom string syntax.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumOrStr {
+    Num(f64),
+    Str(String),
+}
+
+/// Grid track list (`gridTemplateColumns` / `gridTemplateRows`).
+///
+/// GPUI lays out grids as `repeat(count, minmax(0, 1fr))`, so only the track
+/// count survives parsing. Accepts a count (`3`), `"repeat(3, 1fr)"` or a
+/// track list (`"1fr 1fr 1fr"`). Fixed tracks (`"repeat(2, 40px)"`) or
+/// differing ones (`"200px 1fr"`) can't be laid out: they log a warning and
+/// fall back to the same number of equal tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct GridTemplate {
+    pub count: u16,
+}
+
+impl<'de> Deserialize<'de> for GridTemplate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        match NumOrStr::deserialize(deserializer)? {
+            NumOrStr::Num(n) if (1.0..=u16::MAX as f64).contains(&n) => {
+                Ok(GridTemplate { count: n as u16 })
+            }
+            NumOrStr::Num(n) => Err(D::Error::custom(format!("invalid grid track count: {}", n))),
+            NumOrStr::Str(s) => parse_grid_template(&s).map_err(D::Error::custom),
+        }
+    }
+}
+
+fn parse_grid_template(value: &str) -> Result<GridTemplate, String> {
+    let mut tracks: Vec<&str> = Vec::new();
+    for token in split_css_tokens(value) {
+        if let Some(inner) = token
+            .strip_prefix("repeat(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let (count, track) = inner
+                .split_once(',')
+                .ok_or_else(|| format!("invalid grid repeat(): {}", token))?;
+            let count: u16 = count
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid grid repeat count: {}", token))?;
+            tracks.extend(std::iter::repeat_n(track.trim(), count as usize));
+        } else {
+            tracks.push(token);
+        }
+    }
+
+    if tracks.is_empty() {
+        return Err(format!("empty grid template: {:?}", value));
+    }
+    let fractions: Vec<Option<f64>> = tracks.iter().map(|track| grid_fraction(track)).collect();
+    if fractions.iter().any(|fr| *fr != fractions[0]) || fractions[0].is_none() {
+        warn_once(format!(
+            "unsupported grid template {:?}: GPUI only lays out equal fractional tracks, using {} equal tracks",
+            value,
+            tracks.len()
+        ));
+    }
+    let count = u16::try_from(tracks.len()).map_err(|_| "too many grid tracks".to_string())?;
+    Ok(GridTemplate { count })
+}
+
+/// The fraction of a `1fr` or `minmax(0, 1fr)` track; None for other sizes.
+fn grid_fraction(track: &str) -> Option<f64> {
+    let track = match track
+        .strip_prefix("minmax(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        Some(inner) => {
+            let (min, max) = inner.split_once(',')?;
+            if !matches!(min.trim(), "0" | "0px") {
+                return None;
+            }
+            max.trim()
+        }
+        None => track,
+    };
+    track
+        .strip_suffix("fr")?
+        .parse::<f64>()
+        .ok()
+        .filter(|fr| *fr > 0.0 && fr.is_finite())
+}
+
+/// Grid item placement (`gridColumn` / `gridRow`), CSS line syntax:
+/// `2`, `"span 2"`, `"1 / 3"`, `"1 / span 2"`, `"auto"`.
+/// Lines are 1-based; negative lines count from the end.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GridLine {
+    pub start: Option<i16>,
+    pub end: Option<i16>,
+    pub span: Option<u16>,
+}
+
+impl<'de> Deserialize<'de> for GridLine {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        match NumOrStr::deserialize(deserializer)? {
+            NumOrStr::Num(n) if n.fract() == 0.0 && n != 0.0 && n.abs() <= i16::MAX as f64 => {
+                Ok(GridLine {
+                    start: Some(n as i16),
+                    ..Default::default()
+                })
+            }
+            NumOrStr::Num(n) => Err(D::Error::custom(format!("invalid grid line: {}", n))),
+            NumOrStr::Str(s) => parse_grid_line(&s).map_err(D::Error::custom),
+        }
+    }
+}
+
+fn parse_grid_line(value: &str) -> Result<GridLine, String> {
+    let mut line = GridLine::default();
+    let (start, end) = match value.split_once('/') {
+        Some((start, end)) => (start.trim(), Some(end.trim())),
+        None => (value.trim(), None),
+    };
+
+    let invalid = || format!("invalid grid line: {:?}", value);
+    let parse_span = |part: &str| -> Result<Option<u16>, String> {
+        match part.strip_prefix("span") {
+            Some(n) => n.trim().parse().map(Some).map_err(|_| invalid()),
+            None => Ok(None),
+        }
+    };
+
+    if let Some(span) = parse_span(start)? {
+        line.span = Some(span);
+    } else if start != "auto" {
+        line.start = Some(start.parse().map_err(|_| invalid())?);
+    }
+    if let Some(end) = end {
+        if let Some(span) = parse_span(end)? {
+            line.span = Some(span);
+        } else if end != "auto" {
+            line.end = Some(end.parse().map_err(|_| invalid())?);
+        }
+    }
+    Ok(line)
+}
+
+/// One shadow of `boxShadow`. Lengths are pixels.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShadowDesc {
+    #[serde(default)]
+    pub offset_x: f64,
+    #[serde(default)]
+    pub offset_y: f64,
+    #[serde(default)]
+    pub blur: f64,
+    #[serde(default)]
+    pub spread: f64,
+    /// Any color syntax; defaults to `currentColor`, as in CSS.
+    #[serde(default = "current_color")]
+    pub color: String,
+    /// Inner shadows are parsed for CSS compatibility but not rendered —
+    /// GPUI only paints outer shadows.
+    #[serde(default)]
+    pub inset: bool,
+}
+
+fn current_color() -> String {
+    "currentColor".to_string()
+}
+
+/// `boxShadow` — a CSS string (`"0 2px 8px rgba(0,0,0,0.3), 0 0 0 1px #fff2"`,
+/// or `"none"`), a shadow object, or an array of shadow objects. Shadows
+/// are painted in order, the first on top.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct BoxShadowValue {
+    pub shadows: Vec<ShadowDesc>,
+}
+
+impl<'de> Deserialize<'de> for BoxShadowValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Input {
+            Str(String),
+            One(ShadowDesc),
+            Many(Vec<ShadowDesc>),
+        }
+
+        let shadows = match Input::deserialize(deserializer)? {
+            Input::Str(s) => parse_box_shadow(&s).map_err(D::Error::custom)?,
+            Input::One(shadow) => vec![shadow],
+            Input::Many(shadows) => shadows,
+        };
+        Ok(BoxShadowValue { shadows })
+    }
+}
+
+fn parse_box_shadow(value: &str) -> Result<Vec<ShadowDesc>, String> {
+    if value.trim() == "none" {
+        return Ok(Vec::new());
+    }
+    split_top_level(value, ',')
+        .into_iter()
+        .map(|shadow| {
+            let invalid = || format!("invalid box shadow: {:?}", shadow.trim());
+            let mut lengths = Vec::with_capacity(4);
+            let mut color = None;
+            let mut inset = false;
+            for token in split_css_tokens(shadow) {
+                if token == "inset" {
+                    inset = true;
+                } else if let Some(length) = parse_px(token) {
+                    lengths.push(length);
+                } else if color.is_none() {
+                    color = Some(token.to_string());
+                } else {
+                    return Err(invalid());
+                }
+            }
+            let [offset_x, offset_y, blur, spread] = match lengths[..] {
+                [x, y] => [x, y, 0.0, 0.0],
+                [x, y, blur] => [x, y, blur, 0.0],
+                [x, y, blur, spread] => [x, y, blur, spread],
+                _ => return Err(invalid()),
+            };
+            Ok(ShadowDesc {
+                offset_x,
+                offset_y,
+                blur,
+                spread,
+                color: color.unwrap_or_else(current_color),
+                inset,
+            })
+        })
+        .collect()
+}
+
+/// `"4"` or `"4px"` → 4.0.
+fn parse_px(token: &str) -> Option<f64> {
+    token.strip_suffix("px").unwrap_or(token).parse().ok()
+}
+
+/// Split on `separator` outside parentheses, e.g. the commas between
+/// shadows but not those inside `rgba(0, 0, 0, 0.3)`.
+pub fn split_top_level(value: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c == separator && depth == 0 => {
+                parts.push(&value[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
 /// Style description that can be serialized from JS
 /// Note: This is only used for JSON deserialization, not direct napi binding
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
@@ -101,14 +441,23 @@ pub struct StyleDesc {
     pub flex_wrap: Option<String>,
     pub flex_grow: Option<f64>,
     pub flex_shrink: Option<f64>,
-    pub flex_basis: Option<f64>,
+    pub flex_basis: Option<DimensionValue>,
     pub align_items: Option<String>,
     pub align_self: Option<String>,
     pub align_content: Option<String>,
     pub justify_content: Option<String>,
-    pub gap: Option<f64>,
-    pub row_gap: Option<f64>,
-    pub column_gap: Option<f64>,
+    pub gap: Option<DimensionValue>,
+    pub row_gap: Option<DimensionValue>,
+    pub column_gap: Option<DimensionValue>,
+
+    // Grid
+    pub grid_template_columns: Option<GridTemplate>,
+    pub grid_template_rows: Option<GridTemplate>,
+    pub grid_column: Option<GridLine>,
+    pub grid_row: Option<GridLine>,
+    /// Inline-axis alignment of grid items in their cells:
+    /// "start", "center", "end" or "stretch" (default).
+    pub justify_items: Option<String>,
 
     // Sizing - now supports both numbers and strings like "100%" or "auto"
     pub width: Option<DimensionValue>,
@@ -118,26 +467,31 @@ pub struct StyleDesc {
     pub max_width: Option<DimensionValue>,
     pub max_height: Option<DimensionValue>,
 
-    // Spacing (padding)
-    pub padding: Option<f64>,
-    pub padding_top: Option<f64>,
-    pub padding_right: Option<f64>,
-    pub padding_bottom: Option<f64>,
-    pub padding_left: Option<f64>,
+    // Spacing (padding) — percentages resolve against the parent's width
+    pub padding: Option<DimensionValue>,
+    pub padding_top: Option<DimensionValue>,
+    pub padding_right: Option<DimensionValue>,
+    pub padding_bottom: Option<DimensionValue>,
+    pub padding_left: Option<DimensionValue>,
 
-    // Spacing (margin)
-    pub margin: Option<f64>,
-    pub margin_top: Option<f64>,
-    pub margin_right: Option<f64>,
-    pub margin_bottom: Option<f64>,
-    pub margin_left: Option<f64>,
+    // Spacing (margin) — "auto" takes up the free space (centering)
+    pub margin: Option<DimensionValue>,
+    pub margin_top: Option<DimensionValue>,
+    pub margin_right: Option<DimensionValue>,
+    pub margin_bottom: Option<DimensionValue>,
+    pub margin_left: Option<DimensionValue>,
 
     // Position
+    /// "relative", "absolute" or "fixed" (relative to the window).
     pub position: Option<String>,
-    pub top: Option<f64>,
-    pub right: Option<f64>,
-    pub bottom: Option<f64>,
-    pub left: Option<f64>,
+    /// Positive values paint above all unlayered content (GPUI deferred
+    /// draw with this priority); negative values paint absolutely
+    /// positioned elements below their in-flow siblings.
+    pub z_index: Option<i32>,
+    pub top: Option<DimensionValue>,
+    pub right: Option<DimensionValue>,
+    pub bottom: Option<DimensionValue>,
+    pub left: Option<DimensionValue>,
 
     // Background & Colors
     pub background: Option<String>,
@@ -146,23 +500,56 @@ pub struct StyleDesc {
     pub opacity: Option<f64>,
 
     // Border
-    pub border_width: Option<f64>,
+    /// Shorthand: `"1px solid #333"`, `"2px dashed"`, `"none"`.
+    pub border: Option<String>,
+    pub border_top: Option<String>,
+    pub border_right: Option<String>,
+    pub border_bottom: Option<String>,
+    pub border_left: Option<String>,
+    pub border_width: Option<DimensionValue>,
+    pub border_top_width: Option<DimensionValue>,
+    pub border_right_width: Option<DimensionValue>,
+    pub border_bottom_width: Option<DimensionValue>,
+    pub border_left_width: Option<DimensionValue>,
     pub border_color: Option<String>,
-    pub border_radius: Option<f64>,
-    pub border_top_left_radius: Option<f64>,
-    pub border_top_right_radius: Option<f64>,
-    pub border_bottom_left_radius: Option<f64>,
-    pub border_bottom_right_radius: Option<f64>,
+    pub border_top_color: Option<String>,
+    pub border_right_color: Option<String>,
+    pub border_bottom_color: Option<String>,
+    pub border_left_color: Option<String>,
+    /// "solid" (default), "dashed", or "none" / "hidden" (no border).
+    /// "dotted" renders as dashed.
+    pub border_style: Option<String>,
+    pub border_radius: Option<DimensionValue>,
+    pub border_top_left_radius: Option<DimensionValue>,
+    pub border_top_right_radius: Option<DimensionValue>,
+    pub border_bottom_left_radius: Option<DimensionValue>,
+    pub border_bottom_right_radius: Option<DimensionValue>,
+
+    // Shadow
+    pub box_shadow: Option<BoxShadowValue>,
 
     // Text
-    pub font_size: Option<f64>,
+    pub font_size: Option<DimensionValue>,
     pub font_family: Option<String>,
     pub font_weight: Option<FontWeightValue>,
+    pub font_style: Option<String>,
     pub text_align: Option<String>,
-    pub line_height: Option<f64>,
+    /// Number = pixels, "150%" = relative to the font size.
+    pub line_height: Option<DimensionValue>,
     pub white_space: Option<String>,
     pub text_overflow: Option<String>,
     pub line_clamp: Option<f64>,
+    /// "underline", "line-through", "none", or a shorthand like
+    /// "underline wavy #f38ba8".
+    pub text_decoration: Option<String>,
+    pub text_decoration_color: Option<String>,
+    /// "solid" or "wavy".
+    pub text_decoration_style: Option<String>,
+    /// Not rendered — GPUI's text system has no letter-spacing (tracking)
+    /// support. Anything but 0 logs a warning.
+    pub letter_spacing: Option<DimensionValue>,
+    /// "uppercase", "lowercase", "capitalize" or "none".
+    pub text_transform: Option<String>,
 
     // Overflow
     pub overflow: Option<String>,
@@ -171,69 +558,875 @@ pub struct StyleDesc {
 
     // Cursor
     pub cursor: Option<String>,
+    /// `"none"` makes hit-testing pass through the element (and, being
+    /// inherited, its descendants unless they set `"auto"`).
+    pub pointer_events: Option<String>,
+
+    // Transitions
+    /// CSS transition list, e.g. `"opacity 200ms ease-out, backgroundColor 150ms"`.
+    /// Interpolated natively by transition::Transitions.
+    pub transition: Option<String>,
+
+    // Animations
+    /// Registered keyframes reference, e.g. `"pulse 1s ease-in-out infinite"`.
+    /// Driven by GPUI's with_animations (see animation.rs).
+    pub animation: Option<String>,
 
     // Pseudo-selector styles — applied by GPUI natively (no JS round-trip).
     // Uses Box to avoid infinite-size struct (StyleDesc contains StyleDesc).
     pub hover: Option<Box<StyleDesc>>,
     pub active: Option<Box<StyleDesc>>,
+    /// Applied while the element has focus (makes the element focusable).
+    pub focus: Option<Box<StyleDesc>>,
+    /// Applied on top of `focus` when focus came from the keyboard.
+    pub focus_visible: Option<Box<StyleDesc>>,
+    /// Applied while the element's `disabled` prop is true.
+    pub disabled: Option<Box<StyleDesc>>,
+    /// Applied while the nearest ancestor with a `group` prop is hovered.
+    pub group_hover: Option<Box<StyleDesc>>,
+    /// Applied while a drag is over the element.
+    pub drag_over: Option<Box<StyleDesc>>,
+}
+
+const COLOR_FIELD_COUNT: usize = 15;
+
+impl StyleDesc {
+    /// The pseudo-style blocks, set or not.
+    pub fn pseudo_styles(&self) -> [&Option<Box<StyleDesc>>; 7] {
+        [
+            &self.hover,
+            &self.active,
+            &self.focus,
+            &self.focus_visible,
+            &self.disabled,
+            &self.group_hover,
+            &self.drag_over,
+        ]
+    }
+
+    pub fn pseudo_styles_mut(&mut self) -> [&mut Option<Box<StyleDesc>>; 7] {
+        [
+            &mut self.hover,
+            &mut self.active,
+            &mut self.focus,
+            &mut self.focus_visible,
+            &mut self.disabled,
+            &mut self.group_hover,
+            &mut self.drag_over,
+        ]
+    }
+
+    /// References to every color-valued field (not the pseudo sub-styles).
+    /// `textDecoration` and the border shorthands are included since they
+    /// may carry a color. Keep in sync with color_fields_mut().
+    fn color_fields(&self) -> [&Option<String>; COLOR_FIELD_COUNT] {
+        [
+            &self.background,
+            &self.background_color,
+            &self.color,
+            &self.border,
+            &self.border_top,
+            &self.border_right,
+            &self.border_bottom,
+            &self.border_left,
+            &self.border_color,
+            &self.border_top_color,
+            &self.border_right_color,
+            &self.border_bottom_color,
+            &self.border_left_color,
+            &self.text_decoration,
+            &self.text_decoration_color,
+        ]
+    }
+
+    fn color_fields_mut(&mut self) -> [&mut Option<String>; COLOR_FIELD_COUNT] {
+        [
+            &mut self.background,
+            &mut self.background_color,
+            &mut self.color,
+            &mut self.border,
+            &mut self.border_top,
+            &mut self.border_right,
+            &mut self.border_bottom,
+            &mut self.border_left,
+            &mut self.border_color,
+            &mut self.border_top_color,
+            &mut self.border_right_color,
+            &mut self.border_bottom_color,
+            &mut self.border_left_color,
+            &mut self.text_decoration,
+            &mut self.text_decoration_color,
+        ]
+    }
+
+    /// True if any field (including pseudo-styles and shadow colors) uses
+    /// `currentColor`, or a border side has a width but no color.
+    pub fn uses_current_color(&self) -> bool {
+        self.uses_current_color_token()
+            || resolve_borders(self)
+                .is_some_and(|borders| borders.colorless_sides().next().is_some())
+    }
+
+    fn uses_current_color_token(&self) -> bool {
+        let own = self
+            .color_fields()
+            .into_iter()
+            .any(|field| field.as_deref().is_some_and(contains_current_color));
+        let shadows = self
+            .box_shadow
+            .iter()
+            .flat_map(|value| &value.shadows)
+            .any(|shadow| contains_current_color(&shadow.color));
+        own || shadows
+            || self
+                .pseudo_styles()
+                .into_iter()
+                .flatten()
+                .any(|sub| sub.uses_current_color_token())
+    }
+
+    /// Copy of this style with `currentColor` replaced by `color` — the
+    /// element's inherited text color — in every color field. Border sides
+    /// with a width but no color get `color` too, as CSS borders default to
+    /// `currentColor`. Pseudo-styles don't, since their sides refine the
+    /// base style's.
+    pub fn with_current_color(&self, color: &str) -> StyleDesc {
+        let mut style = self.with_current_color_token(color);
+        if let Some(borders) = resolve_borders(&style) {
+            let mut side_colors = [
+                &mut style.border_top_color,
+                &mut style.border_right_color,
+                &mut style.border_bottom_color,
+                &mut style.border_left_color,
+            ];
+            for side in borders.colorless_sides() {
+                *side_colors[side] = Some(color.to_string());
+            }
+        }
+        style
+    }
+
+    fn with_current_color_token(&self, color: &str) -> StyleDesc {
+        let mut style = self.clone();
+        for field in style.color_fields_mut() {
+            if let Some(value) = field.as_mut() {
+                if contains_current_color(value) {
+                    *value = replace_current_color(value, color);
+                }
+            }
+        }
+        for shadow in style
+            .box_shadow
+            .iter_mut()
+            .flat_map(|v| v.shadows.iter_mut())
+        {
+            if contains_current_color(&shadow.color) {
+                shadow.color = replace_current_color(&shadow.color, color);
+            }
+        }
+        for sub in style.pseudo_styles_mut().into_iter().flatten() {
+            **sub = sub.with_current_color_token(color);
+        }
+        style
+    }
+}
+
+fn contains_current_color(value: &str) -> bool {
+    value.to_ascii_lowercase().contains("currentcolor")
+}
+
+/// Replace every case-insensitive `currentColor` token in `value`.
+fn replace_current_color(value: &str, color: &str) -> String {
+    split_css_tokens(value)
+        .into_iter()
+        .map(|token| {
+            if token.eq_ignore_ascii_case("currentcolor") {
+                color
+            } else {
+                token
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Resolved border properties. Sides are ordered top, right, bottom, left.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Borders {
+    pub widths: [Option<f64>; 4],
+    /// 0xRRGGBBAA per side.
+    pub colors: [Option<u32>; 4],
+    pub dashed: bool,
+}
+
+impl Borders {
+    fn visible_colors(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..4)
+            .filter(|&i| self.widths[i].is_none_or(|w| w > 0.0))
+            .filter_map(|i| self.colors[i])
+    }
+
+    /// The single color GPUI paints all sides with: the color shared by the
+    /// visible sides. None when they differ (see per_side_colors) or no
+    /// color is set.
+    pub fn color(&self) -> Option<u32> {
+        let mut colors = self.visible_colors();
+        let first = colors
+            .next()
+            .or_else(|| self.colors.iter().flatten().next().copied())?;
+        colors.all(|color| color == first).then_some(first)
+    }
+
+    /// Each side's color when the visible sides have different colors,
+    /// which GPUI can't paint natively (see borders::overlay).
+    pub fn per_side_colors(&self) -> Option<[Option<u32>; 4]> {
+        let mut colors = self.visible_colors();
+        let first = colors.next()?;
+        colors.any(|color| color != first).then_some(self.colors)
+    }
+
+    /// Sides with a width but no color, which CSS paints in `currentColor`.
+    fn colorless_sides(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..4).filter(|&i| self.colors[i].is_none() && self.widths[i].is_some_and(|w| w > 0.0))
+    }
+
+    /// These borders with a pseudo-style's borders laid over them.
+    pub fn refined(&self, over: &Borders) -> Borders {
+        Borders {
+            widths: std::array::from_fn(|i| over.widths[i].or(self.widths[i])),
+            colors: std::array::from_fn(|i| over.colors[i].or(self.colors[i])),
+            dashed: self.dashed || over.dashed,
+        }
+    }
+}
+
+/// Resolve the border shorthands and longhands of a style. Precedence, from
+/// lowest: `border`, `borderWidth`/`borderColor`/`borderStyle`, per-side
+/// shorthands (`borderTop`), per-side longhands (`borderTopWidth`).
+/// Returns None when no border property is set.
+pub fn resolve_borders(style: &StyleDesc) -> Option<Borders> {
+    let px = |width: Option<DimensionValue>| width.and_then(|w| w.pixels("borderWidth"));
+    let sides = [
+        (&style.border_top, px(style.border_top_width), &style.border_top_color),
+        (&style.border_right, px(style.border_right_width), &style.border_right_color),
+        (&style.border_bottom, px(style.border_bottom_width), &style.border_bottom_color),
+        (&style.border_left, px(style.border_left_width), &style.border_left_color),
+    ];
+    let any_side = sides
+        .iter()
+        .any(|(shorthand, width, color)| shorthand.is_some() || width.is_some() || color.is_some());
+    if !any_side
+        && style.border.is_none()
+        && style.border_width.is_none()
+        && style.border_color.is_none()
+        && style.border_style.is_none()
+    {
+        return None;
+    }
+
+    let mut borders = Borders::default();
+    let mut hidden = [false; 4];
+
+    if let Some(ref shorthand) = style.border {
+        let side = parse_border_shorthand(shorthand);
+        for i in 0..4 {
+            side.apply(&mut borders, &mut hidden, i);
+        }
+    }
+    if style.border_width.is_some() {
+        borders.widths = [px(style.border_width); 4];
+    }
+    if let Some(color) = style.border_color.as_deref().and_then(parse_color_hex) {
+        borders.colors = [Some(color); 4];
+    }
+    if let Some(ref border_style) = style.border_style {
+        let (dashed, none) = parse_border_style(border_style).unwrap_or_default();
+        borders.dashed = dashed;
+        hidden = [none; 4];
+    }
+    for (i, (shorthand, width, color)) in sides.into_iter().enumerate() {
+        if let Some(shorthand) = shorthand {
+            parse_border_shorthand(shorthand).apply(&mut borders, &mut hidden, i);
+        }
+        if width.is_some() {
+            borders.widths[i] = width;
+        }
+        if let Some(color) = color.as_deref().and_then(parse_color_hex) {
+            borders.colors[i] = Some(color);
+        }
+    }
+
+    for (width, hidden) in borders.widths.iter_mut().zip(hidden) {
+        if hidden {
+            *width = Some(0.0);
+        }
+    }
+    Some(borders)
+}
+
+/// One parsed `"1px solid #333"` shorthand; omitted parts leave the
+/// current value alone.
+#[derive(Default)]
+struct BorderShorthand {
+    width: Option<f64>,
+    color: Option<u32>,
+    /// (dashed, none) from the style keyword.
+    style: Option<(bool, bool)>,
+}
+
+impl BorderShorthand {
+    fn apply(&self, borders: &mut Borders, hidden: &mut [bool; 4], side: usize) {
+        if self.width.is_some() {
+            borders.widths[side] = self.width;
+        }
+        if self.color.is_some() {
+            borders.colors[side] = self.color;
+        }
+        if let Some((dashed, none)) = self.style {
+            borders.dashed |= dashed;
+            hidden[side] = none;
+        }
+    }
+}
+
+fn parse_border_shorthand(value: &str) -> BorderShorthand {
+    let mut shorthand = BorderShorthand::default();
+    for token in split_css_tokens(value) {
+        if let Some(style) = parse_border_style(token) {
+            shorthand.style = Some(style);
+        } else if let Some(width) = parse_px(token) {
+            shorthand.width = Some(width);
+        } else if let Some(color) = parse_color_hex(token) {
+            shorthand.color = Some(color);
+        }
+    }
+    shorthand
+}
+
+/// Border style keyword → (dashed, none).
+fn parse_border_style(value: &str) -> Option<(bool, bool)> {
+    match value {
+        "solid" | "double" | "groove" | "ridge" | "inset" | "outset" => Some((false, false)),
+        "dashed" | "dotted" => Some((true, false)),
+        "none" | "hidden" => Some((false, true)),
+        _ => None,
+    }
+}
+
+/// Resolved `textDecoration*` properties.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextDecoration {
+    pub underline: bool,
+    pub line_through: bool,
+    pub wavy: bool,
+    /// 0xRRGGBBAA; None = use the text color.
+    pub color: Option<u32>,
+}
+
+/// Resolve the text decoration of a style. Longhand properties
+/// (`textDecorationColor`, `textDecorationStyle`) override the shorthand.
+/// Returns None when no decoration property is set; `textDecoration: "none"`
+/// yields a decoration with both lines off so it can clear an inherited one.
+pub fn parse_text_decoration(style: &StyleDesc) -> Option<TextDecoration> {
+    if style.text_decoration.is_none()
+        && style.text_decoration_color.is_none()
+        && style.text_decoration_style.is_none()
+    {
+        return None;
+    }
+
+    let mut decoration = TextDecoration::default();
+    if let Some(ref shorthand) = style.text_decoration {
+        for token in split_css_tokens(shorthand) {
+            match token {
+                "underline" => decoration.underline = true,
+                "line-through" => decoration.line_through = true,
+                "wavy" => decoration.wavy = true,
+                "none" | "solid" => {}
+                "overline" | "dotted" | "dashed" | "double" | "blink" => {
+                    warn_unsupported_decoration(token)
+                }
+                color => decoration.color = parse_color_hex(color).or(decoration.color),
+            }
+        }
+    }
+    if let Some(ref color) = style.text_decoration_color {
+        decoration.color = parse_color_hex(color);
+    }
+    match style.text_decoration_style.as_deref() {
+        Some("wavy") => decoration.wavy = true,
+        Some("solid") => decoration.wavy = false,
+        Some(other) => warn_unsupported_decoration(other),
+        None => {}
+    }
+    Some(decoration)
+}
+
+fn warn_unsupported_decoration(value: &str) {
+    warn_once(format!(
+        "unsupported text decoration {:?}: GPUI only draws solid or wavy underlines and line-through, skipping it",
+        value
+    ));
+}
+
+/// Split a CSS value on whitespace, keeping parenthesized groups like
+/// `rgb(1, 2, 3)` together.
+pub fn split_css_tokens(value: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut depth = 0usize;
+    let mut start = None;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    tokens.push(&value[s..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(s) = start {
+        tokens.push(&value[s..]);
+    }
+    tokens
+}
+
+/// Apply CSS `text-transform` to a string.
+pub fn transform_text(text: &str, transform: Option<&str>) -> String {
+    match transform {
+        Some("uppercase") => text.to_uppercase(),
+        Some("lowercase") => text.to_lowercase(),
+        Some("capitalize") => {
+            let mut result = String::with_capacity(text.len());
+            let mut at_word_start = true;
+            for c in text.chars() {
+                if at_word_start && c.is_alphanumeric() {
+                    result.extend(c.to_uppercase());
+                } else {
+                    result.push(c);
+                }
+                at_word_start = c.is_whitespace();
+            }
+            result
+        }
+        _ => text.to_string(),
+    }
+}
+
+/// A resolved `background` value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackgroundValue {
+    /// 0xRRGGBBAA.
+    Solid(u32),
+    /// GPUI gradients have exactly two stops: (0xRRGGBBAA, position 0.0-1.0).
+    /// The angle is in degrees, CSS convention (0 = to top, 90 = to right).
+    LinearGradient {
+        angle: f32,
+        from: (u32, f32),
+        to: (u32, f32),
+    },
+}
+
+/// Parse `background`: a color or `linear-gradient(angle, color stop%, color stop%)`.
+/// Unsupported gradients (other stop counts, repeating or radial gradients)
+/// are rejected with a one-time warning and paint no background.
+pub fn parse_background(value: &str) -> Option<BackgroundValue> {
+    let lower = value.trim().to_ascii_lowercase();
+    if let Some(inner) = function_args(&lower, "linear-gradient") {
+        return match parse_linear_gradient(inner) {
+            Ok(gradient) => Some(gradient),
+            Err(e) => {
+                warn_once(format!("{} in background {:?}", e, value));
+                None
+            }
+        };
+    }
+    if lower.contains("gradient(") {
+        warn_once(format!("unsupported gradient in background {:?}", value));
+        return None;
+    }
+    parse_color_hex(value).map(BackgroundValue::Solid)
 }
 
-/// Parse a color string (hex, rgb, etc.) to GPUI Hsla
+fn parse_linear_gradient(inner: &str) -> Result<BackgroundValue, String> {
+    let mut parts: Vec<&str> = split_top_level(inner, ',')
+        .into_iter()
+        .map(str::trim)
+        .collect();
+
+    // CSS default direction is "to bottom".
+    let mut angle = 180.0;
+    if let Some(first) = parts.first() {
+        if let Some(direction) = parse_gradient_angle(first) {
+            angle = direction;
+            parts.remove(0);
+        }
+    }
+
+    let [from, to] = parts[..] else {
+        return Err(format!(
+            "linear-gradient with {} color stops is not supported (GPUI gradients have exactly 2)",
+            parts.len()
+        ));
+    };
+    Ok(BackgroundValue::LinearGradient {
+        angle,
+        from: parse_color_stop(from, 0.0)?,
+        to: parse_color_stop(to, 1.0)?,
+    })
+}
+
+/// `"90deg"`, `"0.25turn"`, `"to right"`, `"to bottom left"` → degrees.
+fn parse_gradient_angle(value: &str) -> Option<f32> {
+    if let Some(side) = value.strip_prefix("to ") {
+        let mut words: Vec<&str> = side.split_whitespace().collect();
+        words.sort_unstable();
+        return match words[..] {
+            ["top"] => Some(0.0),
+            ["right", "top"] => Some(45.0),
+            ["right"] => Some(90.0),
+            ["bottom", "right"] => Some(135.0),
+            ["bottom"] => Some(180.0),
+            ["bottom", "left"] => Some(225.0),
+            ["left"] => Some(270.0),
+            ["left", "top"] => Some(315.0),
+            _ => None,
+        };
+    }
+    let has_unit = ["deg", "turn", "rad", "grad"]
+        .iter()
+        .any(|unit| value.ends_with(unit));
+    if has_unit {
+        parse_hue(value)
+    } else {
+        None
+    }
+}
+
+/// `"#89b4fa"`, `"#89b4fa 40%"` → (color, position). Position defaults to
+/// `default_position` (0.0 for the first stop, 1.0 for the last).
+fn parse_color_stop(value: &str, default_position: f32) -> Result<(u32, f32), String> {
+    let invalid = || format!("invalid gradient color stop {:?}", value);
+    let (color, position) = match split_css_tokens(value)[..] {
+        [color] => (color, default_position),
+        [color, position] => {
+            let percent = position.strip_suffix('%').ok_or_else(invalid)?;
+            let percent: f32 = percent.parse().map_err(|_| invalid())?;
+            (color, (percent / 100.0).clamp(0.0, 1.0))
+        }
+        _ => return Err(invalid()),
+    };
+    let (r, g, b, a) = parse_color(color).ok_or_else(invalid)?;
+    Ok((rgba_to_hex(r, g, b, a), position))
+}
+
+/// Parse a CSS color string to RGBA floats (0.0-1.0).
+///
+/// Supports hex (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`), `rgb()`/`rgba()`
+/// and `hsl()`/`hsla()` in both comma and space-separated syntax (with
+/// `/ alpha`), percentage channels, the CSS named colors and `transparent`.
+/// `currentColor` must be resolved before parsing (see
+/// StyleDesc::with_current_color); it returns None here.
 pub fn parse_color(color: &str) -> Option<(f32, f32, f32, f32)> {
     let color = color.trim();
 
     // Handle hex colors
-    if color.starts_with('#') {
-        let hex = &color[1..];
-        match hex.len() {
-            3 => {
-                // #RGB -> #RRGGBB
-                let r = u8::from_str_radix(&hex[0..1].repeat(2), 16).ok()? as f32 / 255.0;
-                let g = u8::from_str_radix(&hex[1..2].repeat(2), 16).ok()? as f32 / 255.0;
-                let b = u8::from_str_radix(&hex[2..3].repeat(2), 16).ok()? as f32 / 255.0;
-                return Some((r, g, b, 1.0));
-            }
-            6 => {
-                let r = u8::from_str_radix(&hex[0..2], 16).ok()? as f32 / 255.0;
-                let g = u8::from_str_radix(&hex[2..4], 16).ok()? as f32 / 255.0;
-                let b = u8::from_str_radix(&hex[4..6], 16).ok()? as f32 / 255.0;
-                return Some((r, g, b, 1.0));
-            }
-            8 => {
-                let r = u8::from_str_radix(&hex[0..2], 16).ok()? as f32 / 255.0;
-                let g = u8::from_str_radix(&hex[2..4], 16).ok()? as f32 / 255.0;
-                let b = u8::from_str_radix(&hex[4..6], 16).ok()? as f32 / 255.0;
-                let a = u8::from_str_radix(&hex[6..8], 16).ok()? as f32 / 255.0;
-                return Some((r, g, b, a));
-            }
-            _ => return None,
-        }
-    }
-
-    // Handle rgb/rgba
-    if color.starts_with("rgb") {
-        let inner = color
-            .trim_start_matches("rgba(")
-            .trim_start_matches("rgb(")
-            .trim_end_matches(')');
-        let parts: Vec<&str> = inner.split(',').map(|s| s.trim()).collect();
-
-        if parts.len() >= 3 {
-            let r = parts[0].parse::<f32>().ok()? / 255.0;
-            let g = parts[1].parse::<f32>().ok()? / 255.0;
-            let b = parts[2].parse::<f32>().ok()? / 255.0;
-            let a = if parts.len() == 4 {
-                parts[3].parse::<f32>().ok()?
-            } else {
-                1.0
-            };
-            return Some((r, g, b, a));
-        }
+    if let Some(hex) = color.strip_prefix('#') {
+        let digit = |i: usize| u8::from_str_radix(hex.get(i..i + 1)?, 16).ok();
+        let byte = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        let channel = |v: u8| v as f32 / 255.0;
+        return match hex.len() {
+            // #RGB / #RGBA -> each digit doubled
+            3 | 4 => {
+                let r = digit(0)? * 17;
+                let g = digit(1)? * 17;
+                let b = digit(2)? * 17;
+                let a = if hex.len() == 4 { digit(3)? * 17 } else { 255 };
+                Some((channel(r), channel(g), channel(b), channel(a)))
+            }
+            6 | 8 => {
+                let a = if hex.len() == 8 { byte(6)? } else { 255 };
+                Some((channel(byte(0)?), channel(byte(2)?), channel(byte(4)?), channel(a)))
+            }
+            _ => None,
+        };
+    }
+
+    let lower = color.to_ascii_lowercase();
+
+    if let Some(inner) = function_args(&lower, "rgba").or_else(|| function_args(&lower, "rgb")) {
+        let ([r, g, b], alpha) = split_color_args(inner)?;
+        return Some((
+            parse_rgb_channel(r)?,
+            parse_rgb_channel(g)?,
+            parse_rgb_channel(b)?,
+            parse_alpha(alpha)?,
+        ));
+    }
+
+    if let Some(inner) = function_args(&lower, "hsla").or_else(|| function_args(&lower, "hsl")) {
+        let ([h, s, l], alpha) = split_color_args(inner)?;
+        let (r, g, b) = hsl_to_rgb(parse_hue(h)?, parse_percentage(s)?, parse_percentage(l)?);
+        return Some((r, g, b, parse_alpha(alpha)?));
+    }
+
+    if lower == "transparent" {
+        return Some((0.0, 0.0, 0.0, 0.0));
+    }
+
+    let index = NAMED_COLORS
+        .binary_search_by(|(name, _)| name.cmp(&lower.as_str()))
+        .ok()?;
+    let rgb = NAMED_COLORS[index].1;
+    Some((
+        ((rgb >> 16) & 0xff) as f32 / 255.0,
+        ((rgb >> 8) & 0xff) as f32 / 255.0,
+        (rgb & 0xff) as f32 / 255.0,
+        1.0,
+    ))
+}
+
+/// `"rgb(1 2 3)"`, `"rgb"` → `Some("1 2 3")`.
+pub(crate) fn function_args<'a>(value: &'a str, name: &str) -> Option<&'a str> {
+    value
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+/// Split color function arguments into three channels and an optional alpha.
+/// Accepts `a, b, c[, alpha]` and `a b c[ / alpha]`.
+fn split_color_args(inner: &str) -> Option<([&str; 3], Option<&str>)> {
+    let (channels, slash_alpha) = match inner.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (inner, None),
+    };
+    let parts: Vec<&str> = channels
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect();
+    match (parts.as_slice(), slash_alpha) {
+        ([a, b, c], alpha) => Some(([*a, *b, *c], alpha)),
+        ([a, b, c, alpha], None) => Some(([*a, *b, *c], Some(*alpha))),
+        _ => None,
+    }
+}
+
+/// `255` or `100%` → 0.0-1.0. `none` is 0 (CSS Color 4).
+fn parse_rgb_channel(value: &str) -> Option<f32> {
+    if value == "none" {
+        return Some(0.0);
     }
+    let v = match value.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+        None => value.parse::<f32>().ok()? / 255.0,
+    };
+    Some(v.clamp(0.0, 1.0))
+}
 
-    None
+/// `0.5` or `50%` → 0.0-1.0; missing alpha is opaque.
+fn parse_alpha(value: Option<&str>) -> Option<f32> {
+    let Some(value) = value else {
+        return Some(1.0);
+    };
+    let v = match value.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+        None if value == "none" => 0.0,
+        None => value.parse::<f32>().ok()?,
+    };
+    Some(v.clamp(0.0, 1.0))
 }
 
+/// Saturation/lightness: `50%` (or a bare `50`, as in CSS Color 4) → 0.5.
+fn parse_percentage(value: &str) -> Option<f32> {
+    let v = value.strip_suffix('%').unwrap_or(value).parse::<f32>().ok()?;
+    Some((v / 100.0).clamp(0.0, 1.0))
+}
+
+/// Hue in degrees (default), `deg`, `turn`, `rad` or `grad` → 0.0-360.0.
+fn parse_hue(value: &str) -> Option<f32> {
+    let degrees = if let Some(v) = value.strip_suffix("deg") {
+        v.parse::<f32>().ok()?
+    } else if let Some(v) = value.strip_suffix("turn") {
+        v.parse::<f32>().ok()? * 360.0
+    } else if let Some(v) = value.strip_suffix("grad") {
+        v.parse::<f32>().ok()? * 0.9
+    } else if let Some(v) = value.strip_suffix("rad") {
+        v.parse::<f32>().ok()?.to_degrees()
+    } else if value == "none" {
+        0.0
+    } else {
+        value.parse::<f32>().ok()?
+    };
+    Some(degrees.rem_euclid(360.0))
+}
+
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    let k = |n: f32| (n + h / 30.0) % 12.0;
+    let a = s * l.min(1.0 - l);
+    let f = |n: f32| l - a * (k(n) - 3.0).min(9.0 - k(n)).clamp(-1.0, 1.0);
+    (f(0.0), f(8.0), f(4.0))
+}
+
+/// CSS named colors (0xRRGGBB), sorted for binary search.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
 /// Convert RGBA floats (0.0-1.0) to a hex u32 for GPUI's rgba() function
 /// Format: 0xRRGGBBAA
 pub fn rgba_to_hex(r: f32, g: f32, b: f32, a: f32) -> u32 {
@@ -244,8 +1437,35 @@ pub fn rgba_to_hex(r: f32, g: f32, b: f32, a: f32) -> u32 {
     (r << 24) | (g << 16) | (b << 8) | a
 }
 
-/// Parse a color string and return a hex u32 for GPUI
+/// Parse a color string and return a hex u32 for GPUI.
+/// Unsupported values are logged once each instead of vanishing silently.
 pub fn parse_color_hex(color: &str) -> Option<u32> {
-    let (r, g, b, a) = parse_color(color)?;
-    Some(rgba_to_hex(r, g, b, a))
+    match parse_color(color) {
+        Some((r, g, b, a)) => Some(rgba_to_hex(r, g, b, a)),
+        None => {
+            warn_unsupported_color(color);
+            None
+        }
+    }
+}
+
+fn warn_unsupported_color(color: &str) {
+    warn_once(format!("unsupported color value: {:?}", color));
+}
+
+/// Log a warning the first time `message` is seen. Style values are parsed
+/// every frame, so unsupported ones would otherwise flood the log.
+pub fn warn_once(message: String) {
+    use std::collections::HashSet;
+    use std::sync::{Mutex, OnceLock};
+
+    static WARNED: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    let mut warned = WARNED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if !warned.contains(&message) {
+        log::warn!("[gpuix] {}", message);
+        warned.insert(message);
+    }
 }
@@ -0,0 +1,96 @@
+/// Registered style classes — named style objects that elements reference
+/// through `className` instead of each carrying a full StyleDesc.
+///
+/// JS registers a class once via registerStyleClass(name, json) and then
+/// sends only the class names per element. The effective style is computed
+/// in Rust: each class in className order, then the element's inline style,
+/// merged key by key so later sources win. Pseudo-style blocks (`hover`,
+/// `active`, `focus`, ...) are merged one level deep so a class's `hover.backgroundColor`
+/// survives an inline `hover.opacity`.
+///
+/// Merging happens on raw JSON, before theme resolution, so classes may use
+/// `var()` tokens like any inline style.
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::style::StyleDesc;
+
+#[derive(Debug, Clone, Default)]
+pub struct StyleClasses {
+    classes: HashMap<String, Map<String, Value>>,
+}
+
+impl StyleClasses {
+    /// Register (or replace) a class from a style JSON object. Plain styles
+    /// are validated against StyleDesc here; themed ones when resolved.
+    pub fn register(&mut self, name: &str, json: &str) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(format!("Invalid style class name {:?}", name));
+        }
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse style class {:?}: {}", name, e))?;
+        if !value.is_object() {
+            return Err(format!("Style class {:?} must be a JSON object", name));
+        }
+        if !crate::theme::contains_vars(&value) {
+            StyleDesc::deserialize(&value)
+                .map_err(|e| format!("Invalid style class {:?}: {}", name, e))?;
+        }
+        let Value::Object(map) = value else {
+            unreachable!()
+        };
+        self.classes.insert(name.to_string(), map);
+        Ok(())
+    }
+
+    /// Merge the named classes in order, then `inline`, into one style object.
+    /// Unknown class names are skipped — they may be registered later.
+    pub fn merge(&self, class_names: &[String], inline: Option<&Value>) -> Value {
+        let mut merged = Map::new();
+        for name in class_names {
+            if let Some(class) = self.classes.get(name) {
+                merge_into(&mut merged, class);
+            }
+        }
+        if let Some(Value::Object(inline)) = inline {
+            merge_into(&mut merged, inline);
+        }
+        Value::Object(merged)
+    }
+}
+
+/// Split a `className` string into class names (whitespace separated).
+pub fn parse_class_names(class_name: &str) -> Vec<String> {
+    class_name.split_whitespace().map(str::to_string).collect()
+}
+
+/// Nested style blocks that merge key by key instead of being replaced.
+const PSEUDO_STYLE_KEYS: &[&str] = &[
+    "hover",
+    "active",
+    "focus",
+    "focusVisible",
+    "disabled",
+    "groupHover",
+    "dragOver",
+];
+
+fn merge_into(target: &mut Map<String, Value>, source: &Map<String, Value>) {
+    for (key, value) in source {
+        match (target.get_mut(key), value) {
+            (Some(Value::Object(existing)), Value::Object(nested))
+                if PSEUDO_STYLE_KEYS.contains(&key.as_str()) =>
+            {
+                for (k, v) in nested {
+                    existing.insert(k.clone(), v.clone());
+                }
+            }
+            _ => {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+/// Opt-in synchronous event dispatch.
+///
+/// Events normally reach JS through the renderer's ThreadsafeFunction,
+/// queued on the Node.js event loop, so by the time a handler runs GPUI has
+/// finished propagating the event. tick() runs on the JS thread, though, so
+/// once a handler is set with setSyncEventHandler(), listeners call it
+/// directly while GPUI dispatches the input tick() pumped. Its return value,
+/// `{ stopPropagation, preventDefault }`, is applied with respond().
+///
+/// Events emitted while GpuixView::render() holds the retained tree
+/// (transitionEnd, dragLeave after a drag ends elsewhere) still go through
+/// the queue: a handler calling back into the renderer there would
+/// deadlock on the tree.
+use std::cell::{Cell, RefCell};
+
+use napi::bindgen_prelude::*;
+use napi::Env;
+
+use crate::element_tree::{EventPayload, EventResponse};
+
+type SyncHandler = FunctionRef<EventPayload, Option<EventResponse>>;
+
+thread_local! {
+    static HANDLER: RefCell<Option<SyncHandler>> = const { RefCell::new(None) };
+    /// Set for the duration of tick() (or a simulated input in tests).
+    static DISPATCH_ENV: Cell<Option<Env>> = const { Cell::new(None) };
+    static RENDERING: Cell<bool> = const { Cell::new(false) };
+    static IN_HANDLER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Set or clear (None) the synchronous handler.
+pub fn set_handler(handler: Option<Function<EventPayload, Option<EventResponse>>>) -> Result<()> {
+    if IN_HANDLER.get() {
+        return Err(Error::from_reason(
+            "Can't change the synchronous event handler while it runs",
+        ));
+    }
+    let handler = handler.map(|handler| handler.create_ref()).transpose()?;
+    HANDLER.with(|current| *current.borrow_mut() = handler);
+    Ok(())
+}
+
+/// Dispatch input with `env` available to call the handler. Errors if a
+/// synchronous handler tries to pump input again.
+pub fn dispatching<R>(env: Env, dispatch: impl FnOnce() -> Result<R>) -> Result<R> {
+    if IN_HANDLER.get() {
+        return Err(Error::from_reason(
+            "Can't dispatch input from a synchronous event handler",
+        ));
+    }
+    let previous = DISPATCH_ENV.replace(Some(env));
+    let result = dispatch();
+    DISPATCH_ENV.set(previous);
+    result
+}
+
+/// Held by GpuixView::render(), during which events are queued.
+pub struct Rendering {
+    previous: bool,
+}
+
+impl Rendering {
+    pub fn begin() -> Self {
+        Self {
+            previous: RENDERING.replace(true),
+        }
+    }
+}
+
+impl Drop for Rendering {
+    fn drop(&mut self) {
+        RENDERING.set(self.previous);
+    }
+}
+
+/// Call the synchronous handler with `payload` if one is set and input is
+/// being dispatched. None means the event should be queued instead.
+pub fn call(payload: &EventPayload) -> Option<EventResponse> {
+    let env = DISPATCH_ENV.get().filter(|_| !RENDERING.get())?;
+    HANDLER.with(|handler| {
+        let handler = handler.borrow();
+        let handler = handler.as_ref()?;
+        IN_HANDLER.set(true);
+        let result = handler
+            .borrow_back(&env)
+            .and_then(|handler| handler.call(payload.clone()));
+        IN_HANDLER.set(false);
+        match result {
+            Ok(response) => Some(response.unwrap_or_default()),
+            Err(e) => {
+                log::error!(
+                    "[gpuix] synchronous {} handler failed: {}",
+                    payload.event_type,
+                    e
+                );
+                Some(EventResponse::default())
+            }
+        }
+    })
+}
+
+/// Apply a synchronous handler's response to the event being dispatched.
+pub fn respond(response: EventResponse, window: &mut gpui::Window, cx: &mut gpui::App) {
+    if response.stop_propagation == Some(true) {
+        cx.stop_propagation();
+    }
+    if response.prevent_default == Some(true) {
+        window.prevent_default();
+    }
+}
@@ -17,15 +17,15 @@ use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 
 use napi::bindgen_prelude::*;
+use napi::Env;
 use napi_derive::napi;
 
 use gpui::AppContext as _;
 
 use crate::custom_elements::CustomElementRegistry;
-use crate::element_tree::EventPayload;
+use crate::element_tree::{EventPayload, EventResponse};
 use crate::renderer::{apply_batch_to_tree, to_element_id, EventCallback, GpuixView};
 use crate::retained_tree::RetainedTree;
-use crate::style::StyleDesc;
 
 // ── Thread-local storage for !Send GPUI types ────────────────────────
 
@@ -115,14 +115,17 @@ impl TestGpuixRenderer {
         // rendered by Metal. Uses the same GpuixView as production.
         let window_handle = cx
             .open_offscreen_window_default(|_window, app| {
-                app.new(|_cx| GpuixView {
+                crate::keymap::deliver_unhandled(tree_clone.clone(), callback_clone.clone(), app);
+                app.new(|cx| GpuixView {
                     tree: tree_clone,
                     event_callback: callback_clone,
                     window_title: "GPUIX Test".to_string(),
                     focus_handles: HashMap::new(),
-                    _focus_subscriptions: Vec::new(),
+                    _focus_subscriptions: vec![GpuixView::observe_input_modality(cx)],
                     custom_registry: CustomElementRegistry::with_defaults(),
                     scroll_handles: HashMap::new(),
+                    text_states: HashMap::new(),
+                    transitions: Default::default(),
                 })
             })
             .map_err(|e| Error::from_reason(format!("Failed to open test window: {}", e)))?;
@@ -139,6 +142,8 @@ impl TestGpuixRenderer {
         TEST_STATE.with(|cell| {
             *cell.borrow_mut() = Some(VisualTestState { cx, window, view });
         });
+        // The handler is per thread too; don't inherit the last test's.
+        crate::sync_dispatch::set_handler(None)?;
 
         Ok(Self { tree, events })
     }
@@ -192,12 +197,83 @@ impl TestGpuixRenderer {
     #[napi]
     pub fn set_style(&self, id: f64, style_json: String) -> Result<()> {
         let id = to_element_id(id)?;
-        let style: StyleDesc = serde_json::from_str(&style_json)
+        let style = crate::theme::parse_style_input(&style_json)
             .map_err(|e| Error::from_reason(format!("Failed to parse style: {}", e)))?;
-        self.tree.lock().unwrap().set_style(id, style);
+        self.tree.lock().unwrap().set_style_input(id, style);
         Ok(())
     }
 
+    #[napi]
+    pub fn set_class_name(&self, id: f64, class_name: String) -> Result<()> {
+        let id = to_element_id(id)?;
+        self.tree.lock().unwrap().set_class_name(id, &class_name);
+        Ok(())
+    }
+
+    /// Register a named style class. Mirrors GpuixRenderer::register_style_class.
+    #[napi]
+    pub fn register_style_class(&self, name: String, style_json: String) -> Result<()> {
+        self.tree
+            .lock()
+            .unwrap()
+            .register_style_class(&name, &style_json)
+            .map_err(Error::from_reason)
+    }
+
+    /// Register named keyframes. Mirrors GpuixRenderer::register_animation.
+    #[napi]
+    pub fn register_animation(&self, name: String, keyframes_json: String) -> Result<()> {
+        self.tree
+            .lock()
+            .unwrap()
+            .register_animation(&name, &keyframes_json)
+            .map_err(Error::from_reason)
+    }
+
+    /// Set the theme (token name → value JSON object). Mirrors GpuixRenderer::set_theme.
+    #[napi]
+    pub fn set_theme(&self, theme_json: String) -> Result<()> {
+        let theme = crate::theme::Theme::from_json(&theme_json).map_err(Error::from_reason)?;
+        self.tree.lock().unwrap().set_theme(theme);
+        Ok(())
+    }
+
+    /// Add key bindings. Mirrors GpuixRenderer::register_key_bindings.
+    #[napi]
+    pub fn register_key_bindings(&self, bindings_json: String) -> Result<()> {
+        self.tree
+            .lock()
+            .unwrap()
+            .register_key_bindings(&bindings_json)
+            .map_err(Error::from_reason)
+    }
+
+    /// Remove every key binding. Mirrors GpuixRenderer::clear_key_bindings.
+    #[napi]
+    pub fn clear_key_bindings(&self) -> Result<()> {
+        self.tree.lock().unwrap().clear_key_bindings();
+        Ok(())
+    }
+
+    /// Choose the names used for `key`. Mirrors GpuixRenderer::set_key_names.
+    #[napi]
+    pub fn set_key_names(&self, names: String) -> Result<()> {
+        let names = crate::keyboard::KeyNames::parse(&names).map_err(Error::from_reason)?;
+        self.tree.lock().unwrap().key_names = names;
+        Ok(())
+    }
+
+    /// Handle events synchronously during simulate_* calls. Mirrors
+    /// GpuixRenderer::set_sync_event_handler; handled events are not
+    /// returned by drainEvents().
+    #[napi]
+    pub fn set_sync_event_handler(
+        &self,
+        handler: Option<Function<EventPayload, Option<EventResponse>>>,
+    ) -> Result<()> {
+        crate::sync_dispatch::set_handler(handler)
+    }
+
     #[napi]
     pub fn set_text(&self, id: f64, content: String) -> Result<()> {
         let id = to_element_id(id)?;
@@ -288,14 +364,16 @@ impl TestGpuixRenderer {
     /// which triggers the same event handlers as production.
     /// IMPORTANT: Call flush() before this — hit testing requires laid-out elements.
     #[napi]
-    pub fn simulate_click(&self, x: f64, y: f64) -> Result<()> {
-        with_test_state(|cx, window, _view| {
-            cx.simulate_click(
-                window,
-                gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
-                gpui::Modifiers::default(),
-            );
-            Ok(())
+    pub fn simulate_click(&self, env: Env, x: f64, y: f64) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                cx.simulate_click(
+                    window,
+                    gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
+                    gpui::Modifiers::default(),
+                );
+                Ok(())
+            })
         })
     }
 
@@ -303,10 +381,12 @@ impl TestGpuixRenderer {
     /// Format: space-separated keys, e.g. "a", "enter", "cmd-shift-p".
     /// The focused element receives keyDown/keyUp events.
     #[napi]
-    pub fn simulate_keystrokes(&self, keystrokes: String) -> Result<()> {
-        with_test_state(|cx, window, _view| {
-            cx.simulate_keystrokes(window, &keystrokes);
-            Ok(())
+    pub fn simulate_keystrokes(&self, env: Env, keystrokes: String) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                cx.simulate_keystrokes(window, &keystrokes);
+                Ok(())
+            })
         })
     }
 
@@ -316,22 +396,29 @@ impl TestGpuixRenderer {
     /// no automatic KeyUpEvent follows. Use with simulate_key_up for
     /// fine-grained key event testing.
     #[napi]
-    pub fn simulate_key_down(&self, keystroke: String, is_held: Option<bool>) -> Result<()> {
-        with_test_state(|cx, window, _view| {
-            let parsed = gpui::Keystroke::parse(&keystroke).map_err(|e| {
-                Error::from_reason(format!("Invalid keystroke '{}': {}", keystroke, e))
-            })?;
-
-            cx.simulate_event(
-                window,
-                gpui::KeyDownEvent {
-                    keystroke: parsed,
-                    is_held: is_held.unwrap_or(false),
-                    prefer_character_input: false,
-                },
-            );
-
-            Ok(())
+    pub fn simulate_key_down(
+        &self,
+        env: Env,
+        keystroke: String,
+        is_held: Option<bool>,
+    ) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                let parsed = gpui::Keystroke::parse(&keystroke).map_err(|e| {
+                    Error::from_reason(format!("Invalid keystroke '{}': {}", keystroke, e))
+                })?;
+
+                cx.simulate_event(
+                    window,
+                    gpui::KeyDownEvent {
+                        keystroke: parsed,
+                        is_held: is_held.unwrap_or(false),
+                        prefer_character_input: false,
+                    },
+                );
+
+                Ok(())
+            })
         })
     }
 
@@ -339,15 +426,17 @@ impl TestGpuixRenderer {
     /// Format: modifier-key string, e.g. "a", "enter", "cmd-s".
     /// Pairs with simulate_key_down for fine-grained key event testing.
     #[napi]
-    pub fn simulate_key_up(&self, keystroke: String) -> Result<()> {
-        with_test_state(|cx, window, _view| {
-            let parsed = gpui::Keystroke::parse(&keystroke).map_err(|e| {
-                Error::from_reason(format!("Invalid keystroke '{}': {}", keystroke, e))
-            })?;
+    pub fn simulate_key_up(&self, env: Env, keystroke: String) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                let parsed = gpui::Keystroke::parse(&keystroke).map_err(|e| {
+                    Error::from_reason(format!("Invalid keystroke '{}': {}", keystroke, e))
+                })?;
 
-            cx.simulate_event(window, gpui::KeyUpEvent { keystroke: parsed });
+                cx.simulate_event(window, gpui::KeyUpEvent { keystroke: parsed });
 
-            Ok(())
+                Ok(())
+            })
         })
     }
 
@@ -355,18 +444,26 @@ impl TestGpuixRenderer {
     /// pressed_button: optional mouse button held during move (0=left, 1=middle, 2=right).
     /// Used to simulate drag events.
     #[napi]
-    pub fn simulate_mouse_move(&self, x: f64, y: f64, pressed_button: Option<u32>) -> Result<()> {
-        with_test_state(|cx, window, _view| {
-            let button: Option<gpui::MouseButton> = pressed_button.map(u32_to_mouse_button);
-
-            cx.simulate_mouse_move(
-                window,
-                gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
-                button,
-                gpui::Modifiers::default(),
-            );
-
-            Ok(())
+    pub fn simulate_mouse_move(
+        &self,
+        env: Env,
+        x: f64,
+        y: f64,
+        pressed_button: Option<u32>,
+    ) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                let button: Option<gpui::MouseButton> = pressed_button.map(u32_to_mouse_button);
+
+                cx.simulate_mouse_move(
+                    window,
+                    gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
+                    button,
+                    gpui::Modifiers::default(),
+                );
+
+                Ok(())
+            })
         })
     }
 
@@ -398,51 +495,112 @@ impl TestGpuixRenderer {
     /// Simulate a mouse down event at the given window coordinates.
     /// Button: 0=left, 1=middle, 2=right. Defaults to left (0).
     #[napi]
-    pub fn simulate_mouse_down(&self, x: f64, y: f64, button: Option<u32>) -> Result<()> {
-        with_test_state(|cx, window, _view| {
-            cx.simulate_mouse_down(
-                window,
-                gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
-                u32_to_mouse_button(button.unwrap_or(0)),
-                gpui::Modifiers::default(),
-            );
-            Ok(())
+    pub fn simulate_mouse_down(&self, env: Env, x: f64, y: f64, button: Option<u32>) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                cx.simulate_mouse_down(
+                    window,
+                    gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
+                    u32_to_mouse_button(button.unwrap_or(0)),
+                    gpui::Modifiers::default(),
+                );
+                Ok(())
+            })
         })
     }
 
     /// Simulate a mouse up event at the given window coordinates.
     /// Button: 0=left, 1=middle, 2=right. Defaults to left (0).
     #[napi]
-    pub fn simulate_mouse_up(&self, x: f64, y: f64, button: Option<u32>) -> Result<()> {
-        with_test_state(|cx, window, _view| {
-            cx.simulate_mouse_up(
-                window,
-                gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
-                u32_to_mouse_button(button.unwrap_or(0)),
-                gpui::Modifiers::default(),
-            );
-            Ok(())
+    pub fn simulate_mouse_up(&self, env: Env, x: f64, y: f64, button: Option<u32>) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                cx.simulate_mouse_up(
+                    window,
+                    gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
+                    u32_to_mouse_button(button.unwrap_or(0)),
+                    gpui::Modifiers::default(),
+                );
+                Ok(())
+            })
+        })
+    }
+
+    /// Simulate files dragged from the OS over the window to the given
+    /// coordinates. Repeated calls move the drag; GPUI keeps the paths of
+    /// the first one.
+    #[napi]
+    pub fn simulate_file_drag(&self, env: Env, paths: Vec<String>, x: f64, y: f64) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                cx.simulate_event(
+                    window,
+                    gpui::FileDropEvent::Entered {
+                        position: gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
+                        paths: gpui::ExternalPaths(
+                            paths.into_iter().map(std::path::PathBuf::from).collect(),
+                        ),
+                    },
+                );
+                Ok(())
+            })
+        })
+    }
+
+    /// Simulate dropping the dragged files at the given coordinates.
+    #[napi]
+    pub fn simulate_file_drop(&self, env: Env, x: f64, y: f64) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                cx.simulate_event(
+                    window,
+                    gpui::FileDropEvent::Submit {
+                        position: gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
+                    },
+                );
+                Ok(())
+            })
+        })
+    }
+
+    /// Simulate the dragged files leaving the window without a drop.
+    #[napi]
+    pub fn simulate_file_drag_exit(&self, env: Env) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                cx.simulate_event(window, gpui::FileDropEvent::Exited);
+                Ok(())
+            })
         })
     }
 
     /// Simulate a scroll wheel event at the given position.
     /// delta_x and delta_y are in pixels (negative = scroll up/left).
     #[napi]
-    pub fn simulate_scroll_wheel(&self, x: f64, y: f64, delta_x: f64, delta_y: f64) -> Result<()> {
-        with_test_state(|cx, window, _view| {
-            cx.simulate_event(
-                window,
-                gpui::ScrollWheelEvent {
-                    position: gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
-                    delta: gpui::ScrollDelta::Pixels(gpui::point(
-                        gpui::px(delta_x as f32),
-                        gpui::px(delta_y as f32),
-                    )),
-                    modifiers: gpui::Modifiers::default(),
-                    touch_phase: gpui::TouchPhase::Moved,
-                },
-            );
-            Ok(())
+    pub fn simulate_scroll_wheel(
+        &self,
+        env: Env,
+        x: f64,
+        y: f64,
+        delta_x: f64,
+        delta_y: f64,
+    ) -> Result<()> {
+        crate::sync_dispatch::dispatching(env, || {
+            with_test_state(|cx, window, _view| {
+                cx.simulate_event(
+                    window,
+                    gpui::ScrollWheelEvent {
+                        position: gpui::point(gpui::px(x as f32), gpui::px(y as f32)),
+                        delta: gpui::ScrollDelta::Pixels(gpui::point(
+                            gpui::px(delta_x as f32),
+                            gpui::px(delta_y as f32),
+                        )),
+                        modifiers: gpui::Modifiers::default(),
+                        touch_phase: gpui::TouchPhase::Moved,
+                    },
+                );
+                Ok(())
+            })
         })
     }
 
@@ -550,6 +708,17 @@ impl TestGpuixRenderer {
         })
     }
 
+    /// Read the text currently on the test platform's clipboard.
+    /// Returns null if the clipboard is empty or holds non-text content.
+    #[napi]
+    pub fn read_clipboard_text(&self) -> Result<Option<String>> {
+        with_test_state(|cx, _window, _view| {
+            Ok(cx
+                .update(|app| app.read_from_clipboard())
+                .and_then(|item| item.text()))
+        })
+    }
+
     /// Return and clear all collected events since the last drain.
     /// Events are collected synchronously — no event loop queuing.
     #[napi]
@@ -0,0 +1,213 @@
+/// Theme variables — a map of token names to colors, lengths and font names
+/// that styles reference with CSS `var()` syntax.
+///
+/// JS sets the theme once via setTheme(json). Styles containing `var(--name)`
+/// are re-resolved from RetainedElement::style_source by GpuixView::render()
+/// when the theme changes, so switching theme is one napi call plus a redraw
+/// instead of React re-sending styles.
+///
+/// Resolution happens on the JSON value, before deserializing into StyleDesc,
+/// so tokens work in every field — including numeric ones:
+///   `"var(--gap)"`               → the token's value with its JSON type (8)
+///   `"var(--gap, 4)"`            → fallback when the token is undefined
+///   `"1px solid var(--border)"`  → substituted into the surrounding string
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::style::StyleDesc;
+
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    /// Token name without the leading `--`.
+    vars: HashMap<String, Value>,
+}
+
+impl Theme {
+    /// Parse a theme from a JSON object of `{ "accent": "#89b4fa", "gap": 8 }`.
+    /// Keys may be written with or without the leading `--`.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse theme: {}", e))?;
+        let Value::Object(map) = value else {
+            return Err("Theme must be a JSON object of token names to values".to_string());
+        };
+
+        let mut vars = HashMap::with_capacity(map.len());
+        for (key, value) in map {
+            match value {
+                Value::String(_) | Value::Number(_) => {
+                    vars.insert(key.trim_start_matches("--").to_string(), value);
+                }
+                other => {
+                    return Err(format!(
+                        "Theme token {:?} must be a string or number, got {}",
+                        key, other
+                    ))
+                }
+            }
+        }
+        Ok(Self { vars })
+    }
+
+    fn get(&self, name: &str) -> Option<&Value> {
+        self.vars.get(name.trim_start_matches("--"))
+    }
+}
+
+/// A parsed setStyle payload. The raw JSON is always kept so it can be
+/// merged over style classes; plain styles are also deserialized up front.
+pub struct StyleInput {
+    pub raw: Value,
+    /// `raw` deserialized, when it has no var() references to resolve.
+    pub parsed: Option<StyleDesc>,
+}
+
+/// Parse a setStyle JSON string. Plain styles are validated here; themed
+/// styles can only be fully validated once their tokens are resolved.
+pub fn parse_style_input(json: &str) -> Result<StyleInput, String> {
+    let raw: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    if contains_vars(&raw) {
+        return Ok(StyleInput { raw, parsed: None });
+    }
+    let parsed = StyleDesc::deserialize(&raw).map_err(|e| e.to_string())?;
+    Ok(StyleInput {
+        raw,
+        parsed: Some(parsed),
+    })
+}
+
+/// True if any string inside `value` references a theme variable.
+pub fn contains_vars(value: &Value) -> bool {
+    match value {
+        Value::String(s) => s.contains("var("),
+        Value::Array(items) => items.iter().any(contains_vars),
+        Value::Object(map) => map.values().any(contains_vars),
+        _ => false,
+    }
+}
+
+/// Resolve a raw style JSON value against `theme` and deserialize it.
+/// A property whose resolved value doesn't fit its field is dropped with a
+/// warning, so one bad token doesn't unstyle the whole element. Inside a
+/// pseudo-style (`hover`, `focus`, ...) only the offending property is
+/// dropped too.
+pub fn resolve_style(raw: &Value, theme: &Theme) -> StyleDesc {
+    let mut resolved = resolve_value(raw, theme);
+    if let Ok(style) = StyleDesc::deserialize(&resolved) {
+        return style;
+    }
+    if let Value::Object(map) = &mut resolved {
+        drop_invalid_properties(map, &|style| style);
+    }
+    StyleDesc::deserialize(&resolved).unwrap_or_default()
+}
+
+/// Drop the entries of `map` that fail to deserialize on their own, once
+/// wrapped back into a whole style by `wrap` (identity at the top level,
+/// `{ "hover": ... }` inside a pseudo-style).
+fn drop_invalid_properties(map: &mut Map<String, Value>, wrap: &dyn Fn(Value) -> Value) {
+    map.retain(|key, value| {
+        let Err(e) = StyleDesc::deserialize(&wrap(property(key, value.clone()))) else {
+            return true;
+        };
+        if let Value::Object(inner) = value {
+            let key = key.clone();
+            drop_invalid_properties(inner, &|inner| wrap(property(&key, inner)));
+            return true;
+        }
+        crate::style::warn_once(format!(
+            "themed style property {:?}: {}, skipping it",
+            key, e
+        ));
+        false
+    });
+}
+
+/// `{ key: value }`.
+fn property(key: &str, value: Value) -> Value {
+    Value::Object(Map::from_iter([(key.to_string(), value)]))
+}
+
+/// Substitute `var()` references throughout a JSON value.
+/// Undefined tokens without a fallback resolve to null (property unset).
+fn resolve_value(value: &Value, theme: &Theme) -> Value {
+    match value {
+        Value::String(s) if s.contains("var(") => resolve_string(s, theme),
+        Value::Array(items) => Value::Array(items.iter().map(|v| resolve_value(v, theme)).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), resolve_value(v, theme)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn resolve_string(s: &str, theme: &Theme) -> Value {
+    // Whole value is a single var(): keep the token's JSON type so numeric
+    // fields receive numbers.
+    if let Some((start, end)) = find_var(s) {
+        if start == 0 && end == s.len() {
+            return lookup(&s[4..end - 1], theme).unwrap_or(Value::Null);
+        }
+    }
+
+    // var() embedded in a larger string: textual substitution.
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some((start, end)) = find_var(rest) {
+        result.push_str(&rest[..start]);
+        match lookup(&rest[start + 4..end - 1], theme) {
+            Some(Value::String(v)) => result.push_str(&v),
+            Some(v) => result.push_str(&v.to_string()),
+            None => {
+                log::warn!("[gpuix] undefined theme variable in {:?}", s);
+                return Value::Null;
+            }
+        }
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    Value::String(result)
+}
+
+/// Byte range of the first `var(...)` in `s`, including nested parentheses
+/// in its fallback (e.g. `var(--c, rgb(0 0 0))`).
+fn find_var(s: &str) -> Option<(usize, usize)> {
+    let start = s.find("var(")?;
+    let mut depth = 0usize;
+    for (i, c) in s[start..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((start, start + i + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Look up `--name[, fallback]`. Fallbacks may themselves use var().
+fn lookup(args: &str, theme: &Theme) -> Option<Value> {
+    let (name, fallback) = match args.split_once(',') {
+        Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+        None => (args.trim(), None),
+    };
+    if let Some(value) = theme.get(name) {
+        return Some(value.clone());
+    }
+    let fallback = fallback?;
+    if let Ok(n) = fallback.parse::<f64>() {
+        return serde_json::Number::from_f64(n).map(Value::Number);
+    }
+    match resolve_string(fallback, theme) {
+        Value::Null => None,
+        v => Some(v),
+    }
+}
@@ -0,0 +1,255 @@
+/// Tooltips for any div.
+///
+/// The `tooltip` prop is either text or an object
+/// `{ text?, element?, delay?, placement? }`. `element` is the id (from a
+/// ref) of one of the div's children; that child is left out of the div's
+/// layout and rendered only inside the tooltip, so rich content keeps its
+/// place in the React tree.
+///
+/// GPUI's own tooltip() has a fixed delay and follows the mouse, so
+/// build_div() shows the tooltip itself: its hover listener starts a timer,
+/// and once that fires the next frame appends an anchored overlay. The
+/// overlay is deferred, unless the element is already inside a deferred
+/// layer, so it escapes `overflow: hidden` ancestors. It flips to the other
+/// side at the window edge.
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use crate::retained_tree::RetainedElement;
+use crate::style::warn_once;
+
+/// Same as GPUI's built-in tooltips.
+const DEFAULT_DELAY: Duration = Duration::from_millis(500);
+/// Space between the element and its tooltip.
+const GAP: f32 = 4.0;
+/// Tooltips paint above every zIndex layer.
+const PRIORITY: usize = usize::MAX;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Placement {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TooltipContent {
+    Text(String),
+    /// A child of the element.
+    Element(u64),
+}
+
+/// A parsed `tooltip` prop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tooltip {
+    pub content: TooltipContent,
+    pub delay: Duration,
+    pub placement: Placement,
+}
+
+impl Tooltip {
+    /// The element's `tooltip` prop, if set and valid. Invalid values and
+    /// `element` ids that aren't children of `element` are logged once.
+    pub fn of(element: &RetainedElement) -> Option<Self> {
+        let value = element.custom_props.get("tooltip")?;
+        let tooltip = match Self::parse(value) {
+            Ok(tooltip) => tooltip?,
+            Err(e) => {
+                warn_once(e);
+                return None;
+            }
+        };
+        if let TooltipContent::Element(id) = tooltip.content {
+            if !element.children.contains(&id) {
+                warn_once(format!(
+                    "tooltip element {} is not a child of element {}",
+                    id, element.id
+                ));
+                return None;
+            }
+        }
+        Some(tooltip)
+    }
+
+    fn parse(value: &Value) -> Result<Option<Self>, String> {
+        let invalid = || format!("invalid tooltip {}", value);
+        let mut tooltip = Tooltip {
+            content: TooltipContent::Text(String::new()),
+            delay: DEFAULT_DELAY,
+            placement: Placement::default(),
+        };
+        match value {
+            Value::Null => return Ok(None),
+            Value::String(text) => tooltip.content = TooltipContent::Text(text.clone()),
+            Value::Object(map) => {
+                tooltip.content = match (map.get("element"), map.get("text")) {
+                    (Some(id), _) => TooltipContent::Element(id.as_u64().ok_or_else(invalid)?),
+                    (None, Some(Value::String(text))) => TooltipContent::Text(text.clone()),
+                    _ => return Err(invalid()),
+                };
+                if let Some(delay) = map.get("delay") {
+                    let ms = delay.as_f64().filter(|ms| *ms >= 0.0).ok_or_else(invalid)?;
+                    tooltip.delay = Duration::from_secs_f64(ms / 1000.0);
+                }
+                if let Some(placement) = map.get("placement") {
+                    tooltip.placement = match placement.as_str() {
+                        Some("top") => Placement::Top,
+                        Some("bottom") => Placement::Bottom,
+                        Some("left") => Placement::Left,
+                        Some("right") => Placement::Right,
+                        _ => return Err(invalid()),
+                    };
+                }
+            }
+            _ => return Err(invalid()),
+        }
+        if tooltip.content == TooltipContent::Text(String::new()) {
+            return Ok(None);
+        }
+        Ok(Some(tooltip))
+    }
+
+    /// The child rendered only inside the tooltip, if any.
+    pub fn element(&self) -> Option<u64> {
+        match self.content {
+            TooltipContent::Element(id) => Some(id),
+            TooltipContent::Text(_) => None,
+        }
+    }
+}
+
+/// Where a hovered element's tooltip is in its delay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TooltipPhase {
+    /// Hovered since this instant; the timer compares it so a stale timer
+    /// from an earlier hover can't show the tooltip.
+    Waiting(Instant),
+    Visible,
+}
+
+/// Tooltip phases by element id, shared with the hover listeners.
+pub type TooltipStates = Arc<Mutex<HashMap<u64, TooltipPhase>>>;
+
+/// Hover listener for an element with a tooltip: start the delay on enter,
+/// hide on leave. Either change refreshes the window so build_div() adds
+/// or drops the overlay.
+pub fn on_hover(
+    states: &TooltipStates,
+    id: u64,
+    delay: Duration,
+    hovered: bool,
+    window: &mut gpui::Window,
+    cx: &mut gpui::App,
+) {
+    if !hovered {
+        if states.lock().unwrap().remove(&id) == Some(TooltipPhase::Visible) {
+            window.refresh();
+        }
+        return;
+    }
+    if delay.is_zero() {
+        states.lock().unwrap().insert(id, TooltipPhase::Visible);
+        window.refresh();
+        return;
+    }
+
+    let started = Instant::now();
+    states
+        .lock()
+        .unwrap()
+        .insert(id, TooltipPhase::Waiting(started));
+    let states = states.clone();
+    window
+        .spawn(cx, async move |cx| {
+            cx.background_executor().timer(delay).await;
+            {
+                let mut states = states.lock().unwrap();
+                if states.get(&id) != Some(&TooltipPhase::Waiting(started)) {
+                    return;
+                }
+                states.insert(id, TooltipPhase::Visible);
+            }
+            cx.update(|window, _cx| window.refresh()).ok();
+        })
+        .detach();
+}
+
+/// Default look for text tooltips.
+pub fn text_content(text: &str) -> gpui::AnyElement {
+    use gpui::prelude::*;
+
+    gpui::div()
+        .px(gpui::px(8.0))
+        .py(gpui::px(4.0))
+        .rounded(gpui::px(4.0))
+        .bg(gpui::rgba(0x1e1e2ef2))
+        .border_1()
+        .border_color(gpui::rgb(0x45475a))
+        .text_color(gpui::rgb(0xcdd6f4))
+        .text_size(gpui::px(12.0))
+        .whitespace_nowrap()
+        .child(text.to_string())
+        .into_any_element()
+}
+
+/// The overlay build_div() appends while the tooltip is visible: a
+/// zero-size absolute anchor at the middle of the element's edge, holding
+/// an `anchored` box with the content centered on that point. The box is
+/// deferred unless the element is already inside a deferred layer, where
+/// it paints in tree order.
+pub fn overlay(
+    placement: Placement,
+    content: gpui::AnyElement,
+    deferred: bool,
+) -> gpui::AnyElement {
+    use gpui::prelude::*;
+
+    let half = gpui::relative(0.5);
+    let gap = gpui::px(GAP);
+    let zero = gpui::px(0.0);
+    // Along the edge the box has zero size, so the (unshrinkable) content
+    // overflows it equally on both sides.
+    let (anchor, corner, centered) = match placement {
+        Placement::Top => (
+            gpui::div().top_0().left(half),
+            gpui::Corner::BottomLeft,
+            gpui::div().w(zero).flex().justify_center().pb(gap),
+        ),
+        Placement::Bottom => (
+            gpui::div().bottom_0().left(half),
+            gpui::Corner::TopLeft,
+            gpui::div().w(zero).flex().justify_center().pt(gap),
+        ),
+        Placement::Left => (
+            gpui::div().left_0().top(half),
+            gpui::Corner::TopRight,
+            gpui::div().h(zero).flex().items_center().pr(gap),
+        ),
+        Placement::Right => (
+            gpui::div().right_0().top(half),
+            gpui::Corner::TopLeft,
+            gpui::div().h(zero).flex().items_center().pl(gap),
+        ),
+    };
+
+    let anchored = gpui::anchored()
+        .anchor(corner)
+        .child(centered.child(gpui::div().flex_none().child(content)));
+    let anchored = if deferred {
+        gpui::deferred(anchored)
+            .with_priority(PRIORITY)
+            .into_any_element()
+    } else {
+        anchored.into_any_element()
+    };
+    anchor
+        .absolute()
+        .size(zero)
+        .child(anchored)
+        .into_any_element()
+}
@@ -0,0 +1,506 @@
+/// CSS transitions — interpolating style changes across frames in Rust.
+///
+/// An element opts in with a `transition` style such as
+/// `"opacity 200ms ease-out, backgroundColor 150ms"`. Each frame,
+/// GpuixView::render() calls Transitions::update() before building elements:
+/// it compares every element's effective style with the one seen last frame,
+/// starts a transition for each changed animatable property, and writes the
+/// interpolated style into RetainedElement::animated_style, which
+/// resolve_style() prefers over `style`. While anything is running the view
+/// requests another animation frame, so JS sends nothing per frame.
+///
+/// Animatable properties are numbers (opacity, spacing, radii, font size),
+/// pixel or percentage lengths (width, height, min/max), and colors. Changes
+/// between values that can't be interpolated (e.g. `"auto"` → `200`, or
+/// `currentColor`) snap, as in CSS.
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::retained_tree::RetainedTree;
+use crate::style::{
+    function_args, parse_color, rgba_to_hex, split_css_tokens, split_top_level, warn_once,
+    DimensionValue, StyleDesc,
+};
+
+/// One entry of a `transition` list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitionSpec {
+    /// camelCase style property, or "all".
+    pub property: String,
+    pub duration: Duration,
+    pub delay: Duration,
+    pub easing: Easing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    Linear,
+    CubicBezier(f32, f32, f32, f32),
+    /// `steps(n)` — jump at the end of each interval.
+    Steps(u32),
+}
+
+impl Easing {
+    /// CSS's default timing function.
+    pub const EASE: Easing = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Some(match value {
+            "linear" => Easing::Linear,
+            "ease" => Easing::EASE,
+            "ease-in" => Easing::CubicBezier(0.42, 0.0, 1.0, 1.0),
+            "ease-out" => Easing::CubicBezier(0.0, 0.0, 0.58, 1.0),
+            "ease-in-out" => Easing::CubicBezier(0.42, 0.0, 0.58, 1.0),
+            "step-end" => Easing::Steps(1),
+            _ => {
+                if let Some(args) = function_args(value, "cubic-bezier") {
+                    let n: Vec<f32> = args
+                        .split(',')
+                        .map(|part| part.trim().parse().ok())
+                        .collect::<Option<_>>()?;
+                    let [x1, y1, x2, y2] = n[..] else {
+                        return None;
+                    };
+                    Easing::CubicBezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2)
+                } else if let Some(args) = function_args(value, "steps") {
+                    let count = args.split(',').next()?.trim().parse().ok()?;
+                    Easing::Steps(count)
+                } else {
+                    return None;
+                }
+            }
+        })
+    }
+
+    /// Map linear progress (0.0-1.0) to eased progress.
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::Steps(count) => {
+                let count = count.max(1) as f32;
+                (t * count).floor() / count
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                let s = solve_bezier_x(x1, x2, t);
+                bezier(y1, y2, s)
+            }
+        }
+    }
+}
+
+/// One coordinate of a cubic bezier from (0,0) to (1,1) at parameter `s`.
+fn bezier(p1: f32, p2: f32, s: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+}
+
+/// Find the curve parameter whose x coordinate is `x` (Newton's method,
+/// falling back to bisection where the slope flattens).
+fn solve_bezier_x(x1: f32, x2: f32, x: f32) -> f32 {
+    let mut s = x;
+    for _ in 0..8 {
+        let error = bezier(x1, x2, s) - x;
+        if error.abs() < 1e-5 {
+            return s;
+        }
+        let inv = 1.0 - s;
+        let slope = 3.0 * inv * inv * x1 + 6.0 * inv * s * (x2 - x1) + 3.0 * s * s * (1.0 - x2);
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        s -= error / slope;
+    }
+    let (mut lo, mut hi) = (0.0f32, 1.0f32);
+    s = x;
+    for _ in 0..30 {
+        let value = bezier(x1, x2, s);
+        if (value - x).abs() < 1e-5 {
+            break;
+        }
+        if value < x {
+            lo = s;
+        } else {
+            hi = s;
+        }
+        s = (lo + hi) / 2.0;
+    }
+    s
+}
+
+/// Parse a CSS `transition` list. Property names may be camelCase
+/// (`backgroundColor`) or CSS kebab-case (`background-color`).
+pub fn parse_transition(value: &str) -> Result<Vec<TransitionSpec>, String> {
+    if value.trim() == "none" {
+        return Ok(Vec::new());
+    }
+    split_top_level(value, ',')
+        .into_iter()
+        .map(|entry| {
+            let invalid = || format!("invalid transition {:?}", entry.trim());
+            let mut property = None;
+            let mut times = Vec::with_capacity(2);
+            let mut easing = None;
+            for token in split_css_tokens(entry) {
+                if let Some(time) = parse_time(token) {
+                    times.push(time);
+                } else if let Some(e) = Easing::parse(token) {
+                    easing = Some(e);
+                } else if property.is_none() {
+                    property = Some(camel_case(token));
+                } else {
+                    return Err(invalid());
+                }
+            }
+            let (duration, delay) = match times[..] {
+                [] => (Duration::ZERO, Duration::ZERO),
+                [duration] => (duration, Duration::ZERO),
+                [duration, delay] => (duration, delay),
+                _ => return Err(invalid()),
+            };
+            Ok(TransitionSpec {
+                property: property.unwrap_or_else(|| "all".to_string()),
+                duration,
+                delay,
+                easing: easing.unwrap_or(Easing::EASE),
+            })
+        })
+        .collect()
+}
+
+/// `"200ms"`, `"0.2s"` → Duration.
+pub fn parse_time(token: &str) -> Option<Duration> {
+    let seconds = if let Some(ms) = token.strip_suffix("ms") {
+        ms.parse::<f64>().ok()? / 1000.0
+    } else {
+        token.strip_suffix('s')?.parse::<f64>().ok()?
+    };
+    (seconds >= 0.0).then(|| Duration::from_secs_f64(seconds))
+}
+
+/// `"background-color"` → `"backgroundColor"`.
+fn camel_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '-' {
+            upper = true;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+// ── Animatable properties ────────────────────────────────────────────
+
+/// An interpolatable style value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimValue {
+    Number(f64),
+    Length(DimensionValue),
+    /// RGBA, 0.0-1.0.
+    Color([f32; 4]),
+}
+
+impl AnimValue {
+    /// Interpolate towards `to`. None when the kinds differ (snap).
+    pub fn lerp(self, to: AnimValue, t: f32) -> Option<AnimValue> {
+        let mix = |a: f64, b: f64| a + (b - a) * t as f64;
+        Some(match (self, to) {
+            (AnimValue::Number(a), AnimValue::Number(b)) => AnimValue::Number(mix(a, b)),
+            // Mixed units interpolate as a calc() sum, resolved at render.
+            (AnimValue::Length(a), AnimValue::Length(b)) => AnimValue::Length(a.lerp(b, t as f64)?),
+            (AnimValue::Color(a), AnimValue::Color(b)) => {
+                AnimValue::Color(std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t))
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// Accessors for one animatable StyleDesc field.
+#[derive(Clone, Copy)]
+pub struct Property {
+    pub name: &'static str,
+    pub get: fn(&StyleDesc) -> Option<AnimValue>,
+    pub set: fn(&mut StyleDesc, AnimValue),
+}
+
+macro_rules! number {
+    ($name:literal, $field:ident) => {
+        Property {
+            name: $name,
+            get: |s| s.$field.map(AnimValue::Number),
+            set: |s, v| {
+                if let AnimValue::Number(n) = v {
+                    s.$field = Some(n);
+                }
+            },
+        }
+    };
+}
+
+macro_rules! length {
+    ($name:literal, $field:ident) => {
+        Property {
+            name: $name,
+            get: |s| s.$field.map(AnimValue::Length),
+            set: |s, v| {
+                if let AnimValue::Length(l) = v {
+                    s.$field = Some(l);
+                }
+            },
+        }
+    };
+}
+
+macro_rules! color {
+    ($name:literal, $field:ident) => {
+        Property {
+            name: $name,
+            get: |s| {
+                let (r, g, b, a) = parse_color(s.$field.as_deref()?)?;
+                Some(AnimValue::Color([r, g, b, a]))
+            },
+            set: |s, v| {
+                if let AnimValue::Color([r, g, b, a]) = v {
+                    s.$field = Some(format!("#{:08x}", rgba_to_hex(r, g, b, a)));
+                }
+            },
+        }
+    };
+}
+
+/// Every property that can transition (and, with keyframes, animate).
+pub static ANIMATABLE: &[Property] = &[
+    number!("opacity", opacity),
+    number!("flexGrow", flex_grow),
+    number!("flexShrink", flex_shrink),
+    length!("gap", gap),
+    length!("rowGap", row_gap),
+    length!("columnGap", column_gap),
+    length!("width", width),
+    length!("height", height),
+    length!("minWidth", min_width),
+    length!("minHeight", min_height),
+    length!("maxWidth", max_width),
+    length!("maxHeight", max_height),
+    length!("padding", padding),
+    length!("paddingTop", padding_top),
+    length!("paddingRight", padding_right),
+    length!("paddingBottom", padding_bottom),
+    length!("paddingLeft", padding_left),
+    length!("margin", margin),
+    length!("marginTop", margin_top),
+    length!("marginRight", margin_right),
+    length!("marginBottom", margin_bottom),
+    length!("marginLeft", margin_left),
+    length!("top", top),
+    length!("right", right),
+    length!("bottom", bottom),
+    length!("left", left),
+    color!("background", background),
+    color!("backgroundColor", background_color),
+    color!("color", color),
+    length!("borderWidth", border_width),
+    length!("borderTopWidth", border_top_width),
+    length!("borderRightWidth", border_right_width),
+    length!("borderBottomWidth", border_bottom_width),
+    length!("borderLeftWidth", border_left_width),
+    color!("borderColor", border_color),
+    color!("borderTopColor", border_top_color),
+    color!("borderRightColor", border_right_color),
+    color!("borderBottomColor", border_bottom_color),
+    color!("borderLeftColor", border_left_color),
+    length!("borderRadius", border_radius),
+    length!("borderTopLeftRadius", border_top_left_radius),
+    length!("borderTopRightRadius", border_top_right_radius),
+    length!("borderBottomLeftRadius", border_bottom_left_radius),
+    length!("borderBottomRightRadius", border_bottom_right_radius),
+    length!("fontSize", font_size),
+    length!("lineHeight", line_height),
+    color!("textDecorationColor", text_decoration_color),
+];
+
+// ── Per-element state ────────────────────────────────────────────────
+
+struct Running {
+    property: &'static Property,
+    from: AnimValue,
+    to: AnimValue,
+    start: Instant,
+    delay: Duration,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Running {
+    /// Eased progress at `now`, 0.0-1.0.
+    fn progress(&self, now: Instant) -> f32 {
+        let elapsed = now.saturating_duration_since(self.start);
+        if elapsed < self.delay {
+            return 0.0;
+        }
+        let t = (elapsed - self.delay).as_secs_f32() / self.duration.as_secs_f32();
+        self.easing.apply(t.min(1.0))
+    }
+
+    fn is_finished(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.start) >= self.delay + self.duration
+    }
+
+    fn value(&self, now: Instant) -> AnimValue {
+        self.from
+            .lerp(self.to, self.progress(now))
+            .unwrap_or(self.to)
+    }
+}
+
+struct ElementTransitions {
+    /// The effective style seen last frame — the baseline for change detection.
+    last: StyleDesc,
+    running: Vec<Running>,
+}
+
+/// A transition that completed this frame.
+pub struct FinishedTransition {
+    pub element_id: u64,
+    pub property: &'static str,
+    pub elapsed: Duration,
+}
+
+/// Result of one Transitions::update().
+#[derive(Default)]
+pub struct TransitionFrame {
+    /// True while any transition is still running — request another frame.
+    pub running: bool,
+    pub finished: Vec<FinishedTransition>,
+}
+
+/// Parsed `transition` values kept before the cache is cleared.
+const SPEC_CACHE_LIMIT: usize = 256;
+
+/// Transition state for every element, owned by GpuixView.
+#[derive(Default)]
+pub struct Transitions {
+    elements: HashMap<u64, ElementTransitions>,
+    /// Parsed specs keyed by `transition` value, so they aren't re-parsed
+    /// every frame. Invalid values map to an empty list.
+    specs: HashMap<String, Vec<TransitionSpec>>,
+}
+
+impl Transitions {
+    /// Detect style changes, advance running transitions, and write the
+    /// interpolated styles into `animated_style`.
+    pub fn update(&mut self, tree: &mut RetainedTree, now: Instant) -> TransitionFrame {
+        let mut frame = TransitionFrame::default();
+        self.elements.retain(|id, _| tree.elements.contains_key(id));
+        if self.specs.len() > SPEC_CACHE_LIMIT {
+            self.specs.clear();
+        }
+
+        for (&id, element) in tree.elements.iter_mut() {
+            let specs: &[TransitionSpec] =
+                match element.style.as_ref().and_then(|s| s.transition.as_deref()) {
+                    Some(value) => {
+                        if !self.specs.contains_key(value) {
+                            let specs = parse_transition(value).unwrap_or_else(|e| {
+                                warn_once(e);
+                                Vec::new()
+                            });
+                            self.specs.insert(value.to_owned(), specs);
+                        }
+                        &self.specs[value]
+                    }
+                    None => &[],
+                };
+            let (Some(target), false) = (element.style.as_ref(), specs.is_empty()) else {
+                // Removing `transition` cancels running transitions.
+                if self.elements.remove(&id).is_some() {
+                    element.animated_style = None;
+                }
+                continue;
+            };
+
+            let Some(state) = self.elements.get_mut(&id) else {
+                // First sighting: nothing to transition from.
+                self.elements.insert(
+                    id,
+                    ElementTransitions {
+                        last: target.clone(),
+                        running: Vec::new(),
+                    },
+                );
+                continue;
+            };
+
+            let mut changed = false;
+            for property in ANIMATABLE {
+                let old = (property.get)(&state.last);
+                let new = (property.get)(target);
+                if old == new {
+                    continue;
+                }
+                changed = true;
+                let current = state
+                    .running
+                    .iter()
+                    .find(|r| r.property.name == property.name)
+                    .map(|r| r.value(now))
+                    .or(old);
+                state.running.retain(|r| r.property.name != property.name);
+
+                let spec = specs
+                    .iter()
+                    .rev()
+                    .find(|spec| spec.property == property.name || spec.property == "all");
+                if let (Some(spec), Some(from), Some(to)) = (spec, current, new) {
+                    if spec.duration > Duration::ZERO && from.lerp(to, 0.0).is_some() {
+                        state.running.push(Running {
+                            property,
+                            from,
+                            to,
+                            start: now,
+                            delay: spec.delay,
+                            duration: spec.duration,
+                            easing: spec.easing,
+                        });
+                    }
+                }
+            }
+            // Only animatable properties are compared, so the baseline
+            // needs updating only when one of them changed.
+            if changed {
+                state.last.clone_from(target);
+            }
+            if state.running.is_empty() && element.animated_style.is_none() {
+                continue;
+            }
+
+            let mut animated = target.clone();
+            state.running.retain(|running| {
+                if running.is_finished(now) {
+                    frame.finished.push(FinishedTransition {
+                        element_id: id,
+                        property: running.property.name,
+                        elapsed: running.duration,
+                    });
+                    false
+                } else {
+                    (running.property.set)(&mut animated, running.value(now));
+                    true
+                }
+            });
+            if state.running.is_empty() {
+                element.animated_style = None;
+            } else {
+                frame.running = true;
+                element.animated_style = Some(animated);
+            }
+        }
+        frame
+    }
+}
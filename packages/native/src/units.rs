@@ -0,0 +1,579 @@
+/// CSS length units — parsing `em`/`rem`/`vw`/`vh`/`calc()` into
+/// DimensionValue and resolving them per element before styles reach GPUI.
+///
+/// GPUI lays out with pixels, rems and parent fractions only, so relative
+/// units are resolved in resolve_style() against a LengthContext:
+/// - `rem` against the root element's font size (16px unless set),
+/// - `em` against the element's font size (its parent's, for `fontSize`),
+/// - `vw`/`vh` against the window's viewport,
+/// - `calc()` mixing percentages with absolute terms against the parent's
+///   size measured in the previous frame (build_div() measures parents of
+///   such children and requests another frame when the size changes).
+///
+/// Pure-percentage values stay percentages and are resolved by taffy.
+use std::collections::HashMap;
+
+use crate::retained_tree::{RetainedElement, RetainedTree};
+use crate::style::{CalcLength, DimensionValue, StyleDesc};
+
+/// GPUI's default rem size and text size.
+pub const DEFAULT_FONT_SIZE: f64 = 16.0;
+
+/// Parse a string length: `"auto"`, `"50%"`, `"12"`, `"12px"`, `"1.5rem"`,
+/// `"2em"`, `"50vw"`, `"10vh"` or `"calc(100% - 24px)"`.
+pub fn parse_dimension(value: &str) -> Result<DimensionValue, String> {
+    let value = value.trim();
+    if value == "auto" {
+        return Ok(DimensionValue::Auto);
+    }
+    if let Some(inner) = crate::style::function_args(value, "calc") {
+        let mut parser = CalcParser {
+            input: inner,
+            pos: 0,
+        };
+        let term = parser
+            .expression()
+            .filter(|_| parser.at_end())
+            .ok_or_else(|| format!("invalid calc(): {}", value))?;
+        return match term {
+            Term::Length(calc) => Ok(simplify(calc)),
+            Term::Number(n) if n == 0.0 => Ok(DimensionValue::Pixels(0.0)),
+            Term::Number(_) => Err(format!("calc() without a unit: {}", value)),
+        };
+    }
+    match parse_single(value) {
+        Some(Term::Length(calc)) => Ok(simplify(calc)),
+        Some(Term::Number(n)) => Ok(DimensionValue::Pixels(n)),
+        None => Err(format!("invalid dimension: {}", value)),
+    }
+}
+
+/// A calc() operand: a bare number (only valid as a factor) or a length.
+#[derive(Clone, Copy)]
+enum Term {
+    Number(f64),
+    Length(CalcLength),
+}
+
+/// `12`, `12px`, `50%`, `1.5rem`, `2em`, `50vw`, `10vh`.
+fn parse_single(token: &str) -> Option<Term> {
+    let split = token
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')))
+        .unwrap_or(token.len());
+    // "2em" / "1e3px": a trailing 'e' belongs to the unit, not the exponent.
+    let split = if token[split..].starts_with('m') && token[..split].ends_with('e') {
+        split - 1
+    } else {
+        split
+    };
+    let (number, unit) = token.split_at(split);
+    let n = number.parse::<f64>().ok()?;
+    let mut calc = CalcLength::default();
+    match unit {
+        "" => return Some(Term::Number(n)),
+        "px" => calc.px = n,
+        "%" => calc.percent = n / 100.0,
+        "em" => calc.em = n,
+        "rem" => calc.rem = n,
+        "vw" => calc.vw = n / 100.0,
+        "vh" => calc.vh = n / 100.0,
+        _ => return None,
+    }
+    Some(Term::Length(calc))
+}
+
+/// Recursive-descent parser for calc() bodies: `+`/`-` between lengths,
+/// `*`/`/` by plain numbers, and parentheses (or nested `calc()`).
+struct CalcParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl CalcParser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.pos = self.input.len() - trimmed.len();
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.pos == self.input.len()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expression(&mut self) -> Option<Term> {
+        let mut acc = self.product()?;
+        loop {
+            let sign = if self.eat('+') {
+                1.0
+            } else if self.eat('-') {
+                -1.0
+            } else {
+                return Some(acc);
+            };
+            acc = match (acc, self.product()?) {
+                (Term::Length(a), Term::Length(b)) => Term::Length(add(a, scale(b, sign))),
+                (Term::Number(a), Term::Number(b)) => Term::Number(a + sign * b),
+                // `100% - 0` is fine; other number/length sums are not.
+                (Term::Length(a), Term::Number(b)) if b == 0.0 => Term::Length(a),
+                _ => return None,
+            };
+        }
+    }
+
+    fn product(&mut self) -> Option<Term> {
+        let mut acc = self.factor()?;
+        loop {
+            let divide = if self.eat('*') {
+                false
+            } else if self.eat('/') {
+                true
+            } else {
+                return Some(acc);
+            };
+            acc = match (acc, self.factor()?, divide) {
+                (Term::Number(a), Term::Number(b), false) => Term::Number(a * b),
+                (Term::Number(a), Term::Number(b), true) if b != 0.0 => Term::Number(a / b),
+                (Term::Length(a), Term::Number(b), false)
+                | (Term::Number(b), Term::Length(a), false) => Term::Length(scale(a, b)),
+                (Term::Length(a), Term::Number(b), true) if b != 0.0 => {
+                    Term::Length(scale(a, 1.0 / b))
+                }
+                _ => return None,
+            };
+        }
+    }
+
+    fn factor(&mut self) -> Option<Term> {
+        self.skip_whitespace();
+        if self.rest().starts_with("calc(") {
+            self.pos += "calc".len();
+        }
+        if self.eat('(') {
+            let inner = self.expression()?;
+            return self.eat(')').then_some(inner);
+        }
+        // CSS requires whitespace around binary + and -, so a sign directly
+        // before a number belongs to it.
+        let end = self
+            .rest()
+            .find(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | '*' | '/'))
+            .unwrap_or(self.rest().len());
+        let token = &self.rest()[..end];
+        if token.is_empty() {
+            return None;
+        }
+        self.pos += end;
+        parse_single(token)
+    }
+}
+
+fn add(a: CalcLength, b: CalcLength) -> CalcLength {
+    CalcLength {
+        px: a.px + b.px,
+        percent: a.percent + b.percent,
+        em: a.em + b.em,
+        rem: a.rem + b.rem,
+        vw: a.vw + b.vw,
+        vh: a.vh + b.vh,
+    }
+}
+
+fn scale(a: CalcLength, k: f64) -> CalcLength {
+    CalcLength {
+        px: a.px * k,
+        percent: a.percent * k,
+        em: a.em * k,
+        rem: a.rem * k,
+        vw: a.vw * k,
+        vh: a.vh * k,
+    }
+}
+
+/// The simplest DimensionValue for a sum — a single-unit sum becomes that unit.
+fn simplify(calc: CalcLength) -> DimensionValue {
+    let CalcLength {
+        px,
+        percent,
+        em,
+        rem,
+        vw,
+        vh,
+    } = calc;
+    let units = [px, percent, em, rem, vw, vh];
+    match units.iter().filter(|v| **v != 0.0).count() {
+        0 => DimensionValue::Pixels(0.0),
+        1 if px != 0.0 => DimensionValue::Pixels(px),
+        1 if percent != 0.0 => DimensionValue::Percentage(percent),
+        1 if em != 0.0 => DimensionValue::Em(em),
+        1 if rem != 0.0 => DimensionValue::Rem(rem),
+        1 if vw != 0.0 => DimensionValue::Vw(vw),
+        1 => DimensionValue::Vh(vh),
+        _ => DimensionValue::Calc(calc),
+    }
+}
+
+impl DimensionValue {
+    /// This length as a sum of unit terms; None for `auto`.
+    pub fn to_calc(self) -> Option<CalcLength> {
+        let mut calc = CalcLength::default();
+        match self {
+            DimensionValue::Pixels(v) => calc.px = v,
+            DimensionValue::Percentage(v) => calc.percent = v,
+            DimensionValue::Rem(v) => calc.rem = v,
+            DimensionValue::Em(v) => calc.em = v,
+            DimensionValue::Vw(v) => calc.vw = v,
+            DimensionValue::Vh(v) => calc.vh = v,
+            DimensionValue::Calc(c) => calc = c,
+            DimensionValue::Auto => return None,
+        }
+        Some(calc)
+    }
+
+    /// Interpolate towards `to` (any units — mixed ones become a calc()).
+    /// None when either side is `auto`.
+    pub fn lerp(self, to: DimensionValue, t: f64) -> Option<DimensionValue> {
+        let from = self.to_calc()?;
+        let to = to.to_calc()?;
+        Some(simplify(add(scale(from, 1.0 - t), scale(to, t))))
+    }
+
+    /// True for units GPUI can't take directly.
+    pub fn is_relative(self) -> bool {
+        !matches!(
+            self,
+            DimensionValue::Pixels(_) | DimensionValue::Percentage(_) | DimensionValue::Auto
+        )
+    }
+
+    /// Resolve to Pixels, Percentage or Auto. `percent_base` is the size
+    /// percentages refer to, when known.
+    pub fn resolve(self, ctx: &LengthContext, percent_base: Option<f64>) -> DimensionValue {
+        let Some(calc) = self.to_calc().filter(|_| self.is_relative()) else {
+            return self;
+        };
+        let absolute = calc.px
+            + calc.em * ctx.font_size
+            + calc.rem * ctx.root_font_size
+            + calc.vw * ctx.viewport.0
+            + calc.vh * ctx.viewport.1;
+        if calc.percent == 0.0 {
+            return DimensionValue::Pixels(absolute);
+        }
+        match percent_base {
+            Some(base) => DimensionValue::Pixels(absolute + calc.percent * base),
+            // Not measured yet: lay out the percentage alone this frame.
+            None => DimensionValue::Percentage(calc.percent),
+        }
+    }
+
+    /// True for a calc() that mixes a percentage with absolute terms, which
+    /// needs the parent's measured size to resolve.
+    pub fn needs_parent_size(self) -> bool {
+        matches!(self, DimensionValue::Calc(calc) if calc.percent != 0.0)
+    }
+
+    /// The resolved length in pixels, for fields GPUI only takes as
+    /// absolute lengths (border widths and radii). A percentage or `auto`
+    /// logs a warning and gives None.
+    pub fn pixels(self, field: &str) -> Option<f64> {
+        match self {
+            DimensionValue::Pixels(px) => Some(px),
+            other => {
+                crate::style::warn_once(format!(
+                    "{} must be an absolute length, ignoring {:?}",
+                    field, other
+                ));
+                None
+            }
+        }
+    }
+}
+
+/// What relative units resolve against for one element.
+#[derive(Debug, Clone, Copy)]
+pub struct LengthContext {
+    /// The element's own font size, in pixels (`em`).
+    pub font_size: f64,
+    /// The parent's font size (`em` and `%` inside `fontSize`).
+    pub parent_font_size: f64,
+    /// The root element's font size (`rem`).
+    pub root_font_size: f64,
+    /// Window viewport (width, height) in pixels (`vw`/`vh`).
+    pub viewport: (f64, f64),
+    /// Parent (width, height) from the previous frame's layout, or the
+    /// viewport for the root element (`%` inside `calc()`).
+    pub parent_size: Option<(f64, f64)>,
+}
+
+impl Default for LengthContext {
+    fn default() -> Self {
+        Self {
+            font_size: DEFAULT_FONT_SIZE,
+            parent_font_size: DEFAULT_FONT_SIZE,
+            root_font_size: DEFAULT_FONT_SIZE,
+            viewport: (0.0, 0.0),
+            parent_size: None,
+        }
+    }
+}
+
+/// Which parent dimension a field's percentages refer to.
+#[derive(Clone, Copy)]
+enum Axis {
+    Width,
+    Height,
+    /// Fields GPUI takes no percentage for: border widths and radii, and
+    /// letterSpacing.
+    Absolute,
+}
+
+impl StyleDesc {
+    /// Every length field with the axis its percentages refer to. Padding
+    /// and margin percentages use the width on all sides, as in CSS.
+    fn length_fields_mut(&mut self) -> [(&mut Option<DimensionValue>, Axis); 35] {
+        use Axis::{Absolute, Height, Width};
+        [
+            (&mut self.width, Width),
+            (&mut self.height, Height),
+            (&mut self.min_width, Width),
+            (&mut self.min_height, Height),
+            (&mut self.max_width, Width),
+            (&mut self.max_height, Height),
+            (&mut self.flex_basis, Width),
+            (&mut self.gap, Width),
+            (&mut self.row_gap, Height),
+            (&mut self.column_gap, Width),
+            (&mut self.padding, Width),
+            (&mut self.padding_top, Width),
+            (&mut self.padding_right, Width),
+            (&mut self.padding_bottom, Width),
+            (&mut self.padding_left, Width),
+            (&mut self.margin, Width),
+            (&mut self.margin_top, Width),
+            (&mut self.margin_right, Width),
+            (&mut self.margin_bottom, Width),
+            (&mut self.margin_left, Width),
+            (&mut self.top, Height),
+            (&mut self.right, Width),
+            (&mut self.bottom, Height),
+            (&mut self.left, Width),
+            (&mut self.border_width, Absolute),
+            (&mut self.border_top_width, Absolute),
+            (&mut self.border_right_width, Absolute),
+            (&mut self.border_bottom_width, Absolute),
+            (&mut self.border_left_width, Absolute),
+            (&mut self.border_radius, Absolute),
+            (&mut self.border_top_left_radius, Absolute),
+            (&mut self.border_top_right_radius, Absolute),
+            (&mut self.border_bottom_left_radius, Absolute),
+            (&mut self.border_bottom_right_radius, Absolute),
+            (&mut self.letter_spacing, Absolute),
+        ]
+    }
+
+    /// Every length field of this style, set or not.
+    fn lengths(&self) -> [Option<DimensionValue>; 37] {
+        [
+            self.width,
+            self.height,
+            self.min_width,
+            self.min_height,
+            self.max_width,
+            self.max_height,
+            self.flex_basis,
+            self.gap,
+            self.row_gap,
+            self.column_gap,
+            self.padding,
+            self.padding_top,
+            self.padding_right,
+            self.padding_bottom,
+            self.padding_left,
+            self.margin,
+            self.margin_top,
+            self.margin_right,
+            self.margin_bottom,
+            self.margin_left,
+            self.top,
+            self.right,
+            self.bottom,
+            self.left,
+            self.border_width,
+            self.border_top_width,
+            self.border_right_width,
+            self.border_bottom_width,
+            self.border_left_width,
+            self.border_radius,
+            self.border_top_left_radius,
+            self.border_top_right_radius,
+            self.border_bottom_left_radius,
+            self.border_bottom_right_radius,
+            self.letter_spacing,
+            self.font_size,
+            self.line_height,
+        ]
+    }
+
+    /// True if `test` holds for any length set on this style or its
+    /// pseudo-style blocks. Runs for every element each frame, so it
+    /// checks the fields in place rather than collecting them.
+    fn any_length(&self, test: fn(DimensionValue) -> bool) -> bool {
+        self.lengths().into_iter().flatten().any(test)
+            || self
+                .pseudo_styles()
+                .into_iter()
+                .flatten()
+                .any(|sub| sub.any_length(test))
+    }
+
+    /// True if any length (including pseudo-styles) uses em, rem, vw, vh or calc().
+    pub fn uses_relative_lengths(&self) -> bool {
+        self.any_length(DimensionValue::is_relative)
+    }
+
+    /// True if any length is a calc() needing the parent's measured size.
+    pub fn needs_parent_size(&self) -> bool {
+        self.any_length(DimensionValue::needs_parent_size)
+    }
+
+    /// Copy of this style with every relative length resolved against `ctx`.
+    pub fn with_resolved_lengths(&self, ctx: &LengthContext) -> StyleDesc {
+        let mut style = self.clone();
+        let (width, height) = match ctx.parent_size {
+            Some((w, h)) => (Some(w), Some(h)),
+            None => (None, None),
+        };
+        for (field, axis) in style.length_fields_mut() {
+            if let Some(value) = field.as_mut() {
+                let base = match axis {
+                    Axis::Width => width,
+                    Axis::Height => height,
+                    Axis::Absolute => None,
+                };
+                *value = value.resolve(ctx, base);
+            }
+        }
+        // fontSize: em and % refer to the parent's font size.
+        if let Some(size) = style.font_size.as_mut() {
+            let parent = LengthContext {
+                font_size: ctx.parent_font_size,
+                ..*ctx
+            };
+            *size = match *size {
+                DimensionValue::Percentage(p) => DimensionValue::Pixels(p * ctx.parent_font_size),
+                other => other.resolve(&parent, Some(ctx.parent_font_size)),
+            };
+        }
+        // lineHeight: a plain percentage stays relative (GPUI scales it by
+        // the font size); inside calc() it is resolved the same way.
+        if let Some(line_height) = style.line_height.as_mut() {
+            *line_height = line_height.resolve(ctx, Some(ctx.font_size));
+        }
+        for sub in style.pseudo_styles_mut().into_iter().flatten() {
+            **sub = sub.with_resolved_lengths(ctx);
+        }
+        style
+    }
+}
+
+/// The style that currently paints `element` (mid-transition if animating).
+fn current_style(element: &RetainedElement) -> Option<&StyleDesc> {
+    element.animated_style.as_ref().or(element.style.as_ref())
+}
+
+/// Every element's font size in pixels, computed top-down once per frame
+/// by GpuixView::render() so length_context() doesn't walk the ancestors.
+#[derive(Debug, Default)]
+pub struct FontSizes {
+    /// The root element's font size (`rem`).
+    root: f64,
+    sizes: HashMap<u64, f64>,
+}
+
+impl FontSizes {
+    /// Recompute the font sizes of the elements under the root, parents
+    /// before children. Each is its own `fontSize` resolved against its
+    /// parent's, else inherited; GPUI's default is 16px.
+    pub fn update(&mut self, tree: &RetainedTree) {
+        self.sizes.clear();
+        self.root = DEFAULT_FONT_SIZE;
+        let Some(root) = tree.root_id.and_then(|id| tree.elements.get(&id)) else {
+            return;
+        };
+        self.root = self.resolve(root, DEFAULT_FONT_SIZE, tree.viewport);
+        let mut pending = vec![(root, self.root)];
+        while let Some((element, size)) = pending.pop() {
+            self.sizes.insert(element.id, size);
+            for id in &element.children {
+                if let Some(child) = tree.elements.get(id) {
+                    pending.push((child, self.resolve(child, size, tree.viewport)));
+                }
+            }
+        }
+    }
+
+    /// The font size of `id`, or the default for elements not rendered
+    /// this frame.
+    fn get(&self, id: u64) -> f64 {
+        self.sizes.get(&id).copied().unwrap_or(DEFAULT_FONT_SIZE)
+    }
+
+    /// `element`'s font size given the size it inherits.
+    fn resolve(&self, element: &RetainedElement, inherited: f64, viewport: (f64, f64)) -> f64 {
+        match current_style(element).and_then(|s| s.font_size) {
+            Some(DimensionValue::Percentage(p)) => p * inherited,
+            Some(size) => {
+                let ctx = LengthContext {
+                    font_size: inherited,
+                    parent_font_size: inherited,
+                    root_font_size: self.root,
+                    viewport,
+                    parent_size: None,
+                };
+                match size.resolve(&ctx, Some(inherited)) {
+                    DimensionValue::Pixels(px) => px,
+                    _ => inherited,
+                }
+            }
+            None => inherited,
+        }
+    }
+}
+
+/// Everything `element`'s relative lengths resolve against.
+pub fn length_context(element: &RetainedElement, tree: &RetainedTree) -> LengthContext {
+    let parent = element.parent.and_then(|id| tree.elements.get(&id));
+    let fixed = current_style(element).is_some_and(|s| s.position.as_deref() == Some("fixed"));
+    let parent_size = match parent {
+        // Fixed elements are laid out in a window-sized box.
+        Some(_) if fixed => Some(tree.viewport),
+        Some(parent) => tree
+            .layout_bounds
+            .lock()
+            .unwrap()
+            .get(&parent.id)
+            .map(|b| (b.width, b.height)),
+        None => Some(tree.viewport),
+    };
+    LengthContext {
+        font_size: tree.font_sizes.get(element.id),
+        parent_font_size: parent.map_or(DEFAULT_FONT_SIZE, |p| tree.font_sizes.get(p.id)),
+        root_font_size: tree.font_sizes.root,
+        viewport: tree.viewport,
+        parent_size,
+    }
+}
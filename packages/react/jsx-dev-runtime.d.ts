@@ -1,6 +1,6 @@
 /// GPUIX JSX dev-runtime types — mirrors jsx-runtime.d.ts for development builds.
 
-import type { AnchoredProps, ImgProps, Props, InputProps } from "./dist/types/host"
+import type { AnchoredProps, ImgProps, Props, InputProps, TextProps } from "./dist/types/host"
 
 export { jsx, jsxs, Fragment } from "react/jsx-dev-runtime"
 
@@ -14,7 +14,7 @@ export namespace JSX {
 
   interface IntrinsicElements {
     div: Props
-    text: Props
+    text: TextProps
     img: ImgProps
     svg: Props
     canvas: Props
@@ -1,7 +1,7 @@
 /// GPUIX JSX runtime types — maps intrinsic elements to GPUIX Props
 /// instead of DOM types. Activated via "jsxImportSource": "@gpuix/react".
 
-import type { AnchoredProps, ImgProps, Props, InputProps } from "./dist/types/host"
+import type { AnchoredProps, ImgProps, Props, InputProps, TextProps } from "./dist/types/host"
 
 export { jsx, jsxs, Fragment } from "react/jsx-runtime"
 
@@ -15,7 +15,7 @@ export namespace JSX {
 
   interface IntrinsicElements {
     div: Props
-    text: Props
+    text: TextProps
     img: ImgProps
     svg: Props
     canvas: Props
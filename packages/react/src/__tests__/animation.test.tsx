@@ -0,0 +1,109 @@
+/// Tests for GPUIX keyframe animations — registerAnimation() plus the
+/// `animation` style, driven natively by GPUI's with_animations.
+
+import fs from "fs"
+import { beforeEach, describe, expect, it } from "vitest"
+import React from "react"
+import { createTestRoot, hasNativeTestRenderer } from "../testing"
+import { expectScreenshotsDiffer } from "./test-utils"
+
+const describeNative = hasNativeTestRenderer ? describe : describe.skip
+
+const SCREENSHOT_DIR = "/tmp"
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms))
+
+describeNative("keyframe animations", () => {
+  let testRoot: ReturnType<typeof createTestRoot>
+
+  beforeEach(() => {
+    testRoot = createTestRoot()
+    testRoot.renderer.registerAnimation(
+      "pulse",
+      JSON.stringify({
+        from: { opacity: 1, backgroundColor: "#89b4fa" },
+        "50%": { opacity: 0.3, backgroundColor: "#f38ba8" },
+        to: { opacity: 1, backgroundColor: "#89b4fa" },
+      })
+    )
+  })
+
+  it("runs a registered animation without re-rendering", async () => {
+    testRoot.render(
+      <div style={{ width: 120, height: 60, backgroundColor: "#313244", animation: "pulse 80ms linear infinite" }} />
+    )
+    const commitsBefore = testRoot.renderer.commitCount
+
+    await sleep(40)
+    testRoot.renderer.flush()
+
+    const path = `${SCREENSHOT_DIR}/gpuix-animation-pulse.png`
+    if (fs.existsSync(path)) fs.unlinkSync(path)
+    testRoot.renderer.captureScreenshot(path)
+    expect(fs.existsSync(path)).toBe(true)
+
+    // Animation frames never touch React or the retained style.
+    expect(testRoot.renderer.commitCount).toBe(commitsBefore)
+    const [div] = testRoot.renderer.findByType("div")
+    expect(div.style.animation).toBe("pulse 80ms linear infinite")
+    expect(div.style.backgroundColor).toBe("#313244")
+  })
+
+  it("waits out the delay of an infinite animation before starting it", async () => {
+    testRoot.render(
+      <div style={{ width: 120, height: 60, backgroundColor: "#313244", animation: "pulse 400ms 200ms linear infinite" }} />
+    )
+    testRoot.renderer.flush()
+    const delayed = `${SCREENSHOT_DIR}/gpuix-animation-delayed.png`
+    testRoot.renderer.captureScreenshot(delayed)
+
+    await sleep(300)
+    testRoot.renderer.flush()
+    const running = `${SCREENSHOT_DIR}/gpuix-animation-delayed-running.png`
+    testRoot.renderer.captureScreenshot(running)
+
+    expectScreenshotsDiffer(delayed, running)
+  })
+
+  it("animates text elements", async () => {
+    testRoot.render(
+      <text style={{ fontSize: 24, color: "#cdd6f4", backgroundColor: "#313244", animation: "pulse 200ms linear infinite" }}>
+        pulsing
+      </text>
+    )
+    testRoot.renderer.flush()
+    const start = `${SCREENSHOT_DIR}/gpuix-animation-text-start.png`
+    testRoot.renderer.captureScreenshot(start)
+
+    await sleep(100)
+    testRoot.renderer.flush()
+    const middle = `${SCREENSHOT_DIR}/gpuix-animation-text-middle.png`
+    testRoot.renderer.captureScreenshot(middle)
+
+    expectScreenshotsDiffer(start, middle)
+  })
+
+  it("renders unknown animations and malformed values unanimated", () => {
+    testRoot.render(
+      <div>
+        <div style={{ width: 50, height: 50, animation: "missing 1s" }} />
+        <div style={{ width: 50, height: 50, animation: "pulse 1s 2s 3s" }} />
+      </div>
+    )
+    testRoot.renderer.flush()
+    expect(testRoot.renderer.findByType("div").length).toBe(3)
+  })
+
+  it("accepts comma-separated offsets and rejects invalid keyframes", () => {
+    expect(() =>
+      testRoot.renderer.registerAnimation(
+        "blink",
+        JSON.stringify({ "0%, 100%": { opacity: 1 }, "50%": { opacity: 0 } })
+      )
+    ).not.toThrow()
+    expect(() => testRoot.renderer.registerAnimation("bad", "[1]")).toThrow()
+    expect(() => testRoot.renderer.registerAnimation("bad", JSON.stringify({ "150%": {} }))).toThrow()
+    expect(() => testRoot.renderer.registerAnimation("bad", JSON.stringify({ from: { opacity: "x" } }))).toThrow()
+    expect(() => testRoot.renderer.registerAnimation("two words", JSON.stringify({ from: {} }))).toThrow()
+  })
+})
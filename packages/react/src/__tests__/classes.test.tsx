@@ -0,0 +1,86 @@
+/// Tests for GPUIX style classes — registerStyleClass() plus the className
+/// prop, merged natively (classes in order, then the inline style).
+///
+/// @ts-nocheck
+
+import fs from "fs"
+import { beforeEach, describe, expect, it } from "vitest"
+import React from "react"
+import { createTestRoot, hasNativeTestRenderer } from "../testing"
+
+const describeNative = hasNativeTestRenderer ? describe : describe.skip
+
+const SCREENSHOT_DIR = "/tmp"
+
+describeNative("style classes", () => {
+  let testRoot: ReturnType<typeof createTestRoot>
+
+  beforeEach(() => {
+    testRoot = createTestRoot()
+    testRoot.renderer.registerStyleClass(
+      "card",
+      JSON.stringify({ padding: 12, borderRadius: 6, backgroundColor: "#1e1e2e", hover: { opacity: 0.9 } })
+    )
+    testRoot.renderer.registerStyleClass(
+      "accent",
+      JSON.stringify({ backgroundColor: "#89b4fa", hover: { backgroundColor: "#74c7ec" } })
+    )
+  })
+
+  it("merges classes in order, then the inline style", () => {
+    testRoot.render(<div className="card accent" style={{ width: 200, height: 80, padding: 4 }} />)
+
+    const [div] = testRoot.renderer.findByType("div")
+    expect(div.style.backgroundColor).toBe("#89b4fa")
+    expect(div.style.borderRadius).toBe(6)
+    expect(div.style.padding).toBe(4)
+    expect(div.style.width).toBe(200)
+    expect(div.style.hover.opacity).toBe(0.9)
+    expect(div.style.hover.backgroundColor).toBe("#74c7ec")
+
+    const path = `${SCREENSHOT_DIR}/gpuix-style-classes.png`
+    if (fs.existsSync(path)) fs.unlinkSync(path)
+    testRoot.renderer.captureScreenshot(path)
+    expect(fs.existsSync(path)).toBe(true)
+  })
+
+  it("restyles elements when a class is re-registered", () => {
+    testRoot.render(<div className="card" style={{ width: 200, height: 80 }} />)
+    const commitsBefore = testRoot.renderer.commitCount
+
+    testRoot.renderer.registerStyleClass("card", JSON.stringify({ backgroundColor: "#f38ba8" }))
+
+    const [div] = testRoot.renderer.findByType("div")
+    expect(div.style.backgroundColor).toBe("#f38ba8")
+    expect(div.style.padding).toBeUndefined()
+    expect(testRoot.renderer.commitCount).toBe(commitsBefore)
+  })
+
+  it("updates when className changes and ignores unknown classes", () => {
+    const Box = ({ className }: { className: string }) => (
+      <div className={className} style={{ width: 200, height: 80 }} />
+    )
+
+    testRoot.render(<Box className="card" />)
+    expect(testRoot.renderer.findByType("div")[0].style.backgroundColor).toBe("#1e1e2e")
+
+    testRoot.render(<Box className="accent missing" />)
+    const [div] = testRoot.renderer.findByType("div")
+    expect(div.style.backgroundColor).toBe("#89b4fa")
+    expect(div.style.padding).toBeUndefined()
+  })
+
+  it("resolves theme variables used inside classes", () => {
+    testRoot.renderer.setTheme(JSON.stringify({ surface: "#313244" }))
+    testRoot.renderer.registerStyleClass("themed", JSON.stringify({ backgroundColor: "var(--surface)" }))
+    testRoot.render(<div className="themed" style={{ width: 100, height: 40 }} />)
+
+    expect(testRoot.renderer.findByType("div")[0].style.backgroundColor).toBe("#313244")
+  })
+
+  it("rejects invalid class styles", () => {
+    expect(() => testRoot.renderer.registerStyleClass("bad", "[1]")).toThrow()
+    expect(() => testRoot.renderer.registerStyleClass("two words", "{}")).toThrow()
+    expect(() => testRoot.renderer.registerStyleClass("bad", JSON.stringify({ width: true }))).toThrow()
+  })
+})
@@ -14,7 +14,7 @@ import fs from "fs"
 import { describe, it, expect, beforeEach } from "vitest"
 import React, { useState, useRef } from "react"
 import { createTestRoot, hasNativeTestRenderer } from "../testing"
-import type { EventPayload } from "@gpuix/native"
+import type { EventPayload, EventResponse } from "@gpuix/native"
 import { expectScreenshotsDiffer } from "./test-utils"
 
 // All tests require the native GPUI test renderer (cargo build with test-support).
@@ -120,7 +120,7 @@ describeNative("events", () => {
           <div
             style={{ width: 200, height: 50 }}
             tabIndex={0}
-            onKeyDown={(e: EventPayload) => receivedEvents.push(e)}
+            onKeyDown={(e: EventPayload) => { receivedEvents.push(e) }}
           />
         )
       }
@@ -179,6 +179,319 @@ describeNative("events", () => {
     })
   })
 
+  describe("tooltips", () => {
+    // The tooltip target sits at 0..100 x 100..140 inside a parent that
+    // clips to exactly its bounds, so anything clickable outside that
+    // rectangle must come from the overlay.
+    function Toolbar({ tooltip, onTip, fixed }: {
+      tooltip: (tipId: number | undefined) => any
+      onTip?: () => void
+      fixed?: boolean
+    }) {
+      const tipRef = useRef<any>(null)
+      const [tipId, setTipId] = useState<number>()
+      React.useLayoutEffect(() => setTipId(tipRef.current?.id), [])
+      const layer = fixed ? { position: "fixed" as const, left: 0, top: 0 } : {}
+      return (
+        <div style={{ width: 400, height: 300, paddingTop: 100, ...layer }}>
+          <div style={{ width: 100, height: 40, overflow: "hidden" }}>
+            <div style={{ width: 100, height: 40 }} tooltip={tooltip(tipId)}>
+              <div ref={tipRef} style={{ width: 80, height: 20 }} onClick={onTip} />
+            </div>
+          </div>
+        </div>
+      )
+    }
+
+    it("shows element content below the target, outside overflow: hidden", () => {
+      let clicks = 0
+      testRoot.render(
+        <Toolbar
+          tooltip={(id) => ({ element: id, delay: 0, placement: "bottom" })}
+          onTip={() => clicks++}
+        />,
+      )
+      testRoot.renderer.flush()
+
+      // Not hovered: the child is left out of the target's layout.
+      testRoot.renderer.nativeSimulateClick(40, 110)
+      testRoot.renderer.nativeSimulateClick(50, 150)
+      expect(clicks).toBe(0)
+
+      // Hovered: it is centered 4px below the target (10..90 x 144..164).
+      testRoot.renderer.nativeSimulateMouseMove(50, 120)
+      testRoot.renderer.flush()
+      testRoot.renderer.nativeSimulateClick(50, 150)
+      expect(clicks).toBe(1)
+
+      // Leaving the target hides it again.
+      testRoot.renderer.nativeSimulateMouseMove(300, 250)
+      testRoot.renderer.flush()
+      testRoot.renderer.nativeSimulateClick(50, 150)
+      expect(clicks).toBe(1)
+    })
+
+    it("shows tooltips inside a position: fixed element", () => {
+      let clicks = 0
+      testRoot.render(
+        <Toolbar
+          fixed
+          tooltip={(id) => ({ element: id, delay: 0, placement: "bottom" })}
+          onTip={() => clicks++}
+        />,
+      )
+      testRoot.renderer.flush()
+
+      // The fixed layer is already deferred, so the overlay isn't deferred
+      // again (GPUI panics on nested deferred draws).
+      testRoot.renderer.nativeSimulateMouseMove(50, 120)
+      testRoot.renderer.flush()
+      testRoot.renderer.nativeSimulateClick(50, 150)
+      expect(clicks).toBe(1)
+    })
+
+    it("renders text tooltips", () => {
+      testRoot.render(<Toolbar tooltip={() => ({ text: "Copy", delay: 0, placement: "bottom" })} />)
+      testRoot.renderer.flush()
+      const path0 = "/tmp/gpuix-tooltip-0.png"
+      const path1 = "/tmp/gpuix-tooltip-1.png"
+      if (fs.existsSync(path0)) fs.unlinkSync(path0)
+      if (fs.existsSync(path1)) fs.unlinkSync(path1)
+      testRoot.renderer.captureScreenshot(path0)
+
+      testRoot.renderer.nativeSimulateMouseMove(50, 120)
+      testRoot.renderer.flush()
+      testRoot.renderer.captureScreenshot(path1)
+      expectScreenshotsDiffer(path0, path1)
+    })
+  })
+
+  describe("drag and drop", () => {
+    // Card at 0..100 x 0..40, drop target at 200..300 x 0..100.
+    function Board({ onTarget }: { onTarget: (event: EventPayload) => void }) {
+      return (
+        <div style={{ width: 400, height: 200, flexDirection: "row", gap: 100 }}>
+          <div style={{ width: 100, height: 40 }} draggable dragData={{ card: 7 }} />
+          <div
+            style={{ width: 100, height: 100, dragOver: { backgroundColor: "#a6e3a1" } }}
+            onDragEnter={onTarget}
+            onDragOver={onTarget}
+            onDragLeave={onTarget}
+            onDrop={onTarget}
+          />
+        </div>
+      )
+    }
+
+    it("delivers dragData to the target it is dropped on", () => {
+      const events: EventPayload[] = []
+      testRoot.render(<Board onTarget={(event) => events.push(event)} />)
+
+      testRoot.renderer.nativeSimulateMouseDown(50, 20)
+      // Past GPUI's drag threshold: starts the drag.
+      testRoot.renderer.nativeSimulateMouseMove(60, 20, 0)
+      testRoot.renderer.nativeSimulateMouseMove(250, 50, 0)
+      testRoot.renderer.nativeSimulateMouseMove(260, 50, 0)
+      testRoot.renderer.nativeSimulateMouseUp(260, 50)
+
+      expect(events.map((e) => e.eventType)).toEqual(["dragEnter", "dragOver", "drop"])
+      const drop = events[2]
+      expect(drop.dragData).toEqual({ card: 7 })
+      const card = testRoot.renderer.findByType("div").find((el) => el.customProps?.draggable)
+      expect(drop.dragSource).toBe(card!.id)
+      expect([drop.x, drop.y]).toEqual([260, 50])
+    })
+
+    it("fires dragLeave when the drag moves out or ends elsewhere", () => {
+      const events: string[] = []
+      testRoot.render(<Board onTarget={(event) => events.push(event.eventType)} />)
+
+      testRoot.renderer.nativeSimulateMouseDown(50, 20)
+      testRoot.renderer.nativeSimulateMouseMove(60, 20, 0)
+      testRoot.renderer.nativeSimulateMouseMove(250, 50, 0)
+      testRoot.renderer.nativeSimulateMouseMove(150, 50, 0)
+      expect(events).toEqual(["dragEnter", "dragLeave"])
+
+      testRoot.renderer.nativeSimulateMouseMove(250, 50, 0)
+      // Released outside the target (e.g. cancelled): no drop.
+      testRoot.renderer.nativeSimulateMouseMove(250, 150, 0)
+      testRoot.renderer.nativeSimulateMouseUp(250, 150)
+      expect(events).toEqual(["dragEnter", "dragLeave", "dragEnter", "dragLeave"])
+    })
+
+    it("applies the dragOver style and paints a preview while dragging", () => {
+      testRoot.render(<Board onTarget={() => {}} />)
+      const path0 = "/tmp/gpuix-drag-0.png"
+      const path1 = "/tmp/gpuix-drag-1.png"
+      if (fs.existsSync(path0)) fs.unlinkSync(path0)
+      if (fs.existsSync(path1)) fs.unlinkSync(path1)
+      testRoot.renderer.flush()
+      testRoot.renderer.captureScreenshot(path0)
+
+      testRoot.renderer.nativeSimulateMouseDown(50, 20)
+      testRoot.renderer.nativeSimulateMouseMove(60, 20, 0)
+      testRoot.renderer.nativeSimulateMouseMove(250, 50, 0)
+      testRoot.renderer.captureScreenshot(path1)
+      expectScreenshotsDiffer(path0, path1)
+      testRoot.renderer.nativeSimulateMouseUp(250, 50)
+    })
+  })
+
+  describe("file drop", () => {
+    // Drop zone at 0..200 x 0..100.
+    function DropZone({ onFile }: { onFile: (event: EventPayload) => void }) {
+      return (
+        <div style={{ width: 400, height: 300 }}>
+          <div
+            style={{ width: 200, height: 100, dragOver: { backgroundColor: "#a6e3a1" } }}
+            onFileDragEnter={onFile}
+            onFileDragLeave={onFile}
+            onFileDrop={onFile}
+          />
+        </div>
+      )
+    }
+
+    it("delivers the paths of files dropped onto an element", () => {
+      const events: EventPayload[] = []
+      testRoot.render(<DropZone onFile={(event) => events.push(event)} />)
+
+      const paths = ["/tmp/a.txt", "/tmp/b.png"]
+      testRoot.renderer.nativeSimulateFileDrag(paths, 300, 200)
+      testRoot.renderer.nativeSimulateFileDrag(paths, 50, 50)
+      testRoot.renderer.nativeSimulateFileDrop(50, 50)
+
+      expect(events.map((e) => e.eventType)).toEqual(["fileDragEnter", "fileDrop"])
+      expect(events[1].paths).toEqual(paths)
+      expect([events[1].x, events[1].y]).toEqual([50, 50])
+    })
+
+    it("fires fileDragLeave when the files leave the window", () => {
+      const events: string[] = []
+      testRoot.render(<DropZone onFile={(event) => events.push(event.eventType)} />)
+
+      testRoot.renderer.nativeSimulateFileDrag(["/tmp/a.txt"], 50, 50)
+      testRoot.renderer.nativeSimulateFileDragExit()
+      expect(events).toEqual(["fileDragEnter", "fileDragLeave"])
+    })
+  })
+
+  describe("pointer capture", () => {
+    // Slider track at 50..250 x 50..70.
+    function Slider({ onPointer, disabled }: {
+      onPointer: (event: EventPayload) => void
+      disabled?: boolean
+    }) {
+      return (
+        <div style={{ width: 400, height: 300, padding: 50 }}>
+          <div
+            style={{ width: 200, height: 20 }}
+            capturePointer
+            disabled={disabled}
+            onMouseMove={onPointer}
+            onMouseUp={onPointer}
+          />
+        </div>
+      )
+    }
+
+    it("keeps reporting moves outside the element until release", () => {
+      const events: EventPayload[] = []
+      testRoot.render(<Slider onPointer={(event) => events.push(event)} />)
+
+      testRoot.renderer.nativeSimulateMouseDown(100, 60)
+      testRoot.renderer.nativeSimulateMouseMove(300, 150, 0)
+      testRoot.renderer.nativeSimulateMouseUp(300, 150)
+
+      expect(events.map((e) => e.eventType)).toEqual(["mouseMove", "mouseUp"])
+      expect([events[0].x, events[0].y]).toEqual([300, 150])
+      expect([events[0].localX, events[0].localY]).toEqual([250, 100])
+      expect(events[1].button).toBe(0)
+    })
+
+    it("stops after the release", () => {
+      const events: string[] = []
+      testRoot.render(<Slider onPointer={(event) => events.push(event.eventType)} />)
+
+      testRoot.renderer.nativeSimulateMouseDown(100, 60)
+      testRoot.renderer.nativeSimulateMouseUp(110, 60)
+      testRoot.renderer.nativeSimulateMouseMove(300, 150)
+      expect(events).toEqual(["mouseUp"])
+
+      // Moves over the element still arrive without a capture.
+      testRoot.renderer.nativeSimulateMouseMove(150, 60)
+      expect(events).toEqual(["mouseUp", "mouseMove"])
+    })
+
+    it("releases a capture whose element is disabled mid-press", () => {
+      const events: string[] = []
+      const onPointer = (event: EventPayload) => {
+        events.push(event.eventType)
+      }
+      testRoot.render(<Slider onPointer={onPointer} />)
+
+      testRoot.renderer.nativeSimulateMouseDown(100, 60)
+      testRoot.render(<Slider onPointer={onPointer} disabled />)
+      testRoot.renderer.flush()
+      // Nothing listens for this release while the slider is disabled.
+      testRoot.renderer.nativeSimulateMouseUp(300, 150)
+
+      testRoot.render(<Slider onPointer={onPointer} />)
+      testRoot.renderer.flush()
+      testRoot.renderer.nativeSimulateMouseMove(300, 150, 0)
+      expect(events).toEqual([])
+    })
+  })
+
+  describe("local coordinates", () => {
+    // Target at 40..140 x 30..80.
+    function Target({ onEvent }: { onEvent: (event: EventPayload) => void }) {
+      return (
+        <div style={{ width: 400, height: 300, paddingLeft: 40, paddingTop: 30 }}>
+          <div
+            style={{ width: 100, height: 50 }}
+            onClick={onEvent}
+            onMouseMove={onEvent}
+            onScroll={onEvent}
+          />
+        </div>
+      )
+    }
+
+    it("reports positions relative to the target and its bounds", () => {
+      const events: EventPayload[] = []
+      testRoot.render(<Target onEvent={(event) => events.push(event)} />)
+
+      testRoot.renderer.nativeSimulateClick(60, 50)
+      testRoot.renderer.nativeSimulateMouseMove(130, 70)
+      testRoot.renderer.nativeSimulateScrollWheel(50, 40, 0, -10)
+
+      expect(events.map((e) => e.eventType)).toEqual(["click", "mouseMove", "scroll"])
+      expect(events.map((e) => [e.localX, e.localY])).toEqual([
+        [20, 20],
+        [90, 40],
+        [10, 10],
+      ])
+      for (const event of events) {
+        expect(event.bounds).toEqual({ x: 40, y: 30, width: 100, height: 50 })
+      }
+    })
+
+    it("stamps events with increasing timestamps", () => {
+      const events: EventPayload[] = []
+      testRoot.render(<Target onEvent={(event) => events.push(event)} />)
+
+      testRoot.renderer.nativeSimulateMouseMove(60, 50)
+      testRoot.renderer.nativeSimulateMouseMove(70, 50)
+      testRoot.renderer.nativeSimulateMouseMove(80, 50)
+
+      const timestamps = events.map((e) => e.timestamp)
+      expect(timestamps).toHaveLength(3)
+      expect(timestamps[1]).toBeGreaterThanOrEqual(timestamps[0])
+      expect(timestamps[2]).toBeGreaterThanOrEqual(timestamps[1])
+    })
+  })
+
   describe("mouseDownOutside", () => {
     it("should handle click outside to close pattern", () => {
       function Dropdown() {
@@ -573,7 +886,7 @@ describeNative("events", () => {
         return (
           <div
             style={{ width: 200, height: 200 }}
-            onScroll={(e: EventPayload) => receivedEvents.push(e)}
+            onScroll={(e: EventPayload) => { receivedEvents.push(e) }}
           >
             <text>scrollable</text>
           </div>
@@ -660,8 +973,8 @@ describeNative("events", () => {
           <div
             style={{ width: 200, height: 50 }}
             tabIndex={0}
-            onKeyDown={(e: EventPayload) => events.push(`down:${e.key}`)}
-            onKeyUp={(e: EventPayload) => events.push(`up:${e.key}`)}
+            onKeyDown={(e: EventPayload) => { events.push(`down:${e.key}`) }}
+            onKeyUp={(e: EventPayload) => { events.push(`up:${e.key}`) }}
           />
         )
       }
@@ -678,6 +991,45 @@ describeNative("events", () => {
       expect(events).toContain("up:enter")
     })
 
+    it("should report the physical key in code", () => {
+      const codes: string[] = []
+      testRoot.render(
+        <div
+          style={{ width: 200, height: 50 }}
+          tabIndex={0}
+          onKeyDown={(e: EventPayload) => { codes.push(e.code ?? "none") }}
+        />
+      )
+      const div = testRoot.renderer
+        .findByType("div")
+        .find((d) => d.events.has("keyDown"))!
+
+      for (const key of ["w", "shift-a", "1", "space", "down", "/"]) {
+        testRoot.renderer.nativeSimulateKeyDown(div.id, key)
+      }
+      expect(codes).toEqual(["KeyW", "KeyA", "Digit1", "Space", "ArrowDown", "Slash"])
+    })
+
+    it("should use DOM key names after setKeyNames('dom')", () => {
+      const keys: string[] = []
+      testRoot.render(
+        <div
+          style={{ width: 200, height: 50 }}
+          tabIndex={0}
+          onKeyDown={(e: EventPayload) => { keys.push(e.key ?? "none") }}
+        />
+      )
+      testRoot.renderer.setKeyNames("dom")
+      const div = testRoot.renderer
+        .findByType("div")
+        .find((d) => d.events.has("keyDown"))!
+
+      for (const key of ["enter", "down", "escape", "a", "ctrl-shift-k", "space", "f5"]) {
+        testRoot.renderer.nativeSimulateKeyDown(div.id, key)
+      }
+      expect(keys).toEqual(["Enter", "ArrowDown", "Escape", "a", "K", " ", "F5"])
+    })
+
     it("should handle onKeyUp state update", () => {
       function KeyUpStateTracker() {
         const [lastKey, setLastKey] = useState("none")
@@ -756,7 +1108,7 @@ describeNative("events", () => {
         return (
           <div
             style={{ width: 200, height: 100 }}
-            onMouseDown={(e: EventPayload) => receivedEvents.push(e)}
+            onMouseDown={(e: EventPayload) => { receivedEvents.push(e) }}
           />
         )
       }
@@ -777,6 +1129,151 @@ describeNative("events", () => {
     })
   })
 
+  describe("key bindings", () => {
+    function Workspace({
+      onRoot,
+      onEditor,
+    }: {
+      onRoot: (event: EventPayload) => void | EventResponse
+      onEditor: (event: EventPayload) => void | EventResponse
+    }) {
+      return (
+        <div style={{ width: 300, height: 200 }} onAction={onRoot}>
+          <div
+            style={{ width: 200, height: 50 }}
+            tabIndex={0}
+            keyContext="Editor"
+            onAction={onEditor}
+          />
+        </div>
+      )
+    }
+
+    const bindings = JSON.stringify([
+      { keys: "ctrl-k ctrl-s", action: "save", context: "Editor" },
+      { keys: "ctrl-p", action: "palette" },
+    ])
+
+    it("dispatches matched actions to the focused element's onAction", () => {
+      const root: string[] = []
+      const editor: string[] = []
+      testRoot.render(
+        <Workspace
+          onRoot={(e) => { root.push(e.action!) }}
+          onEditor={(e) => { editor.push(e.action!) }}
+        />
+      )
+      testRoot.renderer.registerKeyBindings(bindings)
+      const target = testRoot.renderer
+        .findByType("div")
+        .find((d) => d.customProps?.keyContext === "Editor")!
+
+      testRoot.renderer.nativeSimulateKeystrokes(target.id, "ctrl-k ctrl-s")
+      testRoot.renderer.nativeSimulateKeystrokes(target.id, "ctrl-p")
+      expect(editor).toEqual(["save", "palette"])
+      // Actions bubble to outer onAction elements.
+      expect(root).toEqual(["save", "palette"])
+    })
+
+    it("stops an action when a synchronous handler returns stopPropagation", () => {
+      const root: string[] = []
+      testRoot.renderer.setSyncEvents(true)
+      testRoot.render(
+        <Workspace
+          onRoot={(e) => { root.push(e.action!) }}
+          onEditor={(e) => (e.action === "save" ? { stopPropagation: true } : undefined)}
+        />
+      )
+      testRoot.renderer.registerKeyBindings(bindings)
+      const target = testRoot.renderer
+        .findByType("div")
+        .find((d) => d.customProps?.keyContext === "Editor")!
+
+      testRoot.renderer.nativeSimulateKeystrokes(target.id, "ctrl-k ctrl-s")
+      testRoot.renderer.nativeSimulateKeystrokes(target.id, "ctrl-p")
+      expect(root).toEqual(["palette"])
+    })
+
+    it("sends global bindings to the root element when nothing is focused", () => {
+      const root: string[] = []
+      testRoot.render(<Workspace onRoot={(e) => { root.push(e.action!) }} onEditor={() => {}} />)
+      testRoot.renderer.registerKeyBindings(bindings)
+
+      testRoot.renderer.nativeSimulateGlobalKeystrokes("ctrl-p")
+      // Outside the Editor context.
+      testRoot.renderer.nativeSimulateGlobalKeystrokes("ctrl-k ctrl-s")
+      expect(root).toEqual(["palette"])
+    })
+
+    it("rejects invalid bindings", () => {
+      testRoot.render(<Workspace onRoot={() => {}} onEditor={() => {}} />)
+      expect(() =>
+        testRoot.renderer.registerKeyBindings(
+          JSON.stringify([{ keys: "ctrl-s", action: "save", context: "Editor &&" }])
+        )
+      ).toThrow(/Invalid key context/)
+    })
+  })
+
+  describe("synchronous dispatch", () => {
+    function Nested({ stop, log }: { stop: boolean; log: string[] }) {
+      return (
+        <div style={{ width: 300, height: 300 }} onMouseDown={() => { log.push("outer") }}>
+          <div
+            style={{ width: 100, height: 100 }}
+            onMouseDown={() => {
+              log.push("inner")
+              return { stopPropagation: stop }
+            }}
+          />
+        </div>
+      )
+    }
+
+    it("stops propagation when a handler returns stopPropagation", () => {
+      const log: string[] = []
+      testRoot.renderer.setSyncEvents(true)
+      testRoot.render(<Nested stop log={log} />)
+
+      testRoot.renderer.nativeSimulateMouseDown(50, 50)
+      expect(log).toEqual(["inner"])
+    })
+
+    it("ignores the return value of queued handlers", () => {
+      const log: string[] = []
+      testRoot.render(<Nested stop log={log} />)
+
+      testRoot.renderer.nativeSimulateMouseDown(50, 50)
+      expect(log).toEqual(["inner", "outer"])
+    })
+
+    it("keeps a focusable element from taking focus with preventDefault", () => {
+      const keys: string[] = []
+      function Focusable({ prevent }: { prevent: boolean }) {
+        return (
+          <div
+            style={{ width: 100, height: 100 }}
+            tabIndex={0}
+            onMouseDown={() => ({ preventDefault: prevent })}
+            onKeyDown={(e) => {
+              keys.push(e.key!)
+            }}
+          />
+        )
+      }
+      testRoot.renderer.setSyncEvents(true)
+      testRoot.render(<Focusable prevent />)
+      testRoot.renderer.nativeSimulateClick(50, 50)
+      testRoot.renderer.nativeSimulateGlobalKeystrokes("a")
+      expect(keys).toEqual([])
+
+      testRoot.render(<Focusable prevent={false} />)
+      testRoot.renderer.nativeSimulateClick(50, 50)
+      testRoot.renderer.nativeSimulateGlobalKeystrokes("b")
+      expect(keys).toEqual(["b"])
+    })
+  })
+
   describe("mouseMove events", () => {
     it("should handle onMouseMove and receive exact position", () => {
       const receivedEvents: EventPayload[] = []
@@ -785,7 +1282,7 @@ describeNative("events", () => {
         return (
           <div
             style={{ width: 300, height: 300 }}
-            onMouseMove={(e: EventPayload) => receivedEvents.push(e)}
+            onMouseMove={(e: EventPayload) => { receivedEvents.push(e) }}
           />
         )
       }
@@ -810,7 +1307,7 @@ describeNative("events", () => {
         return (
           <div
             style={{ width: 300, height: 300 }}
-            onMouseMove={(e: EventPayload) => receivedEvents.push(e)}
+            onMouseMove={(e: EventPayload) => { receivedEvents.push(e) }}
           />
         )
       }
@@ -1295,6 +1792,24 @@ describeNative("events", () => {
       expect(offset![1]).toBeLessThan(0) // scrolled down to reveal item
     })
 
+    it("should count only real children in scrollToItem on a container with listeners", () => {
+      testRoot.render(
+        <div style={{ width: 200, height: 100, overflow: "scroll" }} onScroll={() => {}} onClick={() => {}}>
+          <div style={{ height: 80 }} />
+          <div style={{ height: 80 }} />
+          <div style={{ height: 80 }} />
+        </div>
+      )
+
+      const container = testRoot.renderer
+        .findByType("div")
+        .find((d) => d.style.overflow === "scroll")!
+
+      // Index 1 is the second child (80..160), partly below the viewport.
+      testRoot.renderer.scrollToItem(container.id, 1)
+      expect(testRoot.renderer.getScrollOffset(container.id)![1]).toBeLessThan(0)
+    })
+
     it("should render scrollable container with visible screenshot diff", () => {
       function ScreenshotScroller() {
         return (
@@ -1372,7 +1887,7 @@ describeNative("events", () => {
         return (
           <div
             style={{ width: 300, height: 100, overflow: "scroll" }}
-            onScroll={(e: EventPayload) => receivedScrollEvents.push(e)}
+            onScroll={(e: EventPayload) => { receivedScrollEvents.push(e) }}
           >
             <div style={{ height: 500 }}>
               <text>Scrollable with events</text>
@@ -10,7 +10,7 @@ import fs from "fs"
 import { describe, it, expect, beforeEach } from "vitest"
 import React from "react"
 import { createTestRoot, hasNativeTestRenderer } from "../testing"
-import { bufferSimilarity, isCI } from "./test-utils"
+import { bufferSimilarity, expectScreenshotsDiffer, isCI } from "./test-utils"
 
 const describeNative = hasNativeTestRenderer ? describe : describe.skip
 
@@ -1652,6 +1652,124 @@ describeNative("style properties", () => {
     })
   })
 
+  // ── focus / disabled / groupHover pseudo-styles ─────────────────
+
+  describe("focus, disabled and groupHover styles", () => {
+    it("suppresses events on disabled elements until re-enabled", () => {
+      const clicks: string[] = []
+      function Button({ disabled }: { disabled: boolean }) {
+        return (
+          <div
+            disabled={disabled}
+            style={{
+              width: 160,
+              height: 40,
+              backgroundColor: "#89b4fa",
+              hover: { backgroundColor: "#b4befe" },
+              disabled: { backgroundColor: "#45475a", opacity: 0.6 },
+            }}
+            onClick={() => { clicks.push("click") }}
+          />
+        )
+      }
+
+      testRoot.render(<Button disabled />)
+      const [button] = testRoot.renderer.findByType("div")
+      expect(button.customProps).toEqual({ disabled: true })
+      testRoot.renderer.nativeSimulateClick(20, 20)
+      expect(clicks).toEqual([])
+
+      testRoot.render(<Button disabled={false} />)
+      testRoot.renderer.nativeSimulateClick(20, 20)
+      expect(clicks).toEqual(["click"])
+    })
+
+    it("disables the descendants of a disabled element", () => {
+      const clicks: string[] = []
+      function Form({ disabled }: { disabled: boolean }) {
+        return (
+          <div disabled={disabled} style={{ width: 200, height: 100, padding: 10 }}>
+            <div
+              style={{ width: 160, height: 40, backgroundColor: "#89b4fa" }}
+              onClick={() => { clicks.push("click") }}
+            />
+          </div>
+        )
+      }
+
+      testRoot.render(<Form disabled />)
+      testRoot.renderer.nativeSimulateClick(20, 20)
+      expect(clicks).toEqual([])
+
+      testRoot.render(<Form disabled={false} />)
+      testRoot.renderer.nativeSimulateClick(20, 20)
+      expect(clicks).toEqual(["click"])
+    })
+
+    it("applies groupHover styles when the group ancestor is hovered", () => {
+      function Card() {
+        return (
+          <div group="card" style={{ width: 300, height: 120, padding: 16, backgroundColor: "#1e1e2e" }}>
+            <div
+              style={{
+                width: 80,
+                height: 30,
+                backgroundColor: "#1e1e2e",
+                groupHover: { backgroundColor: "#f38ba8" },
+              }}
+            />
+          </div>
+        )
+      }
+
+      testRoot.render(<Card />)
+      testRoot.renderer.nativeSimulateMouseMove(600, 600)
+      const pathBefore = `${SCREENSHOT_DIR}/gpuix-group-hover-before.png`
+      if (fs.existsSync(pathBefore)) fs.unlinkSync(pathBefore)
+      testRoot.renderer.captureScreenshot(pathBefore)
+
+      // Over the card but not over the child.
+      testRoot.renderer.nativeSimulateMouseMove(250, 100)
+      testRoot.renderer.flush()
+      const pathAfter = `${SCREENSHOT_DIR}/gpuix-group-hover-after.png`
+      if (fs.existsSync(pathAfter)) fs.unlinkSync(pathAfter)
+      testRoot.renderer.captureScreenshot(pathAfter)
+      expect(fs.existsSync(pathAfter)).toBe(true)
+
+      if (!isCI) {
+        const beforeBytes = fs.readFileSync(pathBefore)
+        const afterBytes = fs.readFileSync(pathAfter)
+        expect(bufferSimilarity(beforeBytes, afterBytes)).toBeLessThan(0.99)
+      }
+    })
+
+    it("makes elements with a focus style focusable", () => {
+      testRoot.render(
+        <Center>
+          <div
+            style={{
+              width: 200,
+              height: 40,
+              backgroundColor: "#313244",
+              focus: { borderWidth: 1, borderColor: "#89b4fa" },
+              focusVisible: { borderWidth: 2, borderColor: "#f9e2af" },
+            }}
+          />
+        </Center>
+      )
+      const [, input] = testRoot.renderer.findByType("div")
+      // Keyboard focus: focus and focusVisible styles both apply.
+      testRoot.renderer.nativeSimulateKeystrokes(input.id, "tab")
+      testRoot.renderer.flush()
+
+      const path = `${SCREENSHOT_DIR}/gpuix-focus-visible.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+      expect(input.style.focusVisible).toEqual({ borderWidth: 2, borderColor: "#f9e2af" })
+    })
+  })
+
   // ── pre-like behavior composite ─────────────────────────────────
 
   describe("pre-like behavior", () => {
@@ -1712,4 +1830,660 @@ describeNative("style properties", () => {
       expect(fs.statSync(path).size).toBeGreaterThan(0)
     })
   })
+
+  describe("text styling", () => {
+    it("renders italic, decorations, alignment, line height and transform", () => {
+      function TextStylingTest() {
+        return (
+          <Center>
+            <div
+              style={{
+                display: "flex",
+                flexDirection: "column",
+                width: 360,
+                backgroundColor: "#1e1e2e",
+                padding: 12,
+                borderRadius: 8,
+                gap: 6,
+                color: "#cdd6f4",
+              }}
+            >
+              <text style={{ fontStyle: "italic" }}>italic text</text>
+              <text style={{ textDecoration: "underline" }}>underlined</text>
+              <text style={{ textDecoration: "underline wavy #f38ba8" }}>wavy red underline</text>
+              <text style={{ textDecoration: "line-through" }}>struck through</text>
+              <text style={{ textDecoration: "underline dotted overline #89b4fa" }}>dotted and overline skipped</text>
+              <text style={{ textAlign: "center" }}>centered</text>
+              <text style={{ textAlign: "right" }}>right aligned</text>
+              <text style={{ lineHeight: "200%" }}>double line height</text>
+              <text style={{ lineHeight: 28 }}>28px line height</text>
+              <text style={{ textTransform: "uppercase" }}>uppercase</text>
+              <text style={{ textTransform: "capitalize" }}>capitalize every word</text>
+              <text>
+                mixed{" "}
+                <text style={{ fontStyle: "italic", textDecoration: "underline", textDecorationColor: "#a6e3a1" }}>
+                  inline span
+                </text>
+              </text>
+            </div>
+          </Center>
+        )
+      }
+
+      testRoot.render(<TextStylingTest />)
+
+      // Content is stored untransformed; textTransform is applied at render.
+      expect(testRoot.renderer.getAllText()).toContain("uppercase")
+      expect(testRoot.renderer.getAllText()).toContain("double line height")
+
+      const path = `${SCREENSHOT_DIR}/gpuix-text-styling.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+      expect(fs.statSync(path).size).toBeGreaterThan(0)
+    })
+
+    it("accepts text styles on a div and cascades them", () => {
+      testRoot.render(
+        <div style={{ width: 300, height: 100, color: "#cdd6f4", fontStyle: "italic", textAlign: "center", lineHeight: "150%" }}>
+          <text>inherits italic and centered</text>
+        </div>
+      )
+
+      const [div] = testRoot.renderer.findByType("div")
+      expect(div.style.fontStyle).toBe("italic")
+      expect(div.style.textAlign).toBe("center")
+      expect(testRoot.renderer.getAllText()).toEqual(["inherits italic and centered"])
+    })
+  })
+
+  describe("grid", () => {
+    it("lays out a grid with spans and justifyItems", () => {
+      function GridTest() {
+        const cell = { backgroundColor: "#313244", padding: 8, borderRadius: 4 }
+        return (
+          <Center>
+            <div
+              style={{
+                display: "grid",
+                gridTemplateColumns: "repeat(3, 1fr)",
+                gridTemplateRows: 3,
+                gap: 8,
+                width: 360,
+                height: 200,
+                padding: 12,
+                backgroundColor: "#1e1e2e",
+                justifyItems: "center",
+                color: "#cdd6f4",
+              }}
+            >
+              <div style={{ ...cell, gridColumn: "span 3" }}>
+                <text>header spans 3</text>
+              </div>
+              <div style={cell}>
+                <text>a</text>
+              </div>
+              <div style={{ ...cell, gridColumn: "2 / 4", gridRow: "span 2" }}>
+                <text>2 / 4, two rows</text>
+              </div>
+              <div style={cell}>
+                <text>b</text>
+              </div>
+            </div>
+          </Center>
+        )
+      }
+
+      testRoot.render(<GridTest />)
+
+      expect(testRoot.renderer.getAllText()).toMatchInlineSnapshot(`
+        [
+          "header spans 3",
+          "a",
+          "2 / 4, two rows",
+          "b",
+        ]
+      `)
+
+      const path = `${SCREENSHOT_DIR}/gpuix-grid.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+      expect(fs.statSync(path).size).toBeGreaterThan(0)
+    })
+
+    it("parses grid templates and lines into normalized values", () => {
+      testRoot.render(
+        <div style={{ display: "grid", gridTemplateColumns: "1fr 1fr 1fr 1fr", gridTemplateRows: "repeat(2, minmax(0, 1fr))" }}>
+          <div style={{ gridColumn: "1 / span 2", gridRow: 2 }} />
+        </div>
+      )
+
+      const [grid, item] = testRoot.renderer.findByType("div")
+      expect(grid.style.gridTemplateColumns).toBe(4)
+      expect(grid.style.gridTemplateRows).toBe(2)
+      expect(item.style.gridColumn).toEqual({ start: 1, end: null, span: 2 })
+      expect(item.style.gridRow).toEqual({ start: 2, end: null, span: null })
+    })
+
+    it("falls back to equal tracks for sizes GPUI can't lay out", () => {
+      const templates = ["200px 1fr", "repeat(2, 40px)", "1fr 2fr", "auto auto"]
+      testRoot.render(
+        <div style={{ width: 400, height: 200 }}>
+          {templates.map((template) => (
+            <div key={template} style={{ display: "grid", gridTemplateColumns: template, height: 40 }}>
+              <div style={{ height: 20 }} />
+              <div style={{ height: 20 }} />
+            </div>
+          ))}
+        </div>
+      )
+
+      // The rest of the batch still applies: every grid and item exists.
+      const grids = testRoot.renderer.findByType("div").filter((d) => d.style.display === "grid")
+      expect(grids.map((g) => g.style.gridTemplateColumns)).toEqual([2, 2, 2, 2])
+      expect(testRoot.renderer.findByType("div").length).toBe(1 + templates.length * 3)
+    })
+
+    it("rejects grid lines that aren't whole non-zero numbers", () => {
+      testRoot.render(<div style={{ display: "grid" }} />)
+      const [grid] = testRoot.renderer.findByType("div")
+      for (const line of [1.5, 0, 40000]) {
+        expect(() => testRoot.renderer.setStyle(grid.id, JSON.stringify({ gridColumn: line }))).toThrow(/grid line/)
+      }
+    })
+  })
+
+  describe("color syntax", () => {
+    it("renders hsl, named, transparent, modern rgb and currentColor", () => {
+      const swatch = { width: 40, height: 40, borderRadius: 4 }
+      function ColorTest() {
+        return (
+          <Center>
+            <div
+              style={{
+                display: "flex",
+                flexDirection: "row",
+                flexWrap: "wrap",
+                gap: 8,
+                width: 320,
+                padding: 12,
+                backgroundColor: "hsl(240 21% 15%)",
+                color: "rebeccapurple",
+              }}
+            >
+              <div style={{ ...swatch, backgroundColor: "hsl(343, 81%, 75%)" }} />
+              <div style={{ ...swatch, backgroundColor: "hsla(115deg 54% 76% / 0.5)" }} />
+              <div style={{ ...swatch, backgroundColor: "cornflowerblue" }} />
+              <div style={{ ...swatch, backgroundColor: "rgb(255 0 0 / 50%)" }} />
+              <div style={{ ...swatch, backgroundColor: "rgb(100%, 50%, 0%)" }} />
+              <div style={{ ...swatch, backgroundColor: "#f5c2e780" }} />
+              <div style={{ ...swatch, backgroundColor: "#fa0c" }} />
+              <div style={{ ...swatch, backgroundColor: "transparent", borderWidth: 2, borderColor: "currentColor" }} />
+              <div style={{ ...swatch, color: "gold", backgroundColor: "currentColor" }} />
+              <text style={{ color: "MediumSeaGreen" }}>case-insensitive names</text>
+            </div>
+          </Center>
+        )
+      }
+
+      testRoot.render(<ColorTest />)
+
+      // Unsupported values are ignored (with a logged warning), never
+      // rejected, so the rest of the tree still renders.
+      expect(testRoot.renderer.findByType("div").length).toBe(11)
+
+      const path = `${SCREENSHOT_DIR}/gpuix-color-syntax.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+      expect(fs.statSync(path).size).toBeGreaterThan(0)
+    })
+  })
+
+  describe("box shadow", () => {
+    it("renders CSS string and object shadows", () => {
+      function ShadowTest() {
+        return (
+          <Center>
+            <div style={{ display: "flex", flexDirection: "row", gap: 32, padding: 32, backgroundColor: "#eff1f5" }}>
+              <div
+                style={{
+                  width: 80,
+                  height: 80,
+                  borderRadius: 8,
+                  backgroundColor: "#ffffff",
+                  boxShadow: "0 2px 8px rgba(0, 0, 0, 0.3), 0 0 0 1px #4c4f6933",
+                }}
+              />
+              <div
+                style={{
+                  width: 80,
+                  height: 80,
+                  borderRadius: 8,
+                  backgroundColor: "#ffffff",
+                  boxShadow: [{ offsetY: 12, blur: 24, spread: -4, color: "hsl(220 60% 40% / 40%)" }],
+                  hover: { boxShadow: "0 16px 32px #1e66f566" },
+                }}
+              />
+              <div style={{ width: 80, height: 80, color: "#d20f39", backgroundColor: "#ffffff", boxShadow: "4px 4px" }} />
+            </div>
+          </Center>
+        )
+      }
+
+      testRoot.render(<ShadowTest />)
+
+      const [, first, second, third] = testRoot.renderer.findByType("div")
+      expect(first.style.boxShadow).toEqual([
+        { offsetX: 0, offsetY: 2, blur: 8, spread: 0, color: "rgba(0, 0, 0, 0.3)", inset: false },
+        { offsetX: 0, offsetY: 0, blur: 0, spread: 1, color: "#4c4f6933", inset: false },
+      ])
+      expect(second.style.boxShadow[0].spread).toBe(-4)
+      // Omitted color defaults to currentColor, resolved from the text color at render time.
+      expect(third.style.boxShadow[0].color).toBe("currentColor")
+
+      const path = `${SCREENSHOT_DIR}/gpuix-box-shadow.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+    })
+
+    it("paints the first shadow on top, as in CSS", () => {
+      const box = { width: 80, height: 80, margin: 40, backgroundColor: "#ffffff" }
+      const capture = (boxShadow: string, name: string) => {
+        testRoot.render(<div style={{ ...box, boxShadow }} />)
+        const path = `${SCREENSHOT_DIR}/gpuix-box-shadow-order-${name}.png`
+        testRoot.renderer.captureScreenshot(path)
+        return fs.readFileSync(path)
+      }
+
+      // Two identical shadows: only the top one shows.
+      const stacked = capture("0 0 0 12px #d20f39, 0 0 0 12px #1e66f5", "stacked")
+      const red = capture("0 0 0 12px #d20f39", "red")
+      const blue = capture("0 0 0 12px #1e66f5", "blue")
+      if (!isCI) {
+        expect(bufferSimilarity(stacked, red)).toBe(1)
+        expect(bufferSimilarity(stacked, blue)).toBeLessThan(0.99)
+      }
+    })
+  })
+
+  describe("linear gradient", () => {
+    it("renders two-stop gradients and skips unsupported stop counts", () => {
+      const swatch = { width: 80, height: 80, borderRadius: 8 }
+      function GradientTest() {
+        return (
+          <Center>
+            <div style={{ display: "flex", flexDirection: "row", gap: 12 }}>
+              <div style={{ ...swatch, background: "linear-gradient(135deg, #89b4fa 0%, #cba6f7 100%)" }} />
+              <div style={{ ...swatch, background: "linear-gradient(to right, hsl(0 80% 60%), gold 80%)" }} />
+              <div style={{ ...swatch, background: "linear-gradient(red, green, blue)", borderWidth: 1, borderColor: "#585b70" }} />
+              <div style={{ ...swatch, background: "#a6e3a1" }} />
+            </div>
+          </Center>
+        )
+      }
+
+      testRoot.render(<GradientTest />)
+
+      // The three-stop gradient is rejected natively (logged) but the
+      // element and its siblings still render.
+      expect(testRoot.renderer.findByType("div").length).toBe(6)
+
+      const path = `${SCREENSHOT_DIR}/gpuix-linear-gradient.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+    })
+  })
+
+  describe("borders", () => {
+    it("renders per-side widths, shorthands, dashed style and corner radii", () => {
+      const box = { width: 80, height: 80, backgroundColor: "#313244" }
+      function BorderTest() {
+        return (
+          <Center>
+            <div style={{ display: "flex", flexDirection: "row", gap: 16 }}>
+              <div style={{ ...box, border: "2px solid #89b4fa" }} />
+              <div style={{ ...box, borderBottom: "4px solid #f38ba8" }} />
+              <div style={{ ...box, borderLeftWidth: 6, borderColor: "#a6e3a1" }} />
+              <div style={{ ...box, border: "2px dashed #f9e2af", borderTopLeftRadius: 16, borderBottomRightRadius: 16 }} />
+              <div style={{ ...box, border: "3px solid #cba6f7", borderStyle: "none" }} />
+            </div>
+          </Center>
+        )
+      }
+
+      testRoot.render(<BorderTest />)
+
+      const divs = testRoot.renderer.findByType("div")
+      expect(divs[2].style.border).toBe("2px solid #89b4fa")
+      expect(divs[3].style.borderBottom).toBe("4px solid #f38ba8")
+      expect(divs[4].style.borderLeftWidth).toBe(6)
+      expect(divs[5].style.borderTopLeftRadius).toBe(16)
+
+      const path = `${SCREENSHOT_DIR}/gpuix-borders.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+    })
+
+    it("paints each side in its own color", () => {
+      function Box({ top }: { top: string }) {
+        return (
+          <Center>
+            <div style={{ width: 80, height: 80, border: "6px solid #a6e3a1", borderTopColor: top }} />
+          </Center>
+        )
+      }
+      const uniform = `${SCREENSHOT_DIR}/gpuix-border-colors-0.png`
+      const mixed = `${SCREENSHOT_DIR}/gpuix-border-colors-1.png`
+      for (const path of [uniform, mixed]) if (fs.existsSync(path)) fs.unlinkSync(path)
+
+      // Before per-side colors, the top color was painted on every side.
+      testRoot.render(<Box top="#f38ba8" />)
+      testRoot.renderer.captureScreenshot(mixed)
+      testRoot.render(
+        <Center>
+          <div style={{ width: 80, height: 80, border: "6px solid #f38ba8" }} />
+        </Center>
+      )
+      testRoot.renderer.captureScreenshot(uniform)
+      expectScreenshotsDiffer(uniform, mixed)
+    })
+
+    it("paints sides with a width but no color in the text color", () => {
+      const box = { width: 80, height: 80 }
+      const shot = (style: Record<string, unknown>, path: string) => {
+        if (fs.existsSync(path)) fs.unlinkSync(path)
+        testRoot.render(
+          <Center>
+            <div style={{ ...box, ...style }} />
+          </Center>
+        )
+        testRoot.renderer.captureScreenshot(path)
+        return fs.readFileSync(path)
+      }
+
+      const colorless = shot(
+        { color: "#f38ba8", borderWidth: 6, borderTopColor: "#a6e3a1" },
+        `${SCREENSHOT_DIR}/gpuix-border-current-color-0.png`
+      )
+      const explicit = shot(
+        { border: "6px solid #f38ba8", borderTopColor: "#a6e3a1" },
+        `${SCREENSHOT_DIR}/gpuix-border-current-color-1.png`
+      )
+      if (!isCI) expect(bufferSimilarity(colorless, explicit)).toBe(1)
+    })
+
+    it("follows hover border colors when the sides differ", () => {
+      const box = { width: 80, height: 80 }
+      testRoot.render(
+        <Center>
+          <div style={{ ...box, border: "6px solid #a6e3a1", borderTopColor: "#f38ba8", hover: { borderColor: "#89b4fa" } }} />
+        </Center>
+      )
+      const before = `${SCREENSHOT_DIR}/gpuix-border-hover-before.png`
+      const after = `${SCREENSHOT_DIR}/gpuix-border-hover-after.png`
+      const expected = `${SCREENSHOT_DIR}/gpuix-border-hover-expected.png`
+      for (const path of [before, after, expected]) if (fs.existsSync(path)) fs.unlinkSync(path)
+
+      testRoot.renderer.nativeSimulateMouseMove(10, 10)
+      testRoot.renderer.captureScreenshot(before)
+      // The 80x80 box is centered in the 1280x800 window.
+      testRoot.renderer.nativeSimulateMouseMove(640, 400)
+      testRoot.renderer.flush()
+      testRoot.renderer.captureScreenshot(after)
+      expectScreenshotsDiffer(before, after)
+
+      testRoot.render(
+        <Center>
+          <div style={{ ...box, border: "6px solid #89b4fa" }} />
+        </Center>
+      )
+      testRoot.renderer.captureScreenshot(expected)
+      if (!isCI) {
+        expect(bufferSimilarity(fs.readFileSync(after), fs.readFileSync(expected))).toBe(1)
+      }
+    })
+  })
+
+  describe("display, visibility and pointerEvents", () => {
+    it("hides elements without dropping them from the retained tree", () => {
+      function Panels({ open }: { open: boolean }) {
+        return (
+          <div style={{ display: "flex", flexDirection: "row", gap: 8, width: 400, height: 100 }}>
+            <div style={{ width: 100, height: 100, backgroundColor: "#89b4fa", display: open ? "flex" : "none" }}>
+              <text>panel</text>
+            </div>
+            <div style={{ width: 100, height: 100, backgroundColor: "#f38ba8", visibility: "hidden" }} />
+            <div style={{ width: 100, height: 100, backgroundColor: "#a6e3a1", display: "block" }} />
+          </div>
+        )
+      }
+
+      testRoot.render(<Panels open={false} />)
+      const [, hidden, invisible, block] = testRoot.renderer.findByType("div")
+      expect(hidden.style.display).toBe("none")
+      expect(invisible.style.visibility).toBe("hidden")
+      expect(block.style.display).toBe("block")
+      // The hidden panel's subtree stays mounted.
+      expect(testRoot.renderer.getAllText()).toEqual(["panel"])
+
+      const path = `${SCREENSHOT_DIR}/gpuix-display-none.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+
+      testRoot.render(<Panels open />)
+      expect(testRoot.renderer.findByType("div")[1].style.display).toBe("flex")
+    })
+
+    it("lets clicks pass through pointerEvents: none", () => {
+      const clicks: string[] = []
+      testRoot.render(
+        <div style={{ width: 200, height: 200 }} onClick={() => { clicks.push("base") }}>
+          <div style={{ width: 200, height: 200, pointerEvents: "none" }} onClick={() => { clicks.push("overlay") }}>
+            <div style={{ width: 50, height: 50 }} onClick={() => { clicks.push("inherited") }} />
+          </div>
+        </div>
+      )
+
+      testRoot.renderer.nativeSimulateClick(10, 10)
+      expect(clicks).toEqual(["base"])
+    })
+  })
+
+  describe("relative units", () => {
+    it("accepts em, rem, vw, vh, calc() and auto margins", () => {
+      function Units() {
+        return (
+          <div style={{ width: "100vw", height: "100vh", fontSize: 20, backgroundColor: "#11111b" }}>
+            <div style={{ width: "calc(100% - 24px)", height: "5em", padding: "5%", backgroundColor: "#313244" }}>
+              <text style={{ fontSize: "0.8rem", color: "#cdd6f4" }}>relative</text>
+            </div>
+            <div style={{ width: "10vw", height: "2rem", marginLeft: "auto", marginRight: "auto", backgroundColor: "#89b4fa" }} />
+            <div style={{ width: "calc(2 * 1rem + 4px)", height: "calc((50% - 1em) / 2)", backgroundColor: "#f38ba8" }} />
+          </div>
+        )
+      }
+
+      testRoot.render(<Units />)
+
+      const [root, box, centered, calc] = testRoot.renderer.findByType("div")
+      expect(root.style.width).toBe("100vw")
+      expect(box.style.width).toBe("calc(100% - 24px)")
+      expect(box.style.height).toBe("5em")
+      expect(box.style.padding).toBe("5%")
+      expect(centered.style.marginLeft).toBe("auto")
+      expect(calc.style.width).toBe("calc(4px + 2rem)")
+      expect(calc.style.height).toBe("calc(25% - 0.5em)")
+
+      // calc() with a percentage settles once the parent has been measured.
+      testRoot.renderer.flush()
+      const path = `${SCREENSHOT_DIR}/gpuix-relative-units.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+    })
+
+    it("resolves relative border widths and radii", () => {
+      const box = { width: 80, height: 80 }
+      const shot = (style: Record<string, unknown>, path: string) => {
+        if (fs.existsSync(path)) fs.unlinkSync(path)
+        testRoot.render(
+          <Center>
+            <div style={{ ...box, borderColor: "#89b4fa", backgroundColor: "#313244", ...style }} />
+          </Center>
+        )
+        testRoot.renderer.captureScreenshot(path)
+        return fs.readFileSync(path)
+      }
+
+      const relative = shot(
+        { borderWidth: "0.25rem", borderRadius: "0.5rem", borderTopLeftRadius: "calc(1rem + 4px)" },
+        `${SCREENSHOT_DIR}/gpuix-border-units-0.png`
+      )
+      const [div] = testRoot.renderer.findByType("div").slice(-1)
+      expect(div.style.borderRadius).toBe("0.5rem")
+      const pixels = shot(
+        { borderWidth: 4, borderRadius: 8, borderTopLeftRadius: 20 },
+        `${SCREENSHOT_DIR}/gpuix-border-units-1.png`
+      )
+      if (!isCI) expect(bufferSimilarity(relative, pixels)).toBe(1)
+    })
+  })
+
+  describe("stacking", () => {
+    it("layers zIndex and pins position: fixed to the window", () => {
+      const clicks: string[] = []
+      testRoot.render(
+        <div style={{ width: 400, height: 300, overflow: "scroll", backgroundColor: "#11111b" }}>
+          <div style={{ position: "relative", width: 200, height: 120 }}>
+            <div style={{ position: "absolute", top: 0, left: 0, width: 120, height: 80, backgroundColor: "#f38ba8", zIndex: 2 }} onClick={() => { clicks.push("top") }} />
+            <div style={{ position: "absolute", top: 20, left: 20, width: 120, height: 80, backgroundColor: "#89b4fa" }} />
+            <div style={{ position: "absolute", top: 40, left: 40, width: 120, height: 80, backgroundColor: "#a6e3a1", zIndex: -1 }} />
+          </div>
+          <div style={{ position: "fixed", right: 16, bottom: 16, width: 160, height: 40, backgroundColor: "#f9e2af" }}>
+            <text>toast</text>
+          </div>
+        </div>
+      )
+
+      const divs = testRoot.renderer.findByType("div")
+      expect(divs[2].style.zIndex).toBe(2)
+      expect(divs[5].style.position).toBe("fixed")
+
+      testRoot.renderer.nativeSimulateClick(30, 30)
+      expect(clicks).toEqual(["top"])
+
+      const path = `${SCREENSHOT_DIR}/gpuix-stacking.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+    })
+
+    it("paints layers nested in a layer in tree order", () => {
+      const clicks: string[] = []
+      testRoot.render(
+        <div style={{ width: 400, height: 300, backgroundColor: "#11111b" }}>
+          <div style={{ position: "relative", width: 200, height: 160, zIndex: 1, backgroundColor: "#313244" }}>
+            <div style={{ position: "absolute", top: 0, left: 0, width: 80, height: 80, backgroundColor: "#f38ba8", zIndex: 2 }} onClick={() => { clicks.push("nested") }} />
+            <div style={{ position: "fixed", left: 240, top: 200, width: 120, height: 40, backgroundColor: "#f9e2af", zIndex: 3 }} onClick={() => { clicks.push("fixed") }}>
+              <div style={{ width: 40, height: 20, backgroundColor: "#89b4fa", zIndex: 4 }} />
+            </div>
+          </div>
+          <div style={{ position: "fixed", left: 240, top: 16, width: 120, height: 80, backgroundColor: "#a6e3a1" }}>
+            <div style={{ width: 60, height: 40, backgroundColor: "#fab387", zIndex: 1 }} onClick={() => { clicks.push("in fixed") }} />
+          </div>
+        </div>
+      )
+
+      // Rendering no longer panics on a deferred draw inside another.
+      testRoot.renderer.nativeSimulateClick(20, 20)
+      testRoot.renderer.nativeSimulateClick(340, 230)
+      testRoot.renderer.nativeSimulateClick(250, 20)
+      expect(clicks).toEqual(["nested", "fixed", "in fixed"])
+
+      const path = `${SCREENSHOT_DIR}/gpuix-stacking-nested.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+    })
+  })
+
+  describe("sticky", () => {
+    function Sections({ onHeader }: { onHeader: (name: string) => void }) {
+      return (
+        <div style={{ width: 300, height: 200, overflow: "scroll", backgroundColor: "#11111b" }}>
+          {["one", "two", "three"].map((name) => (
+            <div key={name} style={{ height: 300 }}>
+              <div
+                style={{ position: "sticky", top: 0, height: 40, backgroundColor: "#313244" }}
+                onClick={() => onHeader(name)}
+              >
+                <text>{name}</text>
+              </div>
+              <div style={{ height: 260, backgroundColor: "#45475a" }} />
+            </div>
+          ))}
+        </div>
+      )
+    }
+
+    it("pins headers at their top threshold while their section scrolls", () => {
+      const clicks: string[] = []
+      testRoot.render(<Sections onHeader={(name) => clicks.push(name)} />)
+      // Sticky offsets use the previous frame's layout.
+      testRoot.renderer.flush()
+
+      testRoot.renderer.nativeSimulateScrollWheel(150, 100, 0, -100)
+      testRoot.renderer.nativeSimulateClick(10, 10)
+      expect(clicks).toEqual(["one"])
+
+      const path = `${SCREENSHOT_DIR}/gpuix-sticky.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+    })
+
+    it("pushes a header out with the end of its section", () => {
+      const clicks: string[] = []
+      testRoot.render(<Sections onHeader={(name) => clicks.push(name)} />)
+      testRoot.renderer.flush()
+
+      // 280px down: section one ends 20px into the viewport, so its header
+      // is pushed up to -20..20 and section two's header sits at 20..60.
+      testRoot.renderer.nativeSimulateScrollWheel(150, 100, 0, -280)
+      testRoot.renderer.nativeSimulateClick(10, 10)
+      testRoot.renderer.nativeSimulateClick(10, 40)
+      expect(clicks).toEqual(["one", "two"])
+    })
+
+    it("pins inside a layer and around layered children without deferring twice", () => {
+      const clicks: string[] = []
+      testRoot.render(
+        <div style={{ position: "fixed", left: 0, top: 0, width: 300, height: 200, overflow: "scroll", backgroundColor: "#11111b" }}>
+          {["one", "two"].map((name) => (
+            <div key={name} style={{ height: 300 }}>
+              <div style={{ position: "sticky", top: 0, height: 40, backgroundColor: "#313244" }}>
+                <div
+                  style={{ width: 80, height: 40, backgroundColor: "#f38ba8", zIndex: 1 }}
+                  onClick={() => { clicks.push(name) }}
+                />
+              </div>
+              <div style={{ height: 260, backgroundColor: "#45475a" }} />
+            </div>
+          ))}
+        </div>
+      )
+      testRoot.renderer.flush()
+
+      // Pinned inside a fixed layer, with a zIndex child: no nested defer.
+      testRoot.renderer.nativeSimulateScrollWheel(150, 100, 0, -100)
+      testRoot.renderer.nativeSimulateClick(10, 10)
+      expect(clicks).toEqual(["one"])
+    })
+  })
 })
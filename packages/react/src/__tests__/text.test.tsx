@@ -0,0 +1,224 @@
+/// Tests for GPUIX inline text — nested <text> spans flattened into a single
+/// GPUI StyledText paragraph, and link spans rendered via InteractiveText.
+///
+/// Links are placed at the top-left corner of the window so the first
+/// characters sit at known coordinates for hit testing.
+
+import fs from "fs"
+import { beforeEach, describe, expect, it } from "vitest"
+import React, { useState } from "react"
+import { createTestRoot, hasNativeTestRenderer } from "../testing"
+import type { EventPayload } from "@gpuix/native"
+
+const describeNative = hasNativeTestRenderer ? describe : describe.skip
+
+const SCREENSHOT_DIR = "/tmp"
+
+describeNative("inline text", () => {
+  let testRoot: ReturnType<typeof createTestRoot>
+
+  beforeEach(() => {
+    testRoot = createTestRoot()
+  })
+
+  describe("nested spans", () => {
+    it("should keep span text in document order", () => {
+      testRoot.render(
+        <div style={{ width: 400, height: 100, backgroundColor: "#11111b" }}>
+          <text style={{ color: "#cdd6f4", fontSize: 16 }}>
+            Read the{" "}
+            <text style={{ color: "#89b4fa", fontWeight: "bold" }}>docs</text>
+            {" first"}
+          </text>
+        </div>
+      )
+
+      expect(testRoot.renderer.getAllText()).toMatchInlineSnapshot(`
+        [
+          "Read the",
+          " ",
+          "docs",
+          " first",
+        ]
+      `)
+
+      const path = `${SCREENSHOT_DIR}/gpuix-inline-spans.png`
+      if (fs.existsSync(path)) fs.unlinkSync(path)
+      testRoot.renderer.captureScreenshot(path)
+      expect(fs.existsSync(path)).toBe(true)
+    })
+  })
+
+  describe("links", () => {
+    it("should forward href to the span as a custom prop", () => {
+      testRoot.render(
+        <div style={{ width: 400, height: 100 }}>
+          <text style={{ color: "#cdd6f4" }}>
+            <text href="https://example.com/docs">Docs</text>
+            {" and more"}
+          </text>
+        </div>
+      )
+
+      const link = testRoot.renderer
+        .findByType("text")
+        .find((el) => el.customProps?.href != null)
+      expect(link?.customProps?.href).toBe("https://example.com/docs")
+    })
+
+    it("should emit click with href when a link range is clicked", () => {
+      const clicks: EventPayload[] = []
+
+      function App() {
+        const [count, setCount] = useState(0)
+        return (
+          <div style={{ width: 400, height: 100 }}>
+            <text style={{ color: "#cdd6f4", fontSize: 16 }}>
+              <text
+                href="https://example.com/docs"
+                onClick={(e: EventPayload) => {
+                  clicks.push(e)
+                  setCount((c) => c + 1)
+                }}
+              >
+                Documentation
+              </text>
+              {` clicked ${count}`}
+            </text>
+          </div>
+        )
+      }
+
+      testRoot.render(<App />)
+
+      // InteractiveText records the mouse-down index, then handles the
+      // mouse-up on the next frame — simulate the two halves separately.
+      testRoot.renderer.nativeSimulateMouseDown(10, 8)
+      testRoot.renderer.nativeSimulateMouseUp(10, 8)
+
+      expect(clicks.length).toBe(1)
+      expect(clicks[0].href).toBe("https://example.com/docs")
+      expect(testRoot.renderer.getAllText()).toContain(" clicked 1")
+    })
+
+    it("should not emit link clicks outside the link range", () => {
+      const clicks: EventPayload[] = []
+
+      testRoot.render(
+        <div style={{ width: 400, height: 100 }}>
+          <text style={{ color: "#cdd6f4", fontSize: 16 }}>
+            <text href="https://example.com" onClick={(e: EventPayload) => { clicks.push(e) }}>
+              Go
+            </text>
+            {" — this trailing text is not a link"}
+          </text>
+        </div>
+      )
+
+      testRoot.renderer.nativeSimulateMouseDown(200, 8)
+      testRoot.renderer.nativeSimulateMouseUp(200, 8)
+
+      expect(clicks.length).toBe(0)
+    })
+  })
+
+  describe("selection", () => {
+    it("should emit selectionChange while dragging across selectable text", () => {
+      const changes: EventPayload[] = []
+
+      testRoot.render(
+        <div style={{ width: 400, height: 100 }}>
+          <text
+            selectable
+            style={{ color: "#cdd6f4", fontSize: 16 }}
+            onSelectionChange={(e: EventPayload) => changes.push(e)}
+          >
+            Error: connection refused
+          </text>
+        </div>
+      )
+
+      testRoot.renderer.nativeSimulateMouseDown(1, 8)
+      testRoot.renderer.nativeSimulateMouseMove(90, 8, 0)
+      testRoot.renderer.nativeSimulateMouseUp(90, 8)
+
+      expect(changes.length).toBeGreaterThan(0)
+      const last = changes[changes.length - 1]
+      // The drag stops mid-string; exact glyph widths are font dependent.
+      expect(last.selectionStart).toBe(0)
+      expect(last.selectedText!.length).toBeGreaterThan(0)
+      expect("Error: connection refused".startsWith(last.selectedText!)).toBe(true)
+      expect(last.selectionEnd).toBe(last.selectedText!.length)
+    })
+
+    it("should keep extending the selection when dragged outside the text", () => {
+      const changes: EventPayload[] = []
+
+      testRoot.render(
+        <div style={{ width: 400, height: 100 }}>
+          <text
+            selectable
+            style={{ color: "#cdd6f4", fontSize: 16 }}
+            onSelectionChange={(e: EventPayload) => changes.push(e)}
+          >
+            drag past me
+          </text>
+        </div>
+      )
+
+      testRoot.renderer.nativeSimulateMouseDown(1, 8)
+      // Below the line, outside the text's hitbox.
+      testRoot.renderer.nativeSimulateMouseMove(300, 80, 0)
+      testRoot.renderer.nativeSimulateMouseUp(300, 80)
+
+      expect(changes[changes.length - 1].selectedText).toBe("drag past me")
+    })
+
+    it("should copy the selection to the clipboard with cmd-c", () => {
+      let selected = ""
+
+      testRoot.render(
+        <div style={{ width: 400, height: 100 }}>
+          <text
+            selectable
+            style={{ color: "#cdd6f4", fontSize: 16 }}
+            onSelectionChange={(e: EventPayload) => (selected = e.selectedText ?? "")}
+          >
+            copy me please
+          </text>
+        </div>
+      )
+
+      testRoot.renderer.nativeSimulateMouseDown(1, 8)
+      testRoot.renderer.nativeSimulateMouseMove(90, 8, 0)
+      testRoot.renderer.nativeSimulateMouseUp(90, 8)
+
+      const el = testRoot.renderer.findByText("copy me please")!
+      testRoot.renderer.nativeSimulateKeystrokes(el.id, "cmd-c")
+
+      expect(selected.length).toBeGreaterThan(0)
+      expect(testRoot.renderer.readClipboardText()).toBe(selected)
+    })
+
+    it("should not select text without the selectable prop", () => {
+      const changes: EventPayload[] = []
+
+      testRoot.render(
+        <div style={{ width: 400, height: 100 }}>
+          <text
+            style={{ color: "#cdd6f4", fontSize: 16 }}
+            onSelectionChange={(e: EventPayload) => changes.push(e)}
+          >
+            static label
+          </text>
+        </div>
+      )
+
+      testRoot.renderer.nativeSimulateMouseDown(1, 8)
+      testRoot.renderer.nativeSimulateMouseMove(90, 8, 0)
+      testRoot.renderer.nativeSimulateMouseUp(90, 8)
+
+      expect(changes.length).toBe(0)
+    })
+  })
+})
@@ -0,0 +1,119 @@
+/// Tests for GPUIX theme variables — setTheme() token maps and "var(--name)"
+/// references in style fields, resolved natively at render time.
+///
+/// @ts-nocheck
+
+import fs from "fs"
+import { beforeEach, describe, expect, it } from "vitest"
+import React from "react"
+import { createTestRoot, hasNativeTestRenderer } from "../testing"
+
+const describeNative = hasNativeTestRenderer ? describe : describe.skip
+
+const SCREENSHOT_DIR = "/tmp"
+
+const DARK = { surface: "#1e1e2e", text: "#cdd6f4", accent: "#89b4fa", gap: 8, radius: 6 }
+const LIGHT = { surface: "#eff1f5", text: "#4c4f69", accent: "#1e66f5", gap: 16, radius: 0 }
+
+function ThemedCard() {
+  return (
+    <div
+      style={{
+        display: "flex",
+        flexDirection: "column",
+        width: 300,
+        padding: 12,
+        gap: "var(--gap)",
+        borderRadius: "var(--radius)",
+        backgroundColor: "var(--surface)",
+        color: "var(--text)",
+        borderWidth: 2,
+        borderColor: "var(--accent)",
+      }}
+    >
+      <text style={{ fontSize: 16 }}>Themed card</text>
+      <text style={{ textDecoration: "underline var(--accent)" }}>accent underline</text>
+    </div>
+  )
+}
+
+describeNative("theme variables", () => {
+  let testRoot: ReturnType<typeof createTestRoot>
+
+  beforeEach(() => {
+    testRoot = createTestRoot()
+  })
+
+  it("resolves var() in color and numeric fields", () => {
+    testRoot.renderer.setTheme(JSON.stringify(DARK))
+    testRoot.render(<ThemedCard />)
+
+    const [card] = testRoot.renderer.findByType("div")
+    expect(card.style.backgroundColor).toBe("#1e1e2e")
+    expect(card.style.gap).toBe(8)
+    expect(card.style.borderRadius).toBe(6)
+
+    const [, underlined] = testRoot.renderer.findByType("text")
+    expect(underlined.style.textDecoration).toBe("underline #89b4fa")
+
+    const path = `${SCREENSHOT_DIR}/gpuix-theme-dark.png`
+    if (fs.existsSync(path)) fs.unlinkSync(path)
+    testRoot.renderer.captureScreenshot(path)
+    expect(fs.existsSync(path)).toBe(true)
+  })
+
+  it("re-resolves styles when the theme changes without re-rendering React", () => {
+    testRoot.renderer.setTheme(JSON.stringify(DARK))
+    testRoot.render(<ThemedCard />)
+    const commitsBefore = testRoot.renderer.commitCount
+
+    testRoot.renderer.setTheme(JSON.stringify(LIGHT))
+
+    const [card] = testRoot.renderer.findByType("div")
+    expect(card.style.backgroundColor).toBe("#eff1f5")
+    expect(card.style.gap).toBe(16)
+    expect(testRoot.renderer.commitCount).toBe(commitsBefore)
+
+    const path = `${SCREENSHOT_DIR}/gpuix-theme-light.png`
+    if (fs.existsSync(path)) fs.unlinkSync(path)
+    testRoot.renderer.captureScreenshot(path)
+    expect(fs.existsSync(path)).toBe(true)
+  })
+
+  it("uses fallbacks for undefined tokens and accepts -- prefixed keys", () => {
+    testRoot.renderer.setTheme(JSON.stringify({ "--accent": "#f38ba8" }))
+    testRoot.render(
+      <div style={{ width: 100, height: 40, padding: "var(--pad, 4)", backgroundColor: "var(--accent)" }} />
+    )
+
+    const [div] = testRoot.renderer.findByType("div")
+    expect(div.style.padding).toBe(4)
+    expect(div.style.backgroundColor).toBe("#f38ba8")
+  })
+
+  it("drops only the property whose token doesn't fit its field", () => {
+    testRoot.renderer.setTheme(JSON.stringify({ accent: "#f38ba8" }))
+    testRoot.render(
+      <div
+        style={{
+          width: 100,
+          height: 40,
+          opacity: "var(--accent)",
+          backgroundColor: "var(--accent)",
+          hover: { opacity: "var(--accent)", backgroundColor: "var(--accent)" },
+        }}
+      />
+    )
+
+    const [div] = testRoot.renderer.findByType("div")
+    expect(div.style.opacity).toBeUndefined()
+    expect(div.style.width).toBe(100)
+    expect(div.style.backgroundColor).toBe("#f38ba8")
+    expect((div.style.hover as Record<string, unknown>).backgroundColor).toBe("#f38ba8")
+  })
+
+  it("rejects a theme that is not an object of strings and numbers", () => {
+    expect(() => testRoot.renderer.setTheme("[1, 2]")).toThrow()
+    expect(() => testRoot.renderer.setTheme(JSON.stringify({ nested: { a: 1 } }))).toThrow()
+  })
+})
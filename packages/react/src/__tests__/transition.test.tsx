@@ -0,0 +1,106 @@
+/// Tests for GPUIX CSS transitions — the `transition` style interpolated
+/// natively across frames, with transitionEnd events back to JS.
+///
+/// Transitions run on wall-clock time, so these tests wait real
+/// milliseconds between flushes.
+
+import fs from "fs"
+import { beforeEach, describe, expect, it } from "vitest"
+import React from "react"
+import { createTestRoot, hasNativeTestRenderer } from "../testing"
+import type { EventPayload } from "@gpuix/native"
+
+const describeNative = hasNativeTestRenderer ? describe : describe.skip
+
+const SCREENSHOT_DIR = "/tmp"
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms))
+
+describeNative("transitions", () => {
+  let testRoot: ReturnType<typeof createTestRoot>
+
+  beforeEach(() => {
+    testRoot = createTestRoot()
+  })
+
+  function Box({
+    active,
+    transition,
+    onTransitionEnd,
+  }: {
+    active: boolean
+    transition?: string
+    onTransitionEnd?: (e: EventPayload) => void
+  }) {
+    return (
+      <div
+        style={{
+          width: active ? 200 : 100,
+          height: 60,
+          opacity: active ? 1 : 0.2,
+          backgroundColor: active ? "#89b4fa" : "#f38ba8",
+          transition,
+        }}
+        onTransitionEnd={onTransitionEnd}
+      />
+    )
+  }
+
+  it("emits transitionEnd per property once the duration has elapsed", async () => {
+    const ended: EventPayload[] = []
+    const transition = "opacity 60ms linear, backgroundColor 60ms"
+
+    testRoot.render(<Box active={false} transition={transition} onTransitionEnd={(e) => ended.push(e)} />)
+    testRoot.render(<Box active transition={transition} onTransitionEnd={(e) => ended.push(e)} />)
+
+    // Mid-transition: nothing has finished yet.
+    testRoot.renderer.flush()
+    testRoot.renderer.dispatchNativeEvents()
+    expect(ended.length).toBe(0)
+
+    const path = `${SCREENSHOT_DIR}/gpuix-transition-mid.png`
+    if (fs.existsSync(path)) fs.unlinkSync(path)
+    testRoot.renderer.captureScreenshot(path)
+    expect(fs.existsSync(path)).toBe(true)
+
+    await sleep(120)
+    testRoot.renderer.flush()
+    testRoot.renderer.dispatchNativeEvents()
+
+    expect(ended.map((e) => e.property).sort()).toEqual(["backgroundColor", "opacity"])
+    expect(ended[0].elapsedTime).toBeCloseTo(0.06, 3)
+    // Width changed too but is not listed in `transition`, so it snapped.
+    expect(ended.some((e) => e.property === "width")).toBe(false)
+  })
+
+  it("animates every property with `all` and keeps the target style in the tree", async () => {
+    const ended: EventPayload[] = []
+
+    testRoot.render(<Box active={false} transition="all 40ms" onTransitionEnd={(e) => ended.push(e)} />)
+    testRoot.render(<Box active transition="all 40ms" onTransitionEnd={(e) => ended.push(e)} />)
+
+    // The retained tree always reports the target style; only painting is interpolated.
+    const [div] = testRoot.renderer.findByType("div")
+    expect(div.style.width).toBe(200)
+    expect(div.style.opacity).toBe(1)
+
+    await sleep(100)
+    testRoot.renderer.flush()
+    testRoot.renderer.dispatchNativeEvents()
+
+    expect(ended.map((e) => e.property).sort()).toEqual(["backgroundColor", "opacity", "width"])
+  })
+
+  it("does not animate without a transition style", async () => {
+    const ended: EventPayload[] = []
+
+    testRoot.render(<Box active={false} onTransitionEnd={(e) => ended.push(e)} />)
+    testRoot.render(<Box active onTransitionEnd={(e) => ended.push(e)} />)
+
+    await sleep(50)
+    testRoot.renderer.flush()
+    testRoot.renderer.dispatchNativeEvents()
+
+    expect(ended.length).toBe(0)
+  })
+})
@@ -47,6 +47,7 @@ const BATCHED_METHODS = new Set([
   "removeChild",
   "insertBefore",
   "setStyle",
+  "setClassName",
   "setText",
   "setEventListener",
   "setRoot",
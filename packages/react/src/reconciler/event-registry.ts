@@ -1,22 +1,28 @@
-import type { EventPayload } from "@gpuix/native"
+import type { EventPayload, EventResponse } from "@gpuix/native"
 
 // Event handler registry — keyed by numeric element ID.
-const eventHandlers = new Map<number, Map<string, (event: EventPayload) => void>>()
+const eventHandlers = new Map<number, Map<string, (event: EventPayload) => unknown>>()
 
-export function handleGpuixEvent(payload: EventPayload): void {
+/** Call the element's handler for `payload`. Returns what the handler
+ *  returned, which the native side applies when dispatching synchronously. */
+export function handleGpuixEvent(payload: EventPayload): EventResponse | undefined {
   const elementHandlers = eventHandlers.get(payload.elementId)
   if (elementHandlers) {
     const handler = elementHandlers.get(payload.eventType)
     if (handler) {
-      handler(payload)
+      const response = handler(payload)
+      if (typeof response === "object" && response !== null) {
+        return response as EventResponse
+      }
     }
   }
+  return undefined
 }
 
 export function registerEventHandler(
   elementId: number,
   eventType: string,
-  handler: (event: EventPayload) => void
+  handler: (event: EventPayload) => unknown
 ): void {
   let elementHandlers = eventHandlers.get(elementId)
   if (!elementHandlers) {
@@ -67,6 +67,21 @@ const EVENT_PROPS: Record<string, string> = {
   onBlur: "blur",
   // Scroll events
   onScroll: "scroll",
+  // Text selection (selectable <text>)
+  onSelectionChange: "selectionChange",
+  // Style transitions (transition style property)
+  onTransitionEnd: "transitionEnd",
+  // Drop targets (drags of `draggable` elements)
+  onDragEnter: "dragEnter",
+  onDragOver: "dragOver",
+  onDragLeave: "dragLeave",
+  onDrop: "drop",
+  // Files dragged in from the OS
+  onFileDragEnter: "fileDragEnter",
+  onFileDragLeave: "fileDragLeave",
+  onFileDrop: "fileDrop",
+  // Key binding actions (registerKeyBindings)
+  onAction: "action",
 }
 
 function syncEventListeners(id: number, props: Props): void {
@@ -108,6 +123,12 @@ function sendStyle(id: number, props: Props): void {
   getRenderer().setStyle(id, JSON.stringify(props.style ?? {}))
 }
 
+function sendClassName(id: number, oldProps: Props | null, newProps: Props): void {
+  const className = newProps.className ?? ""
+  if (className === (oldProps?.className ?? "")) return
+  getRenderer().setClassName(id, className)
+}
+
 // ── Custom prop forwarding ───────────────────────────────────────────
 
 // Props that are handled by the reconciler directly (not forwarded as custom props).
@@ -117,18 +138,36 @@ const RESERVED_PROPS = new Set([
   "children",
   "key",
   "ref",
-  "tabIndex",
   "tabStop",
   "autoFocus",
 ])
 
-// Built-in element types that don't use custom props.
-const BUILT_IN_TYPES = new Set(["div", "text"])
+// Built-in element types forward only the props their Rust builder reads.
+// "text" is not listed: spans forward props like `href` for inline links.
+const BUILT_IN_PROPS: Record<string, Set<string>> = {
+  div: new Set([
+    "disabled",
+    "group",
+    "tooltip",
+    "draggable",
+    "dragData",
+    "dragPreview",
+    "capturePointer",
+    "keyContext",
+    "tabIndex",
+  ]),
+}
 
 function isReservedProp(name: string): boolean {
   return RESERVED_PROPS.has(name) || name in EVENT_PROPS
 }
 
+function isForwardedProp(type: string, name: string): boolean {
+  if (isReservedProp(name)) return false
+  const builtIn = BUILT_IN_PROPS[type]
+  return !builtIn || builtIn.has(name)
+}
+
 function serializeCustomProp(
   type: string,
   key: string,
@@ -149,12 +188,11 @@ function serializeCustomProp(
   }
 }
 
-/** Send all custom props to Rust for non-built-in element types. */
+/** Send all forwarded custom props to Rust. */
 function syncCustomProps(id: number, type: string, props: Props): void {
-  if (BUILT_IN_TYPES.has(type)) return
   const r = getRenderer()
   for (const [key, value] of Object.entries(props)) {
-    if (isReservedProp(key)) continue
+    if (!isForwardedProp(type, key)) continue
     r.setCustomProp(id, key, serializeCustomProp(type, key, value))
   }
 }
@@ -166,18 +204,17 @@ function diffCustomProps(
   oldProps: Props,
   newProps: Props
 ): void {
-  if (BUILT_IN_TYPES.has(type)) return
   const r = getRenderer()
   // Updated or added props
   for (const [key, value] of Object.entries(newProps)) {
-    if (isReservedProp(key)) continue
+    if (!isForwardedProp(type, key)) continue
     if (oldProps[key] !== value) {
       r.setCustomProp(id, key, serializeCustomProp(type, key, value))
     }
   }
   // Removed props
   for (const key of Object.keys(oldProps)) {
-    if (isReservedProp(key)) continue
+    if (!isForwardedProp(type, key)) continue
     if (!(key in newProps)) {
       r.setCustomProp(id, key, JSON.stringify(null))
     }
@@ -208,6 +245,7 @@ export const hostConfig = {
     const r = getRenderer()
     r.createElement(id, type)
     sendStyle(id, props)
+    sendClassName(id, null, props)
     syncEventListeners(id, props)
     syncCustomProps(id, type, props)
     return { id, type, props }
@@ -317,6 +355,7 @@ export const hostConfig = {
     // Always resend style — per-element JSON is small, and this avoids
     // bugs from same-reference mutations or style removal.
     sendStyle(instance.id, newProps)
+    sendClassName(instance.id, oldProps, newProps)
     // Event diff
     diffEventListeners(instance.id, oldProps, newProps)
     // Custom prop diff (for non-div/text elements)
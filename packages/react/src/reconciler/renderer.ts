@@ -6,13 +6,22 @@ import { GpuixRenderer } from "@gpuix/native"
 import { reconciler } from "./reconciler.js"
 import type { Container, NativeRenderer } from "../types/host.js"
 import { clearEventHandlers, handleGpuixEvent } from "./event-registry.js"
+import { handleGpuixEventSync } from "./sync-events.js"
 import { setNativeRenderer } from "./host-config.js"
 import { wrapWithBatching } from "./batch-renderer.js"
 
+export interface RendererOptions {
+  /** Run event handlers synchronously while tick() dispatches input, so
+   *  they can return `{ stopPropagation, preventDefault }`. Events emitted
+   *  during rendering (transitionEnd) are still queued. */
+  syncEvents?: boolean
+}
+
 export function createRenderer(
-  onEvent?: (event: import("@gpuix/native").EventPayload) => void
+  onEvent?: (event: import("@gpuix/native").EventPayload) => void,
+  options?: RendererOptions
 ): GpuixRenderer {
-  return new GpuixRenderer((err, event) => {
+  const renderer = new GpuixRenderer((err, event) => {
     if (err) {
       console.error("[GPUIX] Native event error:", err)
       return
@@ -24,6 +33,16 @@ export function createRenderer(
       }
     }
   })
+  if (options?.syncEvents) {
+    renderer.setSyncEventHandler((event) => {
+      const response = handleGpuixEventSync(event)
+      if (onEvent) {
+        onEvent(event)
+      }
+      return response
+    })
+  }
+  return renderer
 }
 
 export interface Root {
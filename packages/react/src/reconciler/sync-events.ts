@@ -0,0 +1,19 @@
+import type { EventPayload, EventResponse } from "@gpuix/native"
+import { reconciler } from "./reconciler.js"
+import { handleGpuixEvent } from "./event-registry.js"
+
+const _r = reconciler as typeof reconciler & {
+  flushSyncFromReconciler?: typeof reconciler.flushSync
+}
+const flushSync = _r.flushSyncFromReconciler ?? _r.flushSync
+
+/** The synchronous event handler (setSyncEventHandler): runs the React
+ *  handler for `event` and commits its state updates before native
+ *  dispatch continues, then returns the handler's response. */
+export function handleGpuixEventSync(event: EventPayload): EventResponse | undefined {
+  let response: EventResponse | undefined
+  flushSync(() => {
+    response = handleGpuixEvent(event)
+  })
+  return response
+}
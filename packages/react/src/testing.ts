@@ -11,12 +11,13 @@
 
 import React from "react"
 import type { ReactNode } from "react"
-import type { EventPayload } from "@gpuix/native"
+import type { EventPayload, EventResponse } from "@gpuix/native"
 import type { NativeRenderer } from "./types/host.js"
 import type { Root } from "./reconciler/renderer.js"
 import { reconciler } from "./reconciler/reconciler.js"
 import { setNativeRenderer, resetIdCounter } from "./reconciler/host-config.js"
 import { clearEventHandlers, handleGpuixEvent } from "./reconciler/event-registry.js"
+import { handleGpuixEventSync } from "./reconciler/sync-events.js"
 import { wrapWithBatching } from "./reconciler/batch-renderer.js"
 import type { OpaqueRoot } from "react-reconciler"
 import { ConcurrentRoot } from "react-reconciler/constants.js"
@@ -98,6 +99,10 @@ export class TestRenderer implements NativeRenderer {
     this.native.setStyle(id, styleJson)
   }
 
+  setClassName(id: number, className: string): void {
+    this.native.setClassName(id, className)
+  }
+
   setText(id: number, content: string): void {
     this.native.setText(id, content)
   }
@@ -169,6 +174,17 @@ export class TestRenderer implements NativeRenderer {
     this.dispatchNativeEvents()
   }
 
+  /** End-to-end: simulate keystrokes through GPUI without focusing an
+   *  element first → dispatch resulting events to React. In a fresh root
+   *  nothing is focused, so this exercises global key bindings.
+   *  @param keystrokes - space-separated keys, e.g. "a", "enter", "cmd-shift-p"
+   */
+  nativeSimulateGlobalKeystrokes(keystrokes: string): void {
+    this.native.flush()
+    this.native.simulateKeystrokes(keystrokes)
+    this.dispatchNativeEvents()
+  }
+
   /** End-to-end: focus element → simulate a single key down through GPUI →
    *  dispatch resulting events to React. Unlike nativeSimulateKeystrokes,
    *  this dispatches ONLY a KeyDownEvent — no automatic KeyUpEvent follows.
@@ -251,6 +267,33 @@ export class TestRenderer implements NativeRenderer {
     this.native.flush()
   }
 
+  /** End-to-end: simulate files dragged from the OS to (x, y) →
+   *  dispatch resulting events to React. */
+  nativeSimulateFileDrag(paths: string[], x: number, y: number): void {
+    this.native.flush()
+    this.native.simulateFileDrag(paths, x, y)
+    this.dispatchNativeEvents()
+    this.native.flush()
+  }
+
+  /** End-to-end: simulate dropping the dragged files at (x, y) →
+   *  dispatch resulting events to React. */
+  nativeSimulateFileDrop(x: number, y: number): void {
+    this.native.flush()
+    this.native.simulateFileDrop(x, y)
+    this.dispatchNativeEvents()
+    this.native.flush()
+  }
+
+  /** End-to-end: simulate the dragged files leaving the window →
+   *  dispatch resulting events to React. */
+  nativeSimulateFileDragExit(): void {
+    this.native.flush()
+    this.native.simulateFileDragExit()
+    this.dispatchNativeEvents()
+    this.native.flush()
+  }
+
   // ── Tree inspection (queries Rust RetainedTree via napi) ────────
 
   /** Build a flat map of TestElements from the native tree JSON.
@@ -307,11 +350,74 @@ export class TestRenderer implements NativeRenderer {
     return this.native.getAllText()
   }
 
+  /** Read the text on the test platform's clipboard (null if empty). */
+  readClipboardText(): string | null {
+    return this.native.readClipboardText()
+  }
+
   /** Print the tree structure for debugging. Only includes non-empty fields. */
   toJSON(): unknown {
     return JSON.parse(this.native.getTreeJson())
   }
 
+  // ── Theme ───────────────────────────────────────────────────────
+
+  /** Replace the theme and re-render with the new token values. */
+  setTheme(themeJson: string): void {
+    this.native.setTheme(themeJson)
+    this.native.flush()
+  }
+
+  // ── Keyboard ────────────────────────────────────────────────────
+
+  /** Add key bindings and re-render so they are bound. */
+  registerKeyBindings(bindingsJson: string): void {
+    this.native.registerKeyBindings(bindingsJson)
+    this.native.flush()
+  }
+
+  /** Remove every key binding. */
+  clearKeyBindings(): void {
+    this.native.clearKeyBindings()
+    this.native.flush()
+  }
+
+  /** Switch `event.key` between GPUI and DOM key names. */
+  setKeyNames(names: "gpui" | "dom"): void {
+    this.native.setKeyNames(names)
+    this.native.flush()
+  }
+
+  // ── Events ──────────────────────────────────────────────────────
+
+  setSyncEventHandler(
+    handler: ((event: EventPayload) => EventResponse | undefined | null) | null
+  ): void {
+    this.native.setSyncEventHandler(handler)
+  }
+
+  /** Run React handlers synchronously during the native* simulations, as
+   *  createRenderer's `syncEvents` does during tick(). */
+  setSyncEvents(enabled: boolean): void {
+    this.native.setSyncEventHandler(enabled ? handleGpuixEventSync : null)
+  }
+
+  // ── Style classes ───────────────────────────────────────────────
+
+  /** Register (or replace) a style class and re-render elements using it. */
+  registerStyleClass(name: string, styleJson: string): void {
+    this.native.registerStyleClass(name, styleJson)
+    this.native.flush()
+  }
+
+  // ── Animations ──────────────────────────────────────────────────
+
+  /** Register (or replace) named keyframes and re-render. */
+  registerAnimation(name: string, keyframesJson: string): void {
+    this.native.registerAnimation(name, keyframesJson)
+    this.native.flush()
+  }
+
   // ── Scroll API ──────────────────────────────────────────────────
 
   /** Set the scroll offset of a scrollable element (overflow: "scroll").
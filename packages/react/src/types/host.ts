@@ -1,22 +1,66 @@
-import type { EventPayload } from "@gpuix/native"
+import type { EventPayload, EventResponse } from "@gpuix/native"
 
+/** Pixels as a number, or a CSS length string: "50%", "auto", "1.5rem",
+ *  "2em", "50vw", "10vh", "calc(100% - 24px)". */
 export type DimensionValue = number | string
 
-export interface StyleDesc {
+/** One box shadow. Lengths are pixels; color defaults to currentColor. */
+export interface BoxShadow {
+  offsetX?: number
+  offsetY?: number
+  blur?: number
+  spread?: number
+  color?: string
+  /** Parsed but not rendered — GPUI only paints outer shadows. */
+  inset?: boolean
+}
+
+/** Theme token reference, resolved natively against setTheme(), e.g. "var(--accent)".
+ *  An optional fallback is allowed: "var(--gap, 8)". */
+export type ThemeVar = `var(--${string})`
+
+type PseudoStyleKey =
+  | "hover"
+  | "active"
+  | "focus"
+  | "focusVisible"
+  | "disabled"
+  | "groupHover"
+  | "dragOver"
+
+/** Every style field also accepts a ThemeVar (pseudo-styles are nested styles). */
+export type StyleDesc = {
+  [K in keyof StyleProps]: K extends PseudoStyleKey ? StyleProps[K] : StyleProps[K] | ThemeVar
+}
+
+interface StyleProps {
+  /** "flex" | "grid" | "block" | "none" — "none" hides the element while
+   *  keeping its subtree (and editor state) alive. */
   display?: string
+  /** "hidden" keeps the element's layout space but doesn't paint it. */
   visibility?: string
   flexDirection?: string
   flexWrap?: string
   flexGrow?: number
   flexShrink?: number
-  flexBasis?: number
+  flexBasis?: DimensionValue
   alignItems?: string
   alignSelf?: string
   alignContent?: string
   justifyContent?: string
-  gap?: number
-  rowGap?: number
-  columnGap?: number
+  gap?: DimensionValue
+  rowGap?: DimensionValue
+  columnGap?: DimensionValue
+
+  /** Track count, "repeat(3, 1fr)" or "1fr 1fr 1fr". GPUI lays out equal
+   *  fractional tracks only — fixed or differing track sizes ("200px 1fr")
+   *  log a warning natively and become that many equal tracks. */
+  gridTemplateColumns?: number | string
+  gridTemplateRows?: number | string
+  /** Line number, "span 2", "1 / 3" or "1 / span 2". */
+  gridColumn?: number | string
+  gridRow?: number | string
+  justifyItems?: "start" | "center" | "end" | "stretch"
 
   width?: DimensionValue
   height?: DimensionValue
@@ -25,56 +69,124 @@ export interface StyleDesc {
   maxWidth?: DimensionValue
   maxHeight?: DimensionValue
 
-  padding?: number
-  paddingTop?: number
-  paddingRight?: number
-  paddingBottom?: number
-  paddingLeft?: number
+  padding?: DimensionValue
+  paddingTop?: DimensionValue
+  paddingRight?: DimensionValue
+  paddingBottom?: DimensionValue
+  paddingLeft?: DimensionValue
 
-  margin?: number
-  marginTop?: number
-  marginRight?: number
-  marginBottom?: number
-  marginLeft?: number
+  margin?: DimensionValue
+  marginTop?: DimensionValue
+  marginRight?: DimensionValue
+  marginBottom?: DimensionValue
+  marginLeft?: DimensionValue
 
+  /** "relative" | "absolute" | "fixed" | "sticky" — fixed insets are relative to
+   *  the window; sticky `top`/`left` are thresholds within the nearest scroll container. */
   position?: string
-  top?: number
-  right?: number
-  bottom?: number
-  left?: number
+  /** Positive values paint above unlayered content (higher on top);
+   *  negative values paint absolutely positioned elements below their siblings. */
+  zIndex?: number
+  top?: DimensionValue
+  right?: DimensionValue
+  bottom?: DimensionValue
+  left?: DimensionValue
 
+  /** A color or "linear-gradient(angle, color stop%, color stop%)".
+   *  Gradients must have exactly two stops (a GPUI limitation); gradients
+   *  with more (or fewer) stops are rejected: they paint nothing and only
+   *  log a warning natively, JS gets no error.
+   *  Doesn't layer with backgroundColor: a gradient replaces the color
+   *  instead of painting over it, and backgroundColor replaces a plain
+   *  color. */
   background?: string
   backgroundColor?: string
   color?: string
   opacity?: number
 
-  borderWidth?: number
+  /** Shorthand: "1px solid #333", "2px dashed", "none". Per-side
+   *  shorthands and longhands override it. */
+  border?: string
+  borderTop?: string
+  borderRight?: string
+  borderBottom?: string
+  borderLeft?: string
+  borderWidth?: DimensionValue
+  borderTopWidth?: DimensionValue
+  borderRightWidth?: DimensionValue
+  borderBottomWidth?: DimensionValue
+  borderLeftWidth?: DimensionValue
+  /** Differing per-side colors are painted by an overlay above the
+   *  children (GPUI paints one border color per element natively). */
   borderColor?: string
-  borderRadius?: number
-  borderTopLeftRadius?: number
-  borderTopRightRadius?: number
-  borderBottomLeftRadius?: number
-  borderBottomRightRadius?: number
+  borderTopColor?: string
+  borderRightColor?: string
+  borderBottomColor?: string
+  borderLeftColor?: string
+  /** "dotted" renders as dashed. */
+  borderStyle?: "solid" | "dashed" | "dotted" | "none" | "hidden"
+  /** Border widths and radii take absolute lengths: pixels, "0.5rem", "1em",
+   *  vw/vh or calc() without percentages. GPUI has no percentage radii. */
+  borderRadius?: DimensionValue
+  borderTopLeftRadius?: DimensionValue
+  borderTopRightRadius?: DimensionValue
+  borderBottomLeftRadius?: DimensionValue
+  borderBottomRightRadius?: DimensionValue
 
-  fontSize?: number
+  /** CSS string ("0 2px 8px rgba(0,0,0,0.3), 0 0 0 1px #fff2"), one shadow
+   *  object, or an array of them. The first shadow paints on top. `inset`
+   *  shadows are skipped with a warning: GPUI only paints outer shadows. */
+  boxShadow?: string | BoxShadow | BoxShadow[]
+
+  fontSize?: DimensionValue
   fontFamily?: string
   fontWeight?: string | number
-  textAlign?: string
-  lineHeight?: number
+  fontStyle?: "normal" | "italic" | "oblique"
+  textAlign?: "left" | "center" | "right" | "start" | "end"
+  /** Number = pixels, "150%" = relative to the font size. */
+  lineHeight?: DimensionValue
   whiteSpace?: "normal" | "nowrap"
   textOverflow?: "ellipsis" | "ellipsis-start"
   lineClamp?: number
+  /** "underline", "line-through", "none", or a shorthand like "underline wavy #f38ba8".
+   *  `overline`, `dotted`, `dashed` and `double` aren't drawn by GPUI; they
+   *  are skipped with a warning in the native log. */
+  textDecoration?: string
+  textDecorationColor?: string
+  textDecorationStyle?: "solid" | "wavy"
+  textTransform?: "none" | "uppercase" | "lowercase" | "capitalize"
 
   overflow?: string
   overflowX?: string
   overflowY?: string
 
   cursor?: string
+  /** "none" lets clicks and hover pass through the element; inherited by
+   *  descendants unless they set "auto". */
+  pointerEvents?: "auto" | "none"
+
+  /** "opacity 200ms ease-out, backgroundColor 150ms" — numeric, length and
+   *  color properties interpolate natively across frames. */
+  transition?: string
+
+  /** "pulse 1s ease-in-out infinite alternate" — runs keyframes registered
+   *  with registerAnimation() natively, without React re-renders. */
+  animation?: string
 
   // Pseudo-selector styles — applied by GPUI natively (no JS round-trip).
-  // Nesting is one level deep: hover/active cannot contain hover/active.
-  hover?: Omit<StyleDesc, "hover" | "active">
-  active?: Omit<StyleDesc, "hover" | "active">
+  // Nesting is one level deep: pseudo-styles cannot contain pseudo-styles.
+  hover?: Omit<StyleDesc, PseudoStyleKey>
+  active?: Omit<StyleDesc, PseudoStyleKey>
+  /** While focused. Makes the element focusable. */
+  focus?: Omit<StyleDesc, PseudoStyleKey>
+  /** Layered over `focus` when focus came from the keyboard, not a click. */
+  focusVisible?: Omit<StyleDesc, PseudoStyleKey>
+  /** While the `disabled` prop is true; replaces hover/active/focus styles. */
+  disabled?: Omit<StyleDesc, PseudoStyleKey>
+  /** While the nearest ancestor with a `group` prop is hovered. */
+  groupHover?: Omit<StyleDesc, PseudoStyleKey>
+  /** While a `draggable` element or files from the OS are dragged over this one. */
+  dragOver?: Omit<StyleDesc, PseudoStyleKey>
 }
 
 // Element types supported by GPUIX
@@ -92,33 +204,129 @@ export type ElementType =
 // Use React refs to get an element's ID: ref.current.id
 export type Props = Record<string, unknown> & {
   style?: StyleDesc
+  /** Space-separated names of classes registered with registerStyleClass().
+   *  Merged in order natively, then `style` on top. */
+  className?: string
   children?: React.ReactNode
 
+  // With synchronous dispatch (createRenderer's `syncEvents`), click,
+  // mouse button and move, key and scroll handlers can return an
+  // EventResponse: `{ stopPropagation: true }` or `{ preventDefault: true }`.
+
   // ── Mouse events ───────────────────────────────────────────────
-  onClick?: (event: EventPayload) => void
-  onMouseDown?: (event: EventPayload) => void
-  onMouseUp?: (event: EventPayload) => void
+  onClick?: (event: EventPayload) => void | EventResponse
+  onMouseDown?: (event: EventPayload) => void | EventResponse
+  onMouseUp?: (event: EventPayload) => void | EventResponse
   onMouseEnter?: (event: EventPayload) => void
   onMouseLeave?: (event: EventPayload) => void
-  onMouseMove?: (event: EventPayload) => void
+  onMouseMove?: (event: EventPayload) => void | EventResponse
   /** Fires when user clicks OUTSIDE this element. Use for "click outside to close". */
-  onMouseDownOutside?: (event: EventPayload) => void
+  onMouseDownOutside?: (event: EventPayload) => void | EventResponse
 
   // ── Keyboard events (require tabIndex or autoFocus for focus) ──
-  onKeyDown?: (event: EventPayload) => void
-  onKeyUp?: (event: EventPayload) => void
+  onKeyDown?: (event: EventPayload) => void | EventResponse
+  onKeyUp?: (event: EventPayload) => void | EventResponse
 
   // ── Focus events ───────────────────────────────────────────────
   onFocus?: (event: EventPayload) => void
   onBlur?: (event: EventPayload) => void
 
   // ── Scroll events ──────────────────────────────────────────────
-  onScroll?: (event: EventPayload) => void
+  onScroll?: (event: EventPayload) => void | EventResponse
+
+  // ── Transition events ──────────────────────────────────────────
+  /** Fires once per property when a `transition` completes.
+   *  `event.property` is the camelCase style name. */
+  onTransitionEnd?: (event: EventPayload) => void
+
+  // ── Drop target events ─────────────────────────────────────────
+  /** `event.dragData` and `event.dragSource` describe the dragged element. */
+  onDragEnter?: (event: EventPayload) => void
+  onDragOver?: (event: EventPayload) => void
+  onDragLeave?: (event: EventPayload) => void
+  onDrop?: (event: EventPayload) => void | EventResponse
+
+  // ── File drop events (files dragged in from the OS) ────────────
+  /** `event.paths` lists the dragged files. */
+  onFileDragEnter?: (event: EventPayload) => void
+  onFileDragLeave?: (event: EventPayload) => void
+  onFileDrop?: (event: EventPayload) => void | EventResponse
+
+  // ── Key binding actions ────────────────────────────────────────
+  /** A key binding matched while this element or a descendant is focused
+   *  (the root element also gets bindings matched with nothing focused).
+   *  Bubbles to outer onAction elements unless a synchronous handler
+   *  returns stopPropagation. `event.action` is the action name. */
+  onAction?: (event: EventPayload) => void | EventResponse
 
   // ── Focus props ────────────────────────────────────────────────
+  /** Makes the element focusable, e.g. for a keyContext scope. */
   tabIndex?: number
   tabStop?: boolean
   autoFocus?: boolean
+  /** Key context for bindings registered with a `context`, e.g. "Editor". */
+  keyContext?: string
+
+  // ── State props ────────────────────────────────────────────────
+  /** Applies the `disabled` style, blocks focus and suppresses every event,
+   *  for this element and everything inside it. */
+  disabled?: boolean
+  /** Names this element as a hover group for descendants' `groupHover` styles. */
+  group?: string
+
+  /** Shown after hovering for `delay`: plain text, or options. */
+  tooltip?: string | TooltipOptions
+
+  // ── Drag source props ──────────────────────────────────────────
+  /** Lets the element be dragged onto drop targets. */
+  draggable?: boolean
+  /** Any JSON value, delivered to drop targets as `event.dragData`. */
+  dragData?: unknown
+  /** Id (`ref.current.id`) of one of this element's children, rendered
+   *  only under the cursor while dragging. Defaults to a translucent ghost. */
+  dragPreview?: number
+
+  // ── Pointer capture ────────────────────────────────────────────
+  /** After a mouse down on the element, its onMouseMove and onMouseUp keep
+   *  firing wherever the cursor goes until the button is released.
+   *  Outside the element, `localX`/`localY` go negative or past its size. */
+  capturePointer?: boolean
+}
+
+/** A key binding for registerKeyBindings(). */
+export interface KeyBinding {
+  /** Space-separated keystrokes, e.g. "ctrl-k ctrl-s". */
+  keys: string
+  /** Name delivered to `onAction` as `event.action`. */
+  action: string
+  /** Key context predicate, e.g. "Editor" or "Editor && !readonly".
+   *  Without one, the binding is global. */
+  context?: string
+}
+
+export interface TooltipOptions {
+  text?: string
+  /** Id (`ref.current.id`) of one of this element's children, rendered
+   *  only inside the tooltip instead of in the element. Wins over `text`. */
+  element?: number
+  /** Milliseconds; defaults to 500. */
+  delay?: number
+  /** Side of the element; flips when there is no room. Defaults to "top". */
+  placement?: "top" | "bottom" | "left" | "right"
+}
+
+// Props for <text>. Nested <text> spans are flattened into one paragraph.
+export interface TextProps extends Props {
+  /** Makes this span a link: pointer cursor, underline on hover, and
+   *  onClick receives the href in `event.href`. Only applies to spans
+   *  nested inside another <text>. */
+  href?: string
+  /** Lets the user select this text with the mouse (drag, double-click
+   *  word, triple-click line) and copy it with Ctrl/Cmd+C. */
+  selectable?: boolean
+  /** Fires when the selection of a selectable <text> changes.
+   *  Offsets are JS string indices into the flattened text. */
+  onSelectionChange?: (event: EventPayload) => void
 }
 
 // Props for the <input> custom element (controlled component).
@@ -157,6 +365,8 @@ export interface NativeRenderer {
   removeChild(parentId: number, childId: number): void
   insertBefore(parentId: number, childId: number, beforeId: number): void
   setStyle(id: number, styleJson: string): void
+  /** Set the element's style classes (space-separated names). */
+  setClassName(id: number, className: string): void
   setText(id: number, content: string): void
   setEventListener(id: number, eventType: string, hasHandler: boolean): void
   setRoot(id: number): void
@@ -173,6 +383,40 @@ export interface NativeRenderer {
   scrollToItem?(elementId: number, index: number): void
   /** Get the current scroll offset [x, y] or null if element is not scrollable. */
   getScrollOffset?(elementId: number): Array<number> | null
+
+  // ── Theme ──────────────────────────────────────────────────────
+  /** Replace the theme: JSON object of token names to colors, lengths or
+   *  font names. Styles reference tokens as "var(--name)"; switching theme
+   *  re-resolves them natively without React re-sending styles. */
+  setTheme?(themeJson: string): void
+
+  // ── Keyboard ───────────────────────────────────────────────────
+  /** Names used for `event.key`: "gpui" (default: "enter", "down") or
+   *  "dom" (KeyboardEvent.key: "Enter", "ArrowDown"). */
+  setKeyNames?(names: "gpui" | "dom"): void
+  /** Add key bindings (JSON array of KeyBinding). Matches fire `onAction`. */
+  registerKeyBindings?(bindingsJson: string): void
+  /** Remove every registered key binding. */
+  clearKeyBindings?(): void
+
+  // ── Events ─────────────────────────────────────────────────────
+  /** Call `handler` synchronously for events dispatched during tick(); its
+   *  return value can stop propagation or prevent GPUI's default handling.
+   *  Pass null to go back to queued events. */
+  setSyncEventHandler?(
+    handler: ((event: EventPayload) => EventResponse | undefined | null) | null
+  ): void
+
+  // ── Style classes ──────────────────────────────────────────────
+  /** Register (or replace) a named style class. Elements reference it via
+   *  className and restyle natively when it is re-registered. */
+  registerStyleClass?(name: string, styleJson: string): void
+
+  // ── Animations ─────────────────────────────────────────────────
+  /** Register (or replace) named keyframes: JSON object of offsets
+   *  ("from", "to", "50%") to styles. Elements run them via the
+   *  `animation` style, natively and without React re-renders. */
+  registerAnimation?(name: string, keyframesJson: string): void
 }
 
 // Container holds the renderer reference.